@@ -0,0 +1,43 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rosu_pp::{Beatmap, Difficulty};
+
+const PATHS: [&str; 4] = [
+    "./resources/2785319.osu",
+    "./resources/1028484.osu",
+    "./resources/2118524.osu",
+    "./resources/1638954.osu",
+];
+
+fn maps() -> Vec<Beatmap> {
+    PATHS
+        .iter()
+        .cycle()
+        .take(32)
+        .map(|path| Beatmap::from_path(path).unwrap())
+        .collect()
+}
+
+fn calculate_many(c: &mut Criterion) {
+    let maps = maps();
+    let difficulty = Difficulty::new();
+
+    let mut group = c.benchmark_group("calculate_many");
+
+    group.bench_function("sequential", |b| {
+        b.iter(|| {
+            black_box(&maps)
+                .iter()
+                .map(|map| difficulty.calculate(map))
+                .collect::<Vec<_>>()
+        });
+    });
+
+    group.bench_function("parallel", |b| {
+        b.iter(|| rosu_pp::calculate_many(black_box(&maps), &difficulty));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, calculate_many);
+criterion_main!(benches);
@@ -0,0 +1,4 @@
+fn main() {
+    println!("cargo::rustc-check-cfg=cfg(rosu_pp_no_std)");
+    println!("cargo::rustc-cfg=rosu_pp_no_std");
+}
@@ -0,0 +1,18 @@
+//! Compiles the math-heavy difficulty helpers of `rosu-pp` with `#![no_std]`.
+//!
+//! The modules are included straight from the main crate's sources so that
+//! any use of `std` within them fails this check.
+
+#![no_std]
+#![allow(dead_code)]
+
+extern crate alloc;
+
+#[path = "../../src/util/difficulty.rs"]
+mod difficulty;
+
+#[path = "../../src/util/math.rs"]
+mod math;
+
+#[path = "../../src/util/special_functions.rs"]
+mod special_functions;
@@ -1,3 +1,7 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use rosu_map::section::general::GameMode;
+
 use crate::{
     catch::{CatchDifficultyAttributes, CatchPerformanceAttributes},
     mania::{ManiaDifficultyAttributes, ManiaPerformanceAttributes},
@@ -9,6 +13,7 @@ use super::performance::{into::IntoPerformance, Performance};
 
 /// The result of a difficulty calculation based on the mode.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DifficultyAttributes {
     /// osu!standard difficulty calculation result.
     Osu(OsuDifficultyAttributes),
@@ -41,14 +46,79 @@ impl DifficultyAttributes {
         }
     }
 
+    /// The mode that the attributes were calculated for.
+    pub const fn mode(&self) -> GameMode {
+        match self {
+            Self::Osu(_) => GameMode::Osu,
+            Self::Taiko(_) => GameMode::Taiko,
+            Self::Catch(_) => GameMode::Catch,
+            Self::Mania(_) => GameMode::Mania,
+        }
+    }
+
+    /// Whether the [`Beatmap`] was a convert i.e. an osu!standard map.
+    ///
+    /// [`Beatmap`]: crate::model::beatmap::Beatmap
+    pub const fn is_convert(&self) -> bool {
+        match self {
+            Self::Osu(_) => false,
+            Self::Taiko(attrs) => attrs.is_convert(),
+            Self::Catch(attrs) => attrs.is_convert(),
+            Self::Mania(attrs) => attrs.is_convert(),
+        }
+    }
+
+    /// The mode of the [`Beatmap`] before conversion.
+    ///
+    /// This is [`GameMode::Osu`] for converts and [`mode`](Self::mode)
+    /// otherwise.
+    ///
+    /// [`Beatmap`]: crate::model::beatmap::Beatmap
+    pub const fn source_mode(&self) -> GameMode {
+        if self.is_convert() {
+            GameMode::Osu
+        } else {
+            self.mode()
+        }
+    }
+
     /// Returns a builder for performance calculation.
     pub fn performance<'a>(self) -> Performance<'a> {
         self.into_performance()
     }
 }
 
+/// The results of difficulty calculations for all modes on the same map.
+///
+/// Modes that the map cannot be converted to are `None`.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AllModeAttributes {
+    /// osu!standard difficulty calculation result.
+    pub osu: Option<OsuDifficultyAttributes>,
+    /// osu!taiko difficulty calculation result.
+    pub taiko: Option<TaikoDifficultyAttributes>,
+    /// osu!catch difficulty calculation result.
+    pub catch: Option<CatchDifficultyAttributes>,
+    /// osu!mania difficulty calculation result.
+    pub mania: Option<ManiaDifficultyAttributes>,
+}
+
+impl AllModeAttributes {
+    /// Return the attributes of the given mode, if available.
+    pub fn get(&self, mode: GameMode) -> Option<DifficultyAttributes> {
+        match mode {
+            GameMode::Osu => self.osu.clone().map(DifficultyAttributes::Osu),
+            GameMode::Taiko => self.taiko.clone().map(DifficultyAttributes::Taiko),
+            GameMode::Catch => self.catch.clone().map(DifficultyAttributes::Catch),
+            GameMode::Mania => self.mania.clone().map(DifficultyAttributes::Mania),
+        }
+    }
+}
+
 /// The result of a performance calculation based on the mode.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PerformanceAttributes {
     /// osu!standard performance calculation result.
     Osu(OsuPerformanceAttributes),
@@ -71,6 +141,27 @@ impl PerformanceAttributes {
         }
     }
 
+    /// The accuracy of the score between `0.0` and `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{Beatmap, Performance};
+    ///
+    /// let map = Beatmap::from_path("./resources/1028484.osu").unwrap();
+    /// let attrs = Performance::new(&map).n300(250).n100(39).calculate();
+    ///
+    /// assert!((attrs.accuracy() - 539.0 / 578.0).abs() < 1e-9);
+    /// ```
+    pub const fn accuracy(&self) -> f64 {
+        match self {
+            Self::Osu(attrs) => attrs.accuracy,
+            Self::Taiko(attrs) => attrs.accuracy,
+            Self::Catch(attrs) => attrs.accuracy,
+            Self::Mania(attrs) => attrs.accuracy,
+        }
+    }
+
     /// The star value.
     pub const fn stars(&self) -> f64 {
         match self {
@@ -101,12 +192,89 @@ impl PerformanceAttributes {
         }
     }
 
+    /// The mode that the attributes were calculated for.
+    pub const fn mode(&self) -> GameMode {
+        match self {
+            Self::Osu(_) => GameMode::Osu,
+            Self::Taiko(_) => GameMode::Taiko,
+            Self::Catch(_) => GameMode::Catch,
+            Self::Mania(_) => GameMode::Mania,
+        }
+    }
+
+    /// Whether the [`Beatmap`] was a convert i.e. an osu!standard map.
+    ///
+    /// [`Beatmap`]: crate::model::beatmap::Beatmap
+    pub const fn is_convert(&self) -> bool {
+        match self {
+            Self::Osu(_) => false,
+            Self::Taiko(attrs) => attrs.is_convert(),
+            Self::Catch(attrs) => attrs.is_convert(),
+            Self::Mania(attrs) => attrs.is_convert(),
+        }
+    }
+
+    /// The mode of the [`Beatmap`] before conversion.
+    ///
+    /// This is [`GameMode::Osu`] for converts and [`mode`](Self::mode)
+    /// otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{model::mode::GameMode, Beatmap, Performance};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    /// let attrs = Performance::new(&map)
+    ///     .mode_or_ignore(GameMode::Taiko)
+    ///     .calculate();
+    ///
+    /// assert_eq!(attrs.mode(), GameMode::Taiko);
+    /// assert!(attrs.is_convert());
+    /// assert_eq!(attrs.source_mode(), GameMode::Osu);
+    /// ```
+    ///
+    /// [`Beatmap`]: crate::model::beatmap::Beatmap
+    pub const fn source_mode(&self) -> GameMode {
+        if self.is_convert() {
+            GameMode::Osu
+        } else {
+            self.mode()
+        }
+    }
+
     /// Returns a builder for performance calculation.
     pub fn performance<'a>(self) -> Performance<'a> {
         self.into_performance()
     }
 }
 
+impl Display for PerformanceAttributes {
+    /// Format a one-line summary of the mode-specific attributes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{Beatmap, Performance};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    /// let attrs = Performance::new(&map).accuracy(98.5).calculate();
+    ///
+    /// let summary = attrs.to_string();
+    ///
+    /// assert!(summary.starts_with(&format!("{:.2}pp (aim ", attrs.pp())));
+    /// assert!(summary.ends_with(&format!("% {:.2}*", attrs.stars())));
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Osu(attrs) => Display::fmt(attrs, f),
+            Self::Taiko(attrs) => Display::fmt(attrs, f),
+            Self::Catch(attrs) => Display::fmt(attrs, f),
+            Self::Mania(attrs) => Display::fmt(attrs, f),
+        }
+    }
+}
+
 impl From<PerformanceAttributes> for DifficultyAttributes {
     fn from(attrs: PerformanceAttributes) -> Self {
         attrs.difficulty_attributes()
@@ -0,0 +1,47 @@
+use rosu_map::section::general::GameMode;
+
+use crate::{
+    catch::Catch, mania::Mania, model::mode::ConvertError, osu::Osu, taiko::Taiko, Beatmap,
+    GameMods,
+};
+
+/// The combo that each hitobject contributes to the maximum combo.
+///
+/// The map is converted to the given [`GameMode`] first and the result
+/// contains one value per hitobject of the converted map, e.g. `1` for
+/// circles or `1` plus the amount of ticks, repeats, and the tail for
+/// osu!standard sliders. Objects that don't give combo, such as osu!taiko
+/// drum rolls or osu!catch banana showers, have a value of `0`.
+///
+/// Mods are only relevant for osu!mania where they may change hold notes.
+///
+/// # Example
+///
+/// ```
+/// use rosu_pp::{any::object_combo_values, model::mode::GameMode, Beatmap, Difficulty};
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+/// let values = object_combo_values(&map, GameMode::Osu, 0).unwrap();
+///
+/// assert_eq!(values.len(), map.hit_objects.len());
+///
+/// let max_combo = Difficulty::new().calculate(&map).max_combo();
+/// assert_eq!(values.iter().sum::<u32>(), max_combo);
+/// ```
+pub fn object_combo_values(
+    map: &Beatmap,
+    mode: GameMode,
+    mods: impl Into<GameMods>,
+) -> Result<Vec<u32>, ConvertError> {
+    let mods = mods.into();
+    let map = map.convert_ref(mode, &mods)?;
+
+    let values = match mode {
+        GameMode::Osu => Osu::object_combo_values(&map),
+        GameMode::Taiko => Taiko::object_combo_values(&map),
+        GameMode::Catch => Catch::object_combo_values(&map),
+        GameMode::Mania => Mania::object_combo_values(&map, &mods),
+    };
+
+    Ok(values)
+}
@@ -0,0 +1,43 @@
+use crate::{Beatmap, Difficulty, GameMods, Performance};
+
+/// The pp of a map for each of the given accuracies.
+///
+/// Star rating only depends on the map and mods, not on how well it was
+/// played, whereas pp additionally take the score into account. The
+/// resulting `(accuracy, pp)` pairs describe how pp scale with accuracy on
+/// a map, e.g. to plot them.
+///
+/// Accuracies are given between `0.0` and `100.0`. Difficulty attributes
+/// are calculated only once and then reused for each accuracy.
+///
+/// # Example
+///
+/// ```
+/// use rosu_pp::{any::performance_curve, Beatmap};
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+/// let curve = performance_curve(&map, 8, &[95.0, 97.0, 99.0, 100.0]);
+///
+/// assert_eq!(curve.len(), 4);
+/// assert!(curve.windows(2).all(|pair| pair[0].1 < pair[1].1));
+/// ```
+pub fn performance_curve(
+    map: &Beatmap,
+    mods: impl Into<GameMods>,
+    accs: &[f64],
+) -> Vec<(f64, f64)> {
+    let mods = mods.into();
+    let attrs = Difficulty::new().mods(mods.clone()).calculate(map);
+
+    accs.iter()
+        .map(|&acc| {
+            let pp = Performance::new(attrs.clone())
+                .mods(mods.clone())
+                .accuracy(acc)
+                .calculate()
+                .pp();
+
+            (acc, pp)
+        })
+        .collect()
+}
@@ -112,3 +112,76 @@ impl ExactSizeIterator for GradualDifficulty {
         }
     }
 }
+
+/// Gradually calculate the combined strain of each object on maps of any
+/// mode.
+///
+/// Note that this type implements [`Iterator`]. On every call of
+/// [`Iterator::next`], the next object will be processed and its strain
+/// will be returned. Unlike [`GradualDifficulty`], no star rating is
+/// evaluated in between so iterating is considerably faster.
+///
+/// Which strains are combined depends on the mode:
+/// - osu!standard: the sum of the aim and speed strain
+/// - osu!taiko: the rhythm, reading, color, and stamina strain combined the
+///   same way as for the star rating, excluding map-wide bonuses
+/// - osu!catch: the movement strain
+/// - osu!mania: the strain
+///
+/// Objects without a difficulty object, e.g. the first object of a map, have
+/// a strain of `0.0`.
+///
+/// # Example
+///
+/// ```
+/// use rosu_pp::{any::GradualStrains, Beatmap, Difficulty};
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+/// let strains: Vec<f64> = GradualStrains::new(Difficulty::new(), &map).collect();
+///
+/// assert_eq!(strains.len(), map.hit_objects.len());
+/// ```
+pub struct GradualStrains {
+    inner: GradualDifficulty,
+}
+
+impl GradualStrains {
+    /// Create a [`GradualStrains`] for a map of any mode.
+    pub fn new(difficulty: Difficulty, map: &Beatmap) -> Self {
+        Self {
+            inner: GradualDifficulty::new(difficulty, map),
+        }
+    }
+
+    /// Create a [`GradualStrains`] for a [`Beatmap`] on a specific [`GameMode`].
+    pub fn new_with_mode(
+        difficulty: Difficulty,
+        map: &Beatmap,
+        mode: GameMode,
+    ) -> Result<Self, ConvertError> {
+        GradualDifficulty::new_with_mode(difficulty, map, mode).map(|inner| Self { inner })
+    }
+}
+
+impl Iterator for GradualStrains {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner {
+            GradualDifficulty::Osu(ref mut gradual) => gradual.next_strain(),
+            GradualDifficulty::Taiko(ref mut gradual) => gradual.next_strain(),
+            GradualDifficulty::Catch(ref mut gradual) => gradual.next_strain(),
+            GradualDifficulty::Mania(ref mut gradual) => gradual.next_strain(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl ExactSizeIterator for GradualStrains {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
@@ -1,12 +1,23 @@
-use crate::{model::mods::GameMods, Difficulty};
+use crate::{
+    model::mods::{rosu_mods::GameModIntermode, GameMods},
+    Difficulty,
+};
 
-use super::ModsDependent;
+use super::{ModsDependent, Skill};
 
 /// [`Difficulty`] but all fields are public for inspection.
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct InspectDifficulty {
     /// Specify mods.
     pub mods: GameMods,
+    /// The mod whose semantics the clock rate has.
+    ///
+    /// See [`Difficulty::clock_rate_with_mod_semantics`].
+    pub rate_mod: Option<GameModIntermode>,
+    /// Mods that are removed from difficulty consideration.
+    ///
+    /// See [`Difficulty::ignore_mods`].
+    pub ignored_mods: Option<GameMods>,
     /// Amount of passed objects for partial plays, e.g. a fail.
     pub passed_objects: Option<u32>,
     /// Adjust the clock rate used in the calculation.
@@ -32,6 +43,35 @@ pub struct InspectDifficulty {
     ///
     /// Defaults to `true`.
     pub lazer: Option<bool>,
+    /// Override the base multiplier of osu!standard star rating and
+    /// performance calculation.
+    ///
+    /// Only relevant for osu!standard.
+    pub performance_base_multiplier: Option<f64>,
+    /// Skills that are excluded from the calculation.
+    ///
+    /// Only relevant for osu!standard.
+    pub disabled_skills: Vec<Skill>,
+    /// Override a beatmap's set slider multiplier.
+    ///
+    /// Only relevant for osu! and osu!catch.
+    pub slider_multiplier: Option<f64>,
+    /// Override a beatmap's set slider tick rate.
+    ///
+    /// Only relevant for osu! and osu!catch.
+    pub slider_tick_rate: Option<f64>,
+    /// Only consider hit objects that start within the given range of
+    /// milliseconds.
+    pub time_range: Option<(f64, f64)>,
+    /// Calculate the difficulty as if all hold notes were regular notes.
+    ///
+    /// Only relevant for osu!mania.
+    pub holds_as_notes: Option<bool>,
+    /// The rotations per second that a player is assumed to need at least
+    /// to complete a spinner.
+    ///
+    /// Only relevant for the osu!standard legacy score simulation.
+    pub assumed_spin_rate: Option<f64>,
 }
 
 impl InspectDifficulty {
@@ -39,6 +79,8 @@ impl InspectDifficulty {
     pub fn into_difficulty(self) -> Difficulty {
         let Self {
             mods,
+            rate_mod,
+            ignored_mods,
             passed_objects,
             clock_rate,
             ar,
@@ -47,9 +89,21 @@ impl InspectDifficulty {
             od,
             hardrock_offsets,
             lazer,
+            performance_base_multiplier,
+            disabled_skills,
+            slider_multiplier,
+            slider_tick_rate,
+            time_range,
+            holds_as_notes,
+            assumed_spin_rate,
         } = self;
 
-        let mut difficulty = Difficulty::new().mods(mods);
+        let mut difficulty = Difficulty {
+            rate_mod,
+            ignored_mods,
+            ..Difficulty::new()
+        }
+        .mods(mods);
 
         if let Some(passed_objects) = passed_objects {
             difficulty = difficulty.passed_objects(passed_objects);
@@ -83,6 +137,34 @@ impl InspectDifficulty {
             difficulty = difficulty.lazer(lazer);
         }
 
+        if let Some(multiplier) = performance_base_multiplier {
+            difficulty = difficulty.performance_base_multiplier(multiplier);
+        }
+
+        for skill in disabled_skills {
+            difficulty = difficulty.disable_skill(skill);
+        }
+
+        if let Some(slider_multiplier) = slider_multiplier {
+            difficulty = difficulty.slider_multiplier(slider_multiplier);
+        }
+
+        if let Some(slider_tick_rate) = slider_tick_rate {
+            difficulty = difficulty.slider_tick_rate(slider_tick_rate);
+        }
+
+        if let Some((start_ms, end_ms)) = time_range {
+            difficulty = difficulty.time_range(start_ms, end_ms);
+        }
+
+        if let Some(holds_as_notes) = holds_as_notes {
+            difficulty = difficulty.holds_as_notes(holds_as_notes);
+        }
+
+        if let Some(rotations_per_second) = assumed_spin_rate {
+            difficulty = difficulty.assumed_spin_rate(rotations_per_second);
+        }
+
         difficulty
     }
 }
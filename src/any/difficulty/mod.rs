@@ -1,4 +1,6 @@
 use std::{
+    borrow::Cow,
+    cmp,
     fmt::{Debug, Formatter, Result as FmtResult},
     num::NonZeroU64,
 };
@@ -8,17 +10,27 @@ use rosu_map::section::general::GameMode;
 use crate::{
     catch::Catch,
     mania::Mania,
-    model::{beatmap::Beatmap, mode::ConvertError, mods::GameMods},
-    osu::Osu,
-    taiko::Taiko,
+    model::{
+        beatmap::{Beatmap, BeatmapAttributes, HitWindows},
+        hit_object::HitObject,
+        mode::{CalculateError, ConvertError},
+        mods::{rosu_mods::GameModIntermode, GameMods},
+    },
+    osu::{Osu, OsuObjectStrain, PERFORMANCE_BASE_MULTIPLIER},
+    taiko::{Taiko, TaikoColorRun},
     GradualDifficulty, GradualPerformance,
 };
 
-use super::{attributes::DifficultyAttributes, InspectDifficulty, Strains};
+use super::{
+    attributes::{AllModeAttributes, DifficultyAttributes},
+    GradualStrains, InspectDifficulty, Strains,
+};
 
 pub mod gradual;
 pub mod inspect;
 pub mod object;
+#[cfg(feature = "rayon")]
+pub mod parallel;
 pub mod skills;
 
 use crate::model::mode::IGameMode;
@@ -39,7 +51,12 @@ use crate::model::mode::IGameMode;
 #[derive(Clone, PartialEq)]
 #[must_use]
 pub struct Difficulty {
+    /// The specified mods including the rate mod, if any.
     mods: GameMods,
+    /// The mods without the ignored ones; only set if mods are ignored.
+    filtered_mods: Option<GameMods>,
+    rate_mod: Option<GameModIntermode>,
+    ignored_mods: Option<GameMods>,
     passed_objects: Option<u32>,
     /// Clock rate will be clamped internally between 0.01 and 100.0.
     ///
@@ -54,6 +71,32 @@ pub struct Difficulty {
     od: Option<ModsDependent>,
     hardrock_offsets: Option<bool>,
     lazer: Option<bool>,
+    performance_base_multiplier: Option<f64>,
+    disabled_skills: u8,
+    slider_multiplier: Option<f64>,
+    slider_tick_rate: Option<f64>,
+    time_range: Option<(f64, f64)>,
+    holds_as_notes: Option<bool>,
+    assumed_spin_rate: Option<f64>,
+}
+
+/// A skill of osu!standard difficulty calculation.
+///
+/// See [`Difficulty::disable_skill`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Skill {
+    Aim,
+    Speed,
+    Flashlight,
+}
+
+impl Skill {
+    /// All skills.
+    pub const ALL: [Self; 3] = [Self::Aim, Self::Speed, Self::Flashlight];
+
+    const fn bit(self) -> u8 {
+        1 << self as u8
+    }
 }
 
 /// Wrapper for beatmap attributes in [`Difficulty`].
@@ -82,6 +125,9 @@ impl Difficulty {
     pub const fn new() -> Self {
         Self {
             mods: GameMods::DEFAULT,
+            filtered_mods: None,
+            rate_mod: None,
+            ignored_mods: None,
             passed_objects: None,
             clock_rate: None,
             ar: None,
@@ -90,6 +136,13 @@ impl Difficulty {
             od: None,
             hardrock_offsets: None,
             lazer: None,
+            performance_base_multiplier: None,
+            disabled_skills: 0,
+            slider_multiplier: None,
+            slider_tick_rate: None,
+            time_range: None,
+            holds_as_notes: None,
+            assumed_spin_rate: None,
         }
     }
 
@@ -98,6 +151,9 @@ impl Difficulty {
     pub fn inspect(self) -> InspectDifficulty {
         let Self {
             mods,
+            filtered_mods: _,
+            rate_mod,
+            ignored_mods,
             passed_objects,
             clock_rate,
             ar,
@@ -106,10 +162,19 @@ impl Difficulty {
             od,
             hardrock_offsets,
             lazer,
+            performance_base_multiplier,
+            disabled_skills,
+            slider_multiplier,
+            slider_tick_rate,
+            time_range,
+            holds_as_notes,
+            assumed_spin_rate,
         } = self;
 
         InspectDifficulty {
             mods,
+            rate_mod,
+            ignored_mods,
             passed_objects,
             clock_rate: clock_rate.map(non_zero_u64_to_f64),
             ar,
@@ -118,6 +183,16 @@ impl Difficulty {
             od,
             hardrock_offsets,
             lazer,
+            performance_base_multiplier,
+            disabled_skills: Skill::ALL
+                .into_iter()
+                .filter(|skill| disabled_skills & skill.bit() > 0)
+                .collect(),
+            slider_multiplier,
+            slider_tick_rate,
+            time_range,
+            holds_as_notes,
+            assumed_spin_rate,
         }
     }
 
@@ -130,12 +205,14 @@ impl Difficulty {
     /// - [`rosu_mods::GameModsIntermode`]
     /// - [`&rosu_mods::GameModsIntermode`](rosu_mods::GameModsIntermode)
     ///
+    /// Mods that shuffle the map, i.e. osu!taiko's and osu!mania's `Random`,
+    /// are only applied if they specify a seed. The shuffling depends solely
+    /// on that seed so the same seed always results in the same attributes.
+    /// osu!standard's `Random` is not supported.
+    ///
     /// See <https://github.com/ppy/osu-api/wiki#mods>
     pub fn mods(self, mods: impl Into<GameMods>) -> Self {
-        Self {
-            mods: mods.into(),
-            ..self
-        }
+        self.with_mods(mods.into())
     }
 
     /// Amount of passed objects for partial plays, e.g. a fail.
@@ -166,6 +243,101 @@ impl Difficulty {
         }
     }
 
+    /// Adjust the clock rate while treating it like the given mod.
+    ///
+    /// In contrast to [`Difficulty::clock_rate`], the mods are adjusted as
+    /// well so that checks for the mod, e.g. score multipliers, apply to the
+    /// custom clock rate. This is useful for custom-rate plays on osu!lazer
+    /// where `DT` does not necessarily mean a clock rate of 1.5.
+    ///
+    /// The mod must be one of `DoubleTime`, `Nightcore`, `HalfTime`, or
+    /// `Daycore` and replaces any of those within the mods, regardless of
+    /// whether they are specified before or after; other mods only adjust
+    /// the clock rate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{model::mods::rosu_mods::GameModIntermode, Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// let plain = Difficulty::new().clock_rate(1.3);
+    /// let like_dt = Difficulty::new()
+    ///     .clock_rate_with_mod_semantics(1.3, GameModIntermode::DoubleTime);
+    ///
+    /// assert_eq!(plain.calculate(&map).stars(), like_dt.calculate(&map).stars());
+    /// assert!(like_dt.max_legacy_score(&map) > plain.max_legacy_score(&map));
+    /// ```
+    pub fn clock_rate_with_mod_semantics(
+        self,
+        clock_rate: f64,
+        like_mod: GameModIntermode,
+    ) -> Self {
+        let mods = self.mods.clone();
+
+        Self {
+            rate_mod: Some(like_mod),
+            ..self
+        }
+        .with_mods(mods)
+        .clock_rate(clock_rate)
+    }
+
+    /// Remove the given mods from difficulty consideration while keeping
+    /// their clock rate and score multiplier.
+    ///
+    /// This differs from not specifying the mods in the first place because
+    /// the clock rate stays intact, e.g. ignoring `DT` still calculates with
+    /// a clock rate of 1.5, and the score multiplier of the legacy score
+    /// simulation still applies. The given mods replace previously ignored
+    /// mods and apply regardless of whether [`Difficulty::mods`] is called
+    /// before or after.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// // HDDT but without the HD
+    /// let ignored = Difficulty::new().mods(8 + 64).ignore_mods(8);
+    /// let dt = Difficulty::new().mods(64);
+    ///
+    /// assert_eq!(ignored.calculate(&map), dt.calculate(&map));
+    /// assert!(ignored.max_legacy_score(&map) > dt.max_legacy_score(&map));
+    /// ```
+    pub fn ignore_mods(self, mods: impl Into<GameMods>) -> Self {
+        let ignored_mods = Some(mods.into());
+        let mods = self.mods.clone();
+
+        Self {
+            ignored_mods,
+            ..self
+        }
+        .with_mods(mods)
+    }
+
+    /// Set the mods while applying the rate mod and ignored mods.
+    fn with_mods(self, mods: GameMods) -> Self {
+        let mods = match self.rate_mod {
+            Some(rate_mod) => mods.with_rate_mod(rate_mod),
+            None => mods,
+        };
+
+        let filtered_mods = self
+            .ignored_mods
+            .as_ref()
+            .map(|ignored| mods.without(ignored));
+
+        Self {
+            mods,
+            filtered_mods,
+            ..self
+        }
+    }
+
     /// Override a beatmap's set AR.
     ///
     /// Only relevant for osu! and osu!catch.
@@ -246,6 +418,129 @@ impl Difficulty {
         }
     }
 
+    /// Override a beatmap's set slider multiplier.
+    ///
+    /// Only relevant for osu! and osu!catch.
+    ///
+    /// | Minimum | Maximum |
+    /// | :-----: | :-----: |
+    /// | 0.4     | 3.6     |
+    pub fn slider_multiplier(self, slider_multiplier: f64) -> Self {
+        Self {
+            slider_multiplier: Some(slider_multiplier.clamp(0.4, 3.6)),
+            ..self
+        }
+    }
+
+    /// Override a beatmap's set slider tick rate.
+    ///
+    /// Only relevant for osu! and osu!catch.
+    ///
+    /// | Minimum | Maximum |
+    /// | :-----: | :-----: |
+    /// | 0.5     | 8       |
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// let max_combo = Difficulty::new().calculate(&map).max_combo();
+    /// let more_ticks = Difficulty::new()
+    ///     .slider_tick_rate(2.0 * map.slider_tick_rate)
+    ///     .calculate(&map)
+    ///     .max_combo();
+    ///
+    /// assert!(more_ticks > max_combo);
+    /// ```
+    pub fn slider_tick_rate(self, slider_tick_rate: f64) -> Self {
+        Self {
+            slider_tick_rate: Some(slider_tick_rate.clamp(0.5, 8.0)),
+            ..self
+        }
+    }
+
+    /// Only consider hit objects that start within the given range of
+    /// milliseconds, e.g. to calculate the difficulty of a single section.
+    ///
+    /// Objects that start within the range but end after it such as long
+    /// sliders are included. The remaining objects are processed as if they
+    /// were the whole map so object counts and max combo only consider
+    /// objects within the range. If [`passed_objects`] is specified as well,
+    /// it refers to the objects within the range.
+    ///
+    /// The start is inclusive, the end is exclusive. Times refer to the map
+    /// itself and are not adjusted by the clock rate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// let full = Difficulty::new().calculate(&map);
+    /// let section = Difficulty::new().time_range(20_000.0, 40_000.0).calculate(&map);
+    ///
+    /// assert!(section.max_combo() < full.max_combo());
+    /// ```
+    ///
+    /// [`passed_objects`]: Difficulty::passed_objects
+    pub const fn time_range(mut self, start_ms: f64, end_ms: f64) -> Self {
+        self.time_range = Some((start_ms, end_ms));
+
+        self
+    }
+
+    /// Calculate the difficulty as if all hold notes were regular notes.
+    ///
+    /// Unlike the `HoldOff` mod, this does not modify the map itself so the
+    /// max combo and amount of hold notes remain unaffected. This allows
+    /// comparing a map's difficulty with its "rice-equivalent" difficulty.
+    ///
+    /// Only relevant for osu!mania. Defaults to `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/1638954.osu").unwrap();
+    ///
+    /// let attrs = Difficulty::new().calculate(&map);
+    /// let rice = Difficulty::new().holds_as_notes(true).calculate(&map);
+    ///
+    /// assert_ne!(rice.stars(), attrs.stars());
+    /// assert_eq!(rice.max_combo(), attrs.max_combo());
+    /// ```
+    pub const fn holds_as_notes(mut self, holds_as_notes: bool) -> Self {
+        self.holds_as_notes = Some(holds_as_notes);
+
+        self
+    }
+
+    /// The rotations per second that a player is assumed to need at least
+    /// to complete a spinner.
+    ///
+    /// osu!stable only awards bonus score for spins beyond the required
+    /// amount so a higher spin rate results in less bonus score. Defaults to
+    /// `3.0`, i.e. the worst case which maximizes bonus score.
+    ///
+    /// Only relevant for the osu!standard legacy score simulation, see
+    /// [`Difficulty::max_legacy_score`].
+    ///
+    /// | Minimum | Maximum |
+    /// | :-----: | :-----: |
+    /// | 0       | 7.95    |
+    pub fn assumed_spin_rate(self, rotations_per_second: f64) -> Self {
+        Self {
+            assumed_spin_rate: Some(rotations_per_second.clamp(0.0, 477.0 / 60.0)),
+            ..self
+        }
+    }
+
     /// Adjust patterns as if the HR mod is enabled.
     ///
     /// Only relevant for osu!catch.
@@ -265,7 +560,107 @@ impl Difficulty {
         self
     }
 
+    /// Override the base multiplier that osu!standard star rating and
+    /// performance calculation scale with.
+    ///
+    /// Intended for experimenting with the balance of the algorithm.
+    /// Defaults to [`PERFORMANCE_BASE_MULTIPLIER`].
+    ///
+    /// Only relevant for osu!standard.
+    ///
+    /// [`PERFORMANCE_BASE_MULTIPLIER`]: crate::osu::PERFORMANCE_BASE_MULTIPLIER
+    pub const fn performance_base_multiplier(mut self, multiplier: f64) -> Self {
+        self.performance_base_multiplier = Some(multiplier);
+
+        self
+    }
+
+    /// Exclude a [`Skill`] from the calculation as if its rating was zero.
+    ///
+    /// Intended to measure how much a skill contributes to the final rating,
+    /// e.g. disabling speed and flashlight results in an aim-only star
+    /// rating. All skills are enabled by default.
+    ///
+    /// Only relevant for osu!standard.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{any::Skill, Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// let stars = Difficulty::new().calculate(&map).stars();
+    /// let aim_only = Difficulty::new()
+    ///     .disable_skill(Skill::Speed)
+    ///     .disable_skill(Skill::Flashlight)
+    ///     .calculate(&map)
+    ///     .stars();
+    ///
+    /// assert!(aim_only < stars);
+    /// ```
+    pub const fn disable_skill(mut self, skill: Skill) -> Self {
+        self.disabled_skills |= skill.bit();
+
+        self
+    }
+
+    /// Resolve the clock rate and the AR, CS, HP, and OD that are effectively
+    /// used for the given [`Beatmap`] i.e. after applying mods and custom
+    /// values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// // DT
+    /// let attrs = Difficulty::new().mods(64).resolve(&map);
+    ///
+    /// assert_eq!(attrs.clock_rate, 1.5);
+    /// assert!(attrs.ar > f64::from(map.ar));
+    /// ```
+    pub fn resolve(&self, map: &Beatmap) -> BeatmapAttributes {
+        map.attributes().difficulty(self).build()
+    }
+
+    /// Calculate the hit windows in milliseconds when playing the given
+    /// [`Beatmap`] in the given [`GameMode`].
+    ///
+    /// Mods and custom values are taken into account. If the mode differs
+    /// from the map's mode, the map is considered to be converted which
+    /// matters for osu!mania's great hit window.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{model::mode::GameMode, Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// let osu = Difficulty::new().hit_windows(&map, GameMode::Osu);
+    /// assert!(osu.od_ok.is_some() && osu.od_meh.is_some());
+    ///
+    /// let taiko = Difficulty::new().hit_windows(&map, GameMode::Taiko);
+    /// assert!(taiko.od_ok.is_some() && taiko.od_meh.is_none());
+    ///
+    /// // HR
+    /// let mania = Difficulty::new().mods(16).hit_windows(&map, GameMode::Mania);
+    /// assert!(mania.od_ok.is_none());
+    /// assert!(mania.od_great < Difficulty::new().hit_windows(&map, GameMode::Mania).od_great);
+    /// ```
+    pub fn hit_windows(&self, map: &Beatmap, mode: GameMode) -> HitWindows {
+        map.attributes()
+            .mode(mode, map.is_convert || map.mode != mode)
+            .difficulty(self)
+            .hit_windows()
+    }
+
     /// Perform the difficulty calculation.
+    ///
+    /// Maps without hit objects result in zero stars.
     #[allow(clippy::missing_panics_doc)]
     pub fn calculate(&self, map: &Beatmap) -> DifficultyAttributes {
         match map.mode {
@@ -284,6 +679,93 @@ impl Difficulty {
         }
     }
 
+    /// Perform the difficulty calculation for the given mode while checking
+    /// that the calculation is meaningful.
+    ///
+    /// Unlike [`Difficulty::calculate`], this returns an error if the map has
+    /// no hitobjects, if the mods cannot be used for the mode, or if the map
+    /// cannot be converted to the mode.
+    ///
+    /// Zero stars for empty maps remain the canonical result of all other
+    /// calculations; this method is meant for callers who would rather
+    /// reject such maps.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{
+    ///     model::{
+    ///         beatmap::BeatmapBuilder,
+    ///         mode::{CalculateError, GameMode},
+    ///     },
+    ///     Beatmap, Difficulty,
+    /// };
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// let attrs = Difficulty::new().mods(8 + 64).checked_calculate(&map, GameMode::Osu);
+    /// assert!(attrs.is_ok());
+    ///
+    /// // EZHR
+    /// let err = Difficulty::new().mods(2 + 16).checked_calculate(&map, GameMode::Osu);
+    /// assert!(matches!(err, Err(CalculateError::InvalidMods(_))));
+    ///
+    /// let map = Beatmap::from_path("./resources/1028484.osu").unwrap();
+    ///
+    /// let err = Difficulty::new().checked_calculate(&map, GameMode::Catch);
+    /// assert!(matches!(err, Err(CalculateError::Convert(_))));
+    ///
+    /// let map = BeatmapBuilder::new().build();
+    ///
+    /// let err = Difficulty::new().checked_calculate(&map, GameMode::Osu);
+    /// assert!(matches!(err, Err(CalculateError::EmptyMap)));
+    /// ```
+    pub fn checked_calculate(
+        &self,
+        map: &Beatmap,
+        mode: GameMode,
+    ) -> Result<DifficultyAttributes, CalculateError> {
+        if map.hit_objects.is_empty() {
+            return Err(CalculateError::EmptyMap);
+        }
+
+        self.mods.validate_for(mode)?;
+        let map = map.convert_ref(mode, &self.mods)?;
+
+        Ok(self.calculate(&map))
+    }
+
+    /// Perform the difficulty calculation for all modes at once.
+    ///
+    /// The map is converted once for each mode that it can be converted to
+    /// and the attributes of modes that it cannot be converted to are `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    /// let attrs = Difficulty::new().mods(64).calculate_all_modes(&map);
+    ///
+    /// assert!(attrs.osu.is_some());
+    /// assert!(attrs.mania.is_some());
+    ///
+    /// let map = Beatmap::from_path("./resources/1028484.osu").unwrap();
+    /// let attrs = Difficulty::new().calculate_all_modes(&map);
+    ///
+    /// assert!(attrs.osu.is_none());
+    /// assert!(attrs.taiko.is_some());
+    /// ```
+    pub fn calculate_all_modes(&self, map: &Beatmap) -> AllModeAttributes {
+        AllModeAttributes {
+            osu: Osu::difficulty(self, map).ok(),
+            taiko: Taiko::difficulty(self, map).ok(),
+            catch: Catch::difficulty(self, map).ok(),
+            mania: Mania::difficulty(self, map).ok(),
+        }
+    }
+
     /// Perform the difficulty calculation for a specific [`IGameMode`].
     pub fn calculate_for_mode<M: IGameMode>(
         &self,
@@ -292,6 +774,34 @@ impl Difficulty {
         M::difficulty(self, map)
     }
 
+    /// The `n` most difficult sections of the map.
+    ///
+    /// Each section is given as `(start_time, end_time, strain)` where the
+    /// times are in milliseconds of the map, i.e. not adjusted by the clock
+    /// rate, and the strain is the sum of all skills' strains within the
+    /// section. Sections have a fixed length depending on the mode, see
+    /// [`Strains::section_len`], so they never overlap.
+    ///
+    /// The sections are sorted by strain in descending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    /// let sections = Difficulty::new().hardest_sections(&map, 3);
+    ///
+    /// assert_eq!(sections.len(), 3);
+    /// assert!(sections.windows(2).all(|pair| pair[0].2 >= pair[1].2));
+    /// assert!(sections.iter().all(|&(start, end, _)| end - start == 400.0));
+    /// ```
+    pub fn hardest_sections(&self, map: &Beatmap, n: usize) -> Vec<(f64, f64, f64)> {
+        self.strains(map)
+            .hardest_sections(self, map, n)
+            .unwrap_or_default()
+    }
+
     /// Perform the difficulty calculation but instead of evaluating the skill
     /// strains, return them as is.
     ///
@@ -320,11 +830,148 @@ impl Difficulty {
         M::strains(self, map)
     }
 
+    /// Calculate the aim, speed, and flashlight strain of each hitobject of an
+    /// osu!standard [`Beatmap`].
+    ///
+    /// The first hitobject has no strain and is thus skipped. Suitable to
+    /// find difficulty spikes within a map.
+    ///
+    /// Returns an error if the map cannot be converted to osu!standard.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// let strains = Difficulty::new().calculate_osu_object_strains(&map).unwrap();
+    /// assert_eq!(strains.len(), map.hit_objects.len() - 1);
+    ///
+    /// let hardest = strains
+    ///     .iter()
+    ///     .max_by(|a, b| a.aim.total_cmp(&b.aim))
+    ///     .unwrap();
+    ///
+    /// println!("Hardest jump at {}ms", hardest.start_time);
+    /// ```
+    pub fn calculate_osu_object_strains(
+        &self,
+        map: &Beatmap,
+    ) -> Result<Vec<OsuObjectStrain>, ConvertError> {
+        Osu::object_strains(self, map)
+    }
+
+    /// Extract the runs of same-colored notes of an osu!taiko [`Beatmap`].
+    ///
+    /// The first two hitobjects are not part of the difficulty calculation
+    /// and are thus not included. Suitable to inspect the color patterns
+    /// that the color skill is evaluating.
+    ///
+    /// Returns an error if the map cannot be converted to osu!taiko.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_map::section::general::GameMode;
+    /// use rosu_pp::{
+    ///     model::{
+    ///         beatmap::BeatmapBuilder,
+    ///         hit_object::{HitObject, HitObjectKind, HitSoundType, Pos},
+    ///     },
+    ///     taiko::TaikoColor,
+    ///     Difficulty,
+    /// };
+    ///
+    /// let mut builder = BeatmapBuilder::new().mode(GameMode::Taiko);
+    ///
+    /// // ddddkkdd
+    /// for (i, kat) in [false, false, false, false, true, true, false, false].into_iter().enumerate() {
+    ///     let h = HitObject {
+    ///         pos: Pos::default(),
+    ///         start_time: 1000.0 + 100.0 * i as f64,
+    ///         kind: HitObjectKind::Circle,
+    ///     };
+    ///
+    ///     let sound = HitSoundType::from(if kat { HitSoundType::CLAP } else { HitSoundType::NONE });
+    ///     builder = builder.hit_object_with_sound(h, sound);
+    /// }
+    ///
+    /// let runs = Difficulty::new().taiko_color_patterns(&builder.build()).unwrap();
+    ///
+    /// let runs: Vec<_> = runs.iter().map(|run| (run.color, run.len)).collect();
+    /// assert_eq!(runs, [(TaikoColor::Don, 2), (TaikoColor::Kat, 2), (TaikoColor::Don, 2)]);
+    /// ```
+    pub fn taiko_color_patterns(&self, map: &Beatmap) -> Result<Vec<TaikoColorRun>, ConvertError> {
+        Taiko::color_runs(self, map)
+    }
+
+    /// The start time and x-position of each fruit and droplet of an
+    /// osu!catch [`Beatmap`], sorted by start time.
+    ///
+    /// Positions account for the mods, e.g. HR offsets and mirroring, as well
+    /// as the slider overrides. osu!standard maps are converted first.
+    ///
+    /// Returns an error if the map cannot be converted to osu!catch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2118524.osu").unwrap();
+    ///
+    /// let positions = Difficulty::new().catch_fruit_positions(&map).unwrap();
+    /// let slower = Difficulty::new()
+    ///     .slider_multiplier(map.slider_multiplier / 2.0)
+    ///     .catch_fruit_positions(&map)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(positions, map.catch_fruit_positions(0));
+    /// assert_ne!(positions, slower);
+    /// ```
+    pub fn catch_fruit_positions(&self, map: &Beatmap) -> Result<Vec<(f64, f32)>, ConvertError> {
+        Catch::fruit_positions(self, map)
+    }
+
+    /// Simulate the maximum osu!stable score, i.e. `ScoreV1`, of an
+    /// osu!standard [`Beatmap`].
+    ///
+    /// Maps of other modes are not supported and return `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// let max_score = Difficulty::new().max_legacy_score(&map);
+    /// let fast_spinner = Difficulty::new().assumed_spin_rate(5.0).max_legacy_score(&map);
+    ///
+    /// assert_eq!(max_score, map.max_legacy_score(0));
+    /// assert!(fast_spinner < max_score);
+    /// ```
+    pub fn max_legacy_score(&self, map: &Beatmap) -> u32 {
+        if map.mode != GameMode::Osu {
+            return 0;
+        }
+
+        Osu::max_legacy_score(self, map)
+    }
+
     /// Create a gradual difficulty calculator for a [`Beatmap`].
     pub fn gradual_difficulty(self, map: &Beatmap) -> GradualDifficulty {
         GradualDifficulty::new(self, map)
     }
 
+    /// Create a gradual strain iterator for a [`Beatmap`].
+    ///
+    /// See [`GradualStrains`].
+    pub fn gradual_strains(self, map: &Beatmap) -> GradualStrains {
+        GradualStrains::new(self, map)
+    }
+
     /// Create a gradual difficulty calculator for a [`Beatmap`] on a specific [`IGameMode`].
     pub fn gradual_difficulty_for_mode<M: IGameMode>(
         self,
@@ -346,7 +993,16 @@ impl Difficulty {
         M::gradual_performance(self, map)
     }
 
+    /// The mods for difficulty consideration, i.e. without ignored mods.
     pub(crate) const fn get_mods(&self) -> &GameMods {
+        match self.filtered_mods {
+            Some(ref mods) => mods,
+            None => &self.mods,
+        }
+    }
+
+    /// The mods including ignored mods, e.g. for score multipliers.
+    pub(crate) const fn get_score_mods(&self) -> &GameMods {
         &self.mods
     }
 
@@ -383,6 +1039,128 @@ impl Difficulty {
     pub(crate) fn get_lazer(&self) -> bool {
         self.lazer.unwrap_or(true)
     }
+
+    pub(crate) fn get_holds_as_notes(&self) -> bool {
+        self.holds_as_notes.unwrap_or(false)
+    }
+
+    pub(crate) fn get_assumed_spin_rate(&self) -> f64 {
+        self.assumed_spin_rate.unwrap_or(3.0)
+    }
+
+    pub(crate) fn get_performance_base_multiplier(&self) -> f64 {
+        self.performance_base_multiplier
+            .unwrap_or(PERFORMANCE_BASE_MULTIPLIER)
+    }
+
+    pub(crate) const fn is_skill_disabled(&self, skill: Skill) -> bool {
+        self.disabled_skills & skill.bit() > 0
+    }
+
+    /// Whether both difficulties create the same difficulty objects, i.e.
+    /// they only differ in settings that affect the evaluation of skills.
+    pub(crate) fn same_objects(&self, other: &Self) -> bool {
+        self.get_mods() == other.get_mods()
+            && self.get_clock_rate().to_bits() == other.get_clock_rate().to_bits()
+            && self.passed_objects == other.passed_objects
+            && self.ar == other.ar
+            && self.cs == other.cs
+            && self.hp == other.hp
+            && self.od == other.od
+            && self.slider_multiplier == other.slider_multiplier
+            && self.slider_tick_rate == other.slider_tick_rate
+            && self.time_range == other.time_range
+    }
+
+    /// Apply the overridden slider multiplier and slider tick rate, if any.
+    pub(crate) fn apply_slider_overrides<'a>(&self, mut map: Cow<'a, Beatmap>) -> Cow<'a, Beatmap> {
+        if let Some(slider_multiplier) = self.slider_multiplier {
+            map.to_mut().slider_multiplier = slider_multiplier;
+        }
+
+        if let Some(slider_tick_rate) = self.slider_tick_rate {
+            map.to_mut().slider_tick_rate = slider_tick_rate;
+        }
+
+        map
+    }
+
+    /// Whether the star rating exceeds `stars`, stopping early once the
+    /// objects processed so far exceed it.
+    ///
+    /// Objects are processed gradually and the star rating is evaluated after
+    /// a quarter and after half of the objects. Stopping early is only a
+    /// heuristic: the star rating of a prefix is usually not above that of
+    /// the whole map but map-wide values such as length bonuses, difficult
+    /// strain counts, or the slider factor can still lower the final rating.
+    ///
+    /// Maps below the threshold are always processed entirely.
+    pub(crate) fn exceeds_stars(&self, map: &Beatmap, stars: f64) -> bool {
+        let mut gradual = GradualDifficulty::new(self.clone(), map);
+        let len = cmp::min(gradual.len(), self.get_passed_objects());
+        let mut processed = 0;
+
+        for checkpoint in [len / 4, len / 2, len] {
+            let Some(n) = checkpoint.checked_sub(processed + 1) else {
+                continue;
+            };
+
+            let Some(attrs) = gradual.nth(n) else {
+                break;
+            };
+
+            if attrs.stars() > stars {
+                return true;
+            }
+
+            processed = checkpoint;
+        }
+
+        false
+    }
+
+    /// Index of the first hit object that starts within the specified time
+    /// range, or `0` if no time range is specified.
+    pub(crate) fn time_range_offset(&self, map: &Beatmap) -> usize {
+        let Some((start, end)) = self.time_range else {
+            return 0;
+        };
+
+        map.hit_objects
+            .iter()
+            .take_while(|h| h.start_time < start || h.start_time >= end)
+            .count()
+    }
+
+    /// Remove all hit objects that don't start within the specified time
+    /// range, if any.
+    pub(crate) fn apply_time_range<'a>(&self, mut map: Cow<'a, Beatmap>) -> Cow<'a, Beatmap> {
+        let Some((start, end)) = self.time_range else {
+            return map;
+        };
+
+        let in_range = |h: &HitObject| h.start_time >= start && h.start_time < end;
+
+        if map.hit_objects.iter().all(in_range) {
+            return map;
+        }
+
+        let map_mut = map.to_mut();
+        let keep: Vec<_> = map_mut.hit_objects.iter().map(in_range).collect();
+
+        // Hit sounds correspond to the hit objects of the same index
+        let mut keep_iter = keep.iter();
+        map_mut
+            .hit_objects
+            .retain(|_| keep_iter.next() == Some(&true));
+
+        let mut keep_iter = keep.iter();
+        map_mut
+            .hit_sounds
+            .retain(|_| keep_iter.next() == Some(&true));
+
+        map
+    }
 }
 
 const fn non_zero_u64_to_f64(n: NonZeroU64) -> f64 {
@@ -393,6 +1171,9 @@ impl Debug for Difficulty {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         let Self {
             mods,
+            filtered_mods: _,
+            rate_mod,
+            ignored_mods,
             passed_objects,
             clock_rate,
             ar,
@@ -401,10 +1182,24 @@ impl Debug for Difficulty {
             od,
             hardrock_offsets,
             lazer,
+            performance_base_multiplier,
+            disabled_skills,
+            slider_multiplier,
+            slider_tick_rate,
+            time_range,
+            holds_as_notes,
+            assumed_spin_rate,
         } = self;
 
+        let disabled_skills: Vec<_> = Skill::ALL
+            .into_iter()
+            .filter(|skill| disabled_skills & skill.bit() > 0)
+            .collect();
+
         f.debug_struct("Difficulty")
             .field("mods", mods)
+            .field("rate_mod", rate_mod)
+            .field("ignored_mods", ignored_mods)
             .field("passed_objects", passed_objects)
             .field("clock_rate", &clock_rate.map(non_zero_u64_to_f64))
             .field("ar", ar)
@@ -413,6 +1208,13 @@ impl Debug for Difficulty {
             .field("od", od)
             .field("hardrock_offsets", hardrock_offsets)
             .field("lazer", lazer)
+            .field("performance_base_multiplier", performance_base_multiplier)
+            .field("disabled_skills", &disabled_skills)
+            .field("slider_multiplier", slider_multiplier)
+            .field("slider_tick_rate", slider_tick_rate)
+            .field("time_range", time_range)
+            .field("holds_as_notes", holds_as_notes)
+            .field("assumed_spin_rate", assumed_spin_rate)
             .finish()
     }
 }
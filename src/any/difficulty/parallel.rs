@@ -0,0 +1,35 @@
+use rayon::prelude::*;
+
+use crate::{any::DifficultyAttributes, Beatmap, Difficulty};
+
+/// Perform the difficulty calculation for multiple maps in parallel.
+///
+/// Each map is calculated for its own mode with the same [`Difficulty`]
+/// settings. The order of the resulting attributes matches the order of
+/// `maps`.
+///
+/// Since no map is converted to another mode, the calculation cannot fail
+/// and the attributes are returned directly instead of wrapped in a
+/// `Result<_, ConvertError>`. Use [`Difficulty::checked_calculate`] on
+/// each map to calculate for a specific mode.
+///
+/// # Example
+///
+/// ```
+/// use rosu_pp::{Beatmap, Difficulty};
+///
+/// let maps = [
+///     Beatmap::from_path("./resources/2785319.osu").unwrap(),
+///     Beatmap::from_path("./resources/1028484.osu").unwrap(),
+/// ];
+///
+/// let attrs = rosu_pp::calculate_many(&maps, &Difficulty::new().mods(8));
+///
+/// assert_eq!(attrs.len(), 2);
+/// assert_eq!(attrs[1], Difficulty::new().mods(8).calculate(&maps[1]));
+/// ```
+pub fn calculate_many(maps: &[Beatmap], difficulty: &Difficulty) -> Vec<DifficultyAttributes> {
+    maps.par_iter()
+        .map(|map| difficulty.calculate(map))
+        .collect()
+}
@@ -40,6 +40,8 @@ pub trait StrainSkill: Sized {
     fn into_difficulty_value(self) -> f64;
 
     fn cloned_difficulty_value(&self) -> f64;
+
+    fn object_strains(&self) -> &[f64];
 }
 
 pub trait StrainDecaySkill: StrainSkill {
@@ -0,0 +1,52 @@
+use crate::{
+    any::{PerformanceAttributes, ScoreState},
+    Beatmap, Difficulty, GameMods, Performance,
+};
+
+/// Calculate the performance of a score for both osu!lazer and osu!stable.
+///
+/// Returns the lazer attributes first and the stable attributes second.
+/// Difficulty attributes are only calculated once and shared between both
+/// calculations since only performance calculation differs between the two
+/// clients.
+///
+/// # Example
+///
+/// ```
+/// use rosu_pp::{any::{lazer_vs_stable, ScoreState}, Beatmap};
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+///
+/// let state = ScoreState {
+///     max_combo: 900,
+///     n300: 580,
+///     n100: 15,
+///     misses: 6,
+///     ..ScoreState::default()
+/// };
+///
+/// let (lazer, stable) = lazer_vs_stable(&map, 8, state);
+/// assert!(lazer.pp() > 0.0 && stable.pp() > 0.0);
+/// ```
+pub fn lazer_vs_stable(
+    map: &Beatmap,
+    mods: impl Into<GameMods>,
+    state: ScoreState,
+) -> (PerformanceAttributes, PerformanceAttributes) {
+    let mods = mods.into();
+    let attrs = Difficulty::new().mods(mods.clone()).calculate(map);
+
+    let lazer = Performance::new(attrs.clone())
+        .mods(mods.clone())
+        .lazer(true)
+        .state(state.clone())
+        .calculate();
+
+    let stable = Performance::new(attrs)
+        .mods(mods)
+        .lazer(false)
+        .state(state)
+        .calculate();
+
+    (lazer, stable)
+}
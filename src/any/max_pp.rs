@@ -0,0 +1,29 @@
+use crate::{Beatmap, GameMods, Performance};
+
+/// The pp of an SS on the given map with the given mods.
+///
+/// No legitimate score on the map with these mods can be worth more than
+/// this value, so it serves as an upper bound for sanity checks e.g. when
+/// moderating submitted scores. Since it goes through the regular
+/// [`Performance`] calculation, the bound tracks any algorithm updates.
+///
+/// # Example
+///
+/// ```
+/// use rosu_pp::{any::theoretical_max_pp, Beatmap, Performance};
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+/// let max_pp = theoretical_max_pp(&map, 8 + 64);
+///
+/// let pp = Performance::new(&map)
+///     .mods(8 + 64)
+///     .accuracy(98.5)
+///     .misses(2)
+///     .calculate()
+///     .pp();
+///
+/// assert!(pp <= max_pp);
+/// ```
+pub fn theoretical_max_pp(map: &Beatmap, mods: impl Into<GameMods>) -> f64 {
+    Performance::new(map).mods(mods).calculate().pp()
+}
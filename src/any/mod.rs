@@ -1,19 +1,35 @@
 pub use self::{
-    attributes::{DifficultyAttributes, PerformanceAttributes},
+    attributes::{AllModeAttributes, DifficultyAttributes, PerformanceAttributes},
+    combo::object_combo_values,
+    curve::performance_curve,
     difficulty::{
-        gradual::GradualDifficulty, inspect::InspectDifficulty, Difficulty, ModsDependent,
+        gradual::{GradualDifficulty, GradualStrains},
+        inspect::InspectDifficulty,
+        Difficulty, ModsDependent, Skill,
     },
+    lazer_stable::lazer_vs_stable,
+    max_pp::theoretical_max_pp,
     performance::{
         gradual::GradualPerformance,
         into::{IntoModePerformance, IntoPerformance},
+        judgement::Judgement,
         HitResultPriority, Performance,
     },
     score_state::ScoreState,
     strains::Strains,
+    verify::{verify, VerifyError},
 };
 
+#[cfg(feature = "rayon")]
+pub use self::difficulty::parallel::calculate_many;
+
 mod attributes;
+mod combo;
+mod curve;
 pub(crate) mod difficulty;
-mod performance;
+mod lazer_stable;
+mod max_pp;
+pub(crate) mod performance;
 mod score_state;
 mod strains;
+mod verify;
@@ -1,7 +1,7 @@
 use rosu_map::section::general::GameMode;
 
 use crate::{
-    any::{PerformanceAttributes, ScoreState},
+    any::{performance::judgement::JudgementTotals, Judgement, PerformanceAttributes, ScoreState},
     catch::{Catch, CatchGradualPerformance},
     mania::{Mania, ManiaGradualPerformance},
     model::mode::{ConvertError, IGameMode},
@@ -153,6 +153,129 @@ impl GradualPerformance {
         }
     }
 
+    /// Add the [`Judgement`] of the next hit object to the running score,
+    /// process the object, and calculate the performance attributes for the
+    /// resulting score.
+    ///
+    /// Unlike [`next`](Self::next), the score is accumulated internally so
+    /// only the judgement of the object itself is required. Judgements that
+    /// do not complete a hit object, such as slider ticks or osu!catch tiny
+    /// droplets, should be added through
+    /// [`add_judgement`](Self::add_judgement) beforehand. The running score
+    /// is independent of the states that are passed to the other methods.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{any::Judgement, Beatmap, Difficulty, GradualPerformance};
+    ///
+    /// let map = Beatmap::from_path("./resources/1028484.osu").unwrap();
+    /// let mut gradual = GradualPerformance::new(Difficulty::new(), &map);
+    ///
+    /// gradual.next_delta(Judgement::Great).unwrap();
+    /// gradual.next_delta(Judgement::Ok).unwrap();
+    /// let attrs = gradual.next_delta(Judgement::Miss).unwrap();
+    ///
+    /// assert!(attrs.pp() > 0.0);
+    /// ```
+    pub fn next_delta(&mut self, judgement: Judgement) -> Option<PerformanceAttributes> {
+        if self.len() == 0 {
+            return None;
+        }
+
+        self.add_judgement(judgement);
+
+        let state = self.totals().state.clone();
+
+        self.next(state)
+    }
+
+    /// Add a [`Judgement`] to the running score without processing a hit
+    /// object.
+    ///
+    /// See [`next_delta`](Self::next_delta).
+    pub fn add_judgement(&mut self, judgement: Judgement) {
+        let mode = match self {
+            GradualPerformance::Osu(_) => GameMode::Osu,
+            GradualPerformance::Taiko(_) => GameMode::Taiko,
+            GradualPerformance::Catch(_) => GameMode::Catch,
+            GradualPerformance::Mania(_) => GameMode::Mania,
+        };
+
+        self.totals_mut().add(judgement, mode);
+    }
+
+    const fn totals(&self) -> &JudgementTotals {
+        match self {
+            GradualPerformance::Osu(gradual) => &gradual.totals,
+            GradualPerformance::Taiko(gradual) => &gradual.totals,
+            GradualPerformance::Catch(gradual) => &gradual.totals,
+            GradualPerformance::Mania(gradual) => &gradual.totals,
+        }
+    }
+
+    const fn totals_mut(&mut self) -> &mut JudgementTotals {
+        match self {
+            GradualPerformance::Osu(gradual) => &mut gradual.totals,
+            GradualPerformance::Taiko(gradual) => &mut gradual.totals,
+            GradualPerformance::Catch(gradual) => &mut gradual.totals,
+            GradualPerformance::Mania(gradual) => &mut gradual.totals,
+        }
+    }
+
+    /// Process everything up to the hit object at index `idx` and calculate
+    /// the performance attributes for the resulting score state.
+    ///
+    /// Unlike [`nth`](Self::nth), `idx` is not relative to the current
+    /// position so already processed objects can be revisited, e.g. to scrub
+    /// through a replay. The result is the same as calling `next` `idx + 1`
+    /// times on a new instance.
+    ///
+    /// The first call makes the calculator store a snapshot of its state
+    /// every few hundred objects so that revisiting objects does not require
+    /// processing from the start. Calculators that never seek don't store
+    /// these snapshots.
+    ///
+    /// Returns `None` if `idx` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{Beatmap, Difficulty, GradualPerformance, any::ScoreState};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    /// let mut gradual = GradualPerformance::new(Difficulty::new(), &map);
+    ///
+    /// let state = ScoreState {
+    ///     max_combo: 100,
+    ///     n300: 100,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let attrs = gradual.at_index(state.clone(), 99).unwrap();
+    ///
+    /// // Jump ahead and back again
+    /// gradual.at_index(state.clone(), 400);
+    ///
+    /// assert_eq!(gradual.at_index(state, 99), Some(attrs));
+    /// ```
+    pub fn at_index(&mut self, state: ScoreState, idx: usize) -> Option<PerformanceAttributes> {
+        match self {
+            GradualPerformance::Osu(gradual) => gradual
+                .at_index(state.into(), idx)
+                .map(PerformanceAttributes::Osu),
+            GradualPerformance::Taiko(gradual) => gradual
+                .at_index(state.into(), idx)
+                .map(PerformanceAttributes::Taiko),
+            GradualPerformance::Catch(gradual) => gradual
+                .at_index(state.into(), idx)
+                .map(PerformanceAttributes::Catch),
+            GradualPerformance::Mania(gradual) => gradual
+                .at_index(state.into(), idx)
+                .map(PerformanceAttributes::Mania),
+        }
+    }
+
     /// Returns the amount of remaining objects.
     #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
@@ -164,3 +287,107 @@ impl GradualPerformance {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn at_index() {
+        let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+        let difficulty = Difficulty::new().mods(88); // HDHRDT
+
+        let modes = [
+            GameMode::Osu,
+            GameMode::Taiko,
+            GameMode::Catch,
+            GameMode::Mania,
+        ];
+
+        for mode in modes {
+            let new = || GradualPerformance::new_with_mode(difficulty.clone(), &map, mode).unwrap();
+
+            let mut seeking = new();
+            let n_objects = seeking.len();
+
+            let state = |idx: usize| ScoreState {
+                max_combo: idx as u32,
+                n300: idx as u32,
+                misses: 1,
+                ..Default::default()
+            };
+
+            for idx in [300, 10, 0, 600, 257, 256, 255, 2, 1, n_objects - 1] {
+                let Some(attrs) = seeking.at_index(state(idx), idx) else {
+                    assert!(idx >= n_objects, "{mode:?}: {idx}");
+
+                    continue;
+                };
+
+                let expected = new().nth(state(idx), idx).unwrap();
+
+                assert_eq!(attrs, expected, "{mode:?}: {idx}");
+                assert_eq!(seeking.len(), n_objects - idx - 1, "{mode:?}: {idx}");
+            }
+
+            assert!(seeking.at_index(state(0), n_objects).is_none());
+        }
+    }
+
+    #[test]
+    fn next_delta() {
+        let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+        let difficulty = Difficulty::new().mods(88); // HDHRDT
+
+        let modes = [
+            GameMode::Osu,
+            GameMode::Taiko,
+            GameMode::Catch,
+            GameMode::Mania,
+        ];
+
+        for mode in modes {
+            let new = || GradualPerformance::new_with_mode(difficulty.clone(), &map, mode).unwrap();
+
+            let mut delta = new();
+            let mut cumulative = new();
+            let mut state = ScoreState::default();
+            let mut combo = 0;
+
+            for idx in 0.. {
+                let judgement = match idx % 7 {
+                    3 => Judgement::Miss,
+                    5 => Judgement::Ok,
+                    _ => Judgement::Great,
+                };
+
+                match judgement {
+                    Judgement::Miss => {
+                        state.misses += 1;
+                        combo = 0;
+                    }
+                    Judgement::Ok => {
+                        state.n100 += 1;
+                        combo += 1;
+                    }
+                    _ => {
+                        state.n300 += 1;
+                        combo += 1;
+                    }
+                }
+
+                state.max_combo = state.max_combo.max(combo);
+
+                let Some(actual) = delta.next_delta(judgement) else {
+                    assert!(cumulative.next(state.clone()).is_none(), "{mode:?}: {idx}");
+
+                    break;
+                };
+
+                let expected = cumulative.next(state.clone()).unwrap();
+
+                assert_eq!(actual, expected, "{mode:?}: {idx}");
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use rosu_map::section::general::GameMode;
+
+use crate::any::ScoreState;
+
+/// The result of a single judgement, e.g. of a hit object or a slider tick.
+///
+/// Used to update the running score of a [`GradualPerformance`] through
+/// [`GradualPerformance::next_delta`] and
+/// [`GradualPerformance::add_judgement`].
+///
+/// [`GradualPerformance`]: crate::GradualPerformance
+/// [`GradualPerformance::next_delta`]: crate::GradualPerformance::next_delta
+/// [`GradualPerformance::add_judgement`]: crate::GradualPerformance::add_judgement
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Judgement {
+    /// A 320 in osu!mania.
+    Perfect,
+    /// A 300, or a fruit for osu!catch.
+    Great,
+    /// A 200 in osu!mania, or a missed tiny droplet for osu!catch.
+    Good,
+    /// A 100, or a droplet for osu!catch.
+    Ok,
+    /// A 50, or a tiny droplet for osu!catch.
+    Meh,
+    /// A missed hit object, or a missed fruit or droplet for osu!catch.
+    Miss,
+    /// A hit slider tick or repeat in osu!standard.
+    ///
+    /// See [`ScoreState::osu_large_tick_hits`].
+    LargeTickHit,
+    /// A missed slider tick or repeat in osu!standard.
+    LargeTickMiss,
+    /// A hit small tick in osu!standard.
+    ///
+    /// See [`ScoreState::osu_small_tick_hits`].
+    SmallTickHit,
+    /// A hit slider end in osu!standard.
+    SliderTailHit,
+}
+
+/// Running totals of [`Judgement`]s.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct JudgementTotals {
+    pub(crate) state: ScoreState,
+    combo: u32,
+}
+
+impl JudgementTotals {
+    /// Add a [`Judgement`] of a map with the given [`GameMode`].
+    pub(crate) fn add(&mut self, judgement: Judgement, mode: GameMode) {
+        let state = &mut self.state;
+
+        let field = match judgement {
+            Judgement::Perfect => &mut state.n_geki,
+            Judgement::Great => &mut state.n300,
+            Judgement::Good => &mut state.n_katu,
+            Judgement::Ok => &mut state.n100,
+            Judgement::Meh => &mut state.n50,
+            Judgement::Miss => &mut state.misses,
+            Judgement::LargeTickHit => &mut state.osu_large_tick_hits,
+            Judgement::LargeTickMiss => {
+                self.combo = 0;
+
+                return;
+            }
+            Judgement::SmallTickHit => &mut state.osu_small_tick_hits,
+            Judgement::SliderTailHit => &mut state.slider_end_hits,
+        };
+
+        *field += 1;
+
+        match (judgement, mode) {
+            (Judgement::Miss, _) => self.combo = 0,
+            // Tiny droplets don't affect the combo
+            (Judgement::Good | Judgement::Meh, GameMode::Catch) => {}
+            _ => {
+                self.combo += 1;
+                state.max_combo = state.max_combo.max(self.combo);
+            }
+        }
+    }
+}
@@ -11,6 +11,7 @@ use super::{attributes::PerformanceAttributes, score_state::ScoreState};
 
 pub mod gradual;
 pub mod into;
+pub mod judgement;
 
 /// Performance calculator on maps of any mode.
 #[derive(Clone, Debug, PartialEq)]
@@ -49,6 +50,8 @@ impl<'map> Performance<'map> {
 
     /// Consume the performance calculator and calculate
     /// performance attributes for the given parameters.
+    ///
+    /// Maps without hit objects result in zero pp.
     #[allow(clippy::missing_panics_doc)]
     pub fn calculate(self) -> PerformanceAttributes {
         match self {
@@ -365,6 +368,17 @@ impl<'map> Performance<'map> {
         }
     }
 
+    /// Specify the amount of slider breaks.
+    ///
+    /// Only relevant for osu!standard.
+    pub fn slider_breaks(self, slider_breaks: u32) -> Self {
+        if let Self::Osu(osu) = self {
+            Self::Osu(osu.slider_breaks(slider_breaks))
+        } else {
+            self
+        }
+    }
+
     /// Specify the amount of 300s of a play.
     pub fn n300(self, n300: u32) -> Self {
         match self {
@@ -442,10 +456,34 @@ pub enum HitResultPriority {
     WorstCase,
     /// Prioritize fast hitresults generation
     Fastest,
+    /// Distribute hitresults between the best and worst case so that they
+    /// match the given accuracy.
+    ///
+    /// If no accuracy is specified, there is nothing to balance towards so
+    /// the remaining hitresults are generated like for [`BestCase`].
+    ///
+    /// [`BestCase`]: HitResultPriority::BestCase
+    Balanced,
 }
 
 impl HitResultPriority {
     pub(crate) const DEFAULT: Self = Self::BestCase;
+
+    /// Split `remaining` hitresults between the best and the worst bucket so
+    /// that the resulting accuracy is as close as possible to `acc`.
+    ///
+    /// `worst_acc` and `best_acc` are the accuracies if all `remaining`
+    /// hitresults went into the worst or best bucket, respectively. Returns
+    /// the amount for the best bucket.
+    pub(crate) fn balanced_split(remaining: u32, acc: f64, worst_acc: f64, best_acc: f64) -> u32 {
+        if best_acc <= worst_acc {
+            return remaining;
+        }
+
+        let ratio = ((acc - worst_acc) / (best_acc - worst_acc)).clamp(0.0, 1.0);
+
+        (ratio * f64::from(remaining)).round() as u32
+    }
 }
 
 impl Default for HitResultPriority {
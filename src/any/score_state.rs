@@ -1,7 +1,10 @@
 use rosu_map::section::general::GameMode;
 
 use crate::{
-    catch::CatchScoreState, mania::ManiaScoreState, osu::OsuScoreState, taiko::TaikoScoreState,
+    catch::CatchScoreState,
+    mania::ManiaScoreState,
+    osu::{OsuScoreOrigin, OsuScoreState},
+    taiko::TaikoScoreState,
 };
 
 /// Aggregation for a score's current state.
@@ -85,6 +88,21 @@ impl ScoreState {
 
         amount
     }
+
+    /// Calculate the accuracy between `0.0` and `1.0` for this state based
+    /// on the mode.
+    ///
+    /// For osu!standard, slider ticks and ends are not considered, i.e. the
+    /// accuracy is calculated as on osu!stable. For osu!mania, the accuracy
+    /// uses osu!lazer's weighting without the `Classic` mod.
+    pub fn accuracy(&self, mode: GameMode) -> f64 {
+        match mode {
+            GameMode::Osu => OsuScoreState::from(self.clone()).accuracy(OsuScoreOrigin::Stable),
+            GameMode::Taiko => TaikoScoreState::from(self.clone()).accuracy(),
+            GameMode::Catch => CatchScoreState::from(self.clone()).accuracy(),
+            GameMode::Mania => ManiaScoreState::from(self.clone()).accuracy(false),
+        }
+    }
 }
 
 impl From<ScoreState> for OsuScoreState {
@@ -1,4 +1,11 @@
-use crate::{catch::CatchStrains, mania::ManiaStrains, osu::OsuStrains, taiko::TaikoStrains};
+use std::cmp;
+
+use rosu_map::section::general::GameMode;
+
+use crate::{
+    catch::CatchStrains, mania::ManiaStrains, model::mode::ConvertError, osu::OsuStrains,
+    taiko::TaikoStrains, Beatmap, Difficulty,
+};
 
 /// The result of calculating the strains on a map.
 ///
@@ -21,6 +28,180 @@ impl Strains {
             Strains::Mania(_) => ManiaStrains::SECTION_LEN,
         }
     }
+
+    /// Map each strain section to the range of hitobject indices it covers.
+    ///
+    /// The `i`th tuple contains the start index (inclusive) and end index
+    /// (exclusive) of the hitobjects within the `i`th section. Sections
+    /// without objects result in an empty range.
+    ///
+    /// The given [`Difficulty`] and [`Beatmap`] must be the ones that were
+    /// used to calculate the strains. Converted maps are converted again
+    /// so the indices refer to the converted hitobjects. If a
+    /// [`time_range`] is specified, the sections only cover objects within
+    /// it but indices still refer to all of the map's hitobjects.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    /// let difficulty = Difficulty::new();
+    /// let strains = difficulty.strains(&map);
+    ///
+    /// let sections = strains.section_object_indices(&difficulty, &map).unwrap();
+    ///
+    /// assert_eq!(sections.first().map(|(start, _)| *start), Some(0));
+    /// assert_eq!(sections.last().map(|(_, end)| *end), Some(map.hit_objects.len()));
+    /// ```
+    ///
+    /// [`time_range`]: Difficulty::time_range
+    pub fn section_object_indices(
+        &self,
+        difficulty: &Difficulty,
+        map: &Beatmap,
+    ) -> Result<Vec<(usize, usize)>, ConvertError> {
+        let (mode, n_sections) = match self {
+            Strains::Osu(strains) => (GameMode::Osu, strains.aim.len()),
+            Strains::Taiko(strains) => (GameMode::Taiko, strains.color.len()),
+            Strains::Catch(strains) => (GameMode::Catch, strains.movement.len()),
+            Strains::Mania(strains) => (GameMode::Mania, strains.strains.len()),
+        };
+
+        let map = map.convert_ref(mode, difficulty.get_mods())?;
+        let offset = difficulty.time_range_offset(&map);
+        let map = difficulty.apply_time_range(map);
+
+        let Some(last_section) = n_sections.checked_sub(1) else {
+            return Ok(Vec::new());
+        };
+
+        let section_len = self.section_len();
+        let clock_rate = difficulty.get_clock_rate();
+        let take = cmp::min(difficulty.get_passed_objects(), map.hit_objects.len());
+        let mut counts = vec![0; n_sections];
+
+        // The first object has no difficulty object so it's always part of
+        // the first section.
+        counts[0] = usize::from(take > 0);
+
+        let mut times = map.hit_objects[..take]
+            .iter()
+            .skip(1)
+            .map(|h| h.start_time / clock_rate);
+
+        if let Some(first_time) = times.next() {
+            let first_section_end = (first_time / section_len).ceil() * section_len;
+            counts[0] += 1;
+
+            for time in times {
+                let section = if time > first_section_end {
+                    ((time - first_section_end) / section_len).ceil() as usize
+                } else {
+                    0
+                };
+
+                counts[cmp::min(section, last_section)] += 1;
+            }
+        }
+
+        let mut start = offset;
+
+        let sections = counts
+            .into_iter()
+            .map(|count| {
+                let range = (start, start + count);
+                start += count;
+
+                range
+            })
+            .collect();
+
+        Ok(sections)
+    }
+
+    /// The sum of all skills' strains for each section.
+    ///
+    /// Alternative versions of a skill such as osu!standard's aim without
+    /// sliders are not included and neither is flashlight unless the mods
+    /// include `FL`.
+    fn combined(&self, difficulty: &Difficulty) -> Vec<f64> {
+        fn sum(skills: &[&[f64]]) -> Vec<f64> {
+            let len = skills.iter().map(|skill| skill.len()).max().unwrap_or(0);
+
+            (0..len)
+                .map(|i| skills.iter().filter_map(|skill| skill.get(i)).sum())
+                .collect()
+        }
+
+        match self {
+            Strains::Osu(strains) if difficulty.get_mods().fl() => {
+                sum(&[&strains.aim, &strains.speed, &strains.flashlight])
+            }
+            Strains::Osu(strains) => sum(&[&strains.aim, &strains.speed]),
+            Strains::Taiko(strains) => sum(&[
+                &strains.color,
+                &strains.reading,
+                &strains.rhythm,
+                &strains.stamina,
+            ]),
+            Strains::Catch(strains) => strains.movement.clone(),
+            Strains::Mania(strains) => strains.strains.clone(),
+        }
+    }
+
+    /// The `n` sections with the highest combined strain as
+    /// `(start_time, end_time, strain)` sorted by strain in descending order.
+    ///
+    /// See [`Difficulty::hardest_sections`].
+    pub(crate) fn hardest_sections(
+        &self,
+        difficulty: &Difficulty,
+        map: &Beatmap,
+        n: usize,
+    ) -> Result<Vec<(f64, f64, f64)>, ConvertError> {
+        let mode = match self {
+            Strains::Osu(_) => GameMode::Osu,
+            Strains::Taiko(_) => GameMode::Taiko,
+            Strains::Catch(_) => GameMode::Catch,
+            Strains::Mania(_) => GameMode::Mania,
+        };
+
+        let map = map.convert_ref(mode, difficulty.get_mods())?;
+        let map = difficulty.apply_time_range(map);
+
+        // The first section ends at the section boundary after the first
+        // difficulty object i.e. the second hitobject.
+        let Some(first_object) = map.hit_objects.get(1) else {
+            return Ok(Vec::new());
+        };
+
+        let section_len = self.section_len();
+        let clock_rate = difficulty.get_clock_rate();
+        let first_section_end =
+            (first_object.start_time / clock_rate / section_len).ceil() * section_len;
+
+        let mut sections: Vec<_> = self
+            .combined(difficulty)
+            .into_iter()
+            .enumerate()
+            .map(|(i, strain)| {
+                let start = first_section_end + (i as f64 - 1.0) * section_len;
+
+                (
+                    start * clock_rate,
+                    (start + section_len) * clock_rate,
+                    strain,
+                )
+            })
+            .collect();
+
+        sections.sort_by(|(_, _, a), (_, _, b)| b.total_cmp(a));
+        sections.truncate(n);
+
+        Ok(sections)
+    }
 }
 
 macro_rules! from_mode_strains {
@@ -0,0 +1,69 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+use crate::{Beatmap, Difficulty, GameMods, Performance};
+
+/// Verify that difficulty and performance calculation agree on a map's star
+/// rating.
+///
+/// The stars are calculated once through [`Difficulty::calculate`] and once
+/// through a [`Performance`] calculation for an SS. Both paths are expected
+/// to result in the same value so a mismatch indicates a regression.
+///
+/// # Example
+///
+/// ```
+/// use rosu_pp::{any::verify, Beatmap};
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+///
+/// assert!(verify(&map, 8 + 64).is_ok());
+/// ```
+pub fn verify(map: &Beatmap, mods: impl Into<GameMods>) -> Result<(), VerifyError> {
+    let mods = mods.into();
+
+    let difficulty = Difficulty::new().mods(mods.clone()).calculate(map).stars();
+    let performance = Performance::new(map).mods(mods).calculate().stars();
+
+    if (difficulty - performance).abs() <= VerifyError::TOLERANCE {
+        Ok(())
+    } else {
+        Err(VerifyError {
+            difficulty,
+            performance,
+        })
+    }
+}
+
+/// Error type when difficulty and performance calculation disagree on a
+/// map's star rating.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct VerifyError {
+    /// Stars as calculated through [`Difficulty::calculate`].
+    pub difficulty: f64,
+    /// Stars as calculated through [`Performance::calculate`].
+    pub performance: f64,
+}
+
+impl VerifyError {
+    /// Maximum allowed difference between the two star values.
+    pub const TOLERANCE: f64 = 1e-9;
+}
+
+impl Error for VerifyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+impl Display for VerifyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "Stars through difficulty ({}) and performance ({}) calculation differ",
+            self.difficulty, self.performance
+        )
+    }
+}
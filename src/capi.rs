@@ -1,14 +1,21 @@
 use std::{
+    collections::{HashMap, VecDeque},
     ffi::CStr,
     ffi::c_void,
     os::raw::c_char,
     panic::{catch_unwind, AssertUnwindSafe},
+    sync::atomic::{AtomicU64, Ordering},
 };
 
 use rosu_map::section::general::GameMode;
+use rosu_mods::{serde::GameModsSeed, GameMode as ModsGameMode, GameMods as GameModsLazer};
+use serde::de::DeserializeSeed;
 
 use crate::{
-    any::{DifficultyAttributes, PerformanceAttributes, ScoreState},
+    any::{DifficultyAttributes, HitResultPriority, PerformanceAttributes, ScoreState, Strains},
+    catch::CatchPerformance,
+    mania::ManiaPerformance,
+    model::mods::GameMods,
     Beatmap, Difficulty, GradualPerformance, Performance,
 };
 
@@ -23,6 +30,9 @@ pub enum RosuPpError {
     Panic = 4,
     TooSuspicious = 5,
     EndOfStream = 6,
+    ConversionFailed = 7,
+    InvalidArgument = 8,
+    BufferTooSmall = 9,
 }
 
 /// Game mode of the parsed beatmap.
@@ -41,6 +51,55 @@ impl Default for RosuPpGameMode {
     }
 }
 
+impl TryFrom<u32> for RosuPpGameMode {
+    type Error = RosuPpError;
+
+    fn try_from(mode: u32) -> Result<Self, Self::Error> {
+        match mode {
+            0 => Ok(Self::Osu),
+            1 => Ok(Self::Taiko),
+            2 => Ok(Self::Catch),
+            3 => Ok(Self::Mania),
+            _ => Err(RosuPpError::InvalidArgument),
+        }
+    }
+}
+
+/// How missing hitresults are generated, see [`HitResultPriority`].
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RosuPpHitResultPriority {
+    BestCase = 0,
+    WorstCase = 1,
+    Fastest = 2,
+    Balanced = 3,
+}
+
+impl TryFrom<u32> for RosuPpHitResultPriority {
+    type Error = RosuPpError;
+
+    fn try_from(priority: u32) -> Result<Self, Self::Error> {
+        match priority {
+            0 => Ok(Self::BestCase),
+            1 => Ok(Self::WorstCase),
+            2 => Ok(Self::Fastest),
+            3 => Ok(Self::Balanced),
+            _ => Err(RosuPpError::InvalidArgument),
+        }
+    }
+}
+
+impl From<RosuPpHitResultPriority> for HitResultPriority {
+    fn from(priority: RosuPpHitResultPriority) -> Self {
+        match priority {
+            RosuPpHitResultPriority::BestCase => Self::BestCase,
+            RosuPpHitResultPriority::WorstCase => Self::WorstCase,
+            RosuPpHitResultPriority::Fastest => Self::Fastest,
+            RosuPpHitResultPriority::Balanced => Self::Balanced,
+        }
+    }
+}
+
 /// Reason why a beatmap was flagged as suspicious.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -56,6 +115,17 @@ pub enum RosuPpSuspicion {
 }
 
 /// C representation of [`crate::any::ScoreState`].
+///
+/// Which fields are read depends on the mode:
+/// - osu!: `max_combo`, `osu_large_tick_hits`, `osu_small_tick_hits`,
+///   `slider_end_hits`, `n300`, `n100`, `n50`, `misses`
+/// - taiko: `max_combo`, `n300`, `n100`, `misses`
+/// - catch: `max_combo`, `n300` (fruits), `n100` (droplets), `n50` (tiny
+///   droplets), `n_katu` (tiny droplet misses), `misses`
+/// - mania: `n_geki` (320s), `n300`, `n_katu` (200s), `n100`, `n50`, `misses`
+///
+/// Prefer [`RosuPpCatchScoreState`] and [`RosuPpManiaScoreState`] for
+/// catch and mania scores.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Default)]
 pub struct RosuPpScoreState {
@@ -71,6 +141,30 @@ pub struct RosuPpScoreState {
     pub misses: u32,
 }
 
+/// C representation of [`crate::catch::CatchScoreState`].
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct RosuPpCatchScoreState {
+    pub max_combo: u32,
+    pub fruits: u32,
+    pub droplets: u32,
+    pub tiny_droplets: u32,
+    pub tiny_droplet_misses: u32,
+    pub misses: u32,
+}
+
+/// C representation of [`crate::mania::ManiaScoreState`].
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct RosuPpManiaScoreState {
+    pub n320: u32,
+    pub n300: u32,
+    pub n200: u32,
+    pub n100: u32,
+    pub n50: u32,
+    pub misses: u32,
+}
+
 impl From<RosuPpScoreState> for ScoreState {
     fn from(state: RosuPpScoreState) -> Self {
         Self {
@@ -107,6 +201,78 @@ pub struct RosuPpPerformanceAttributes {
     pub mode: RosuPpGameMode,
 }
 
+/// A beatmap handed out through the C API.
+///
+/// Each beatmap receives a unique id so that caches can tell maps apart even
+/// if a map is allocated at the address of a previously freed one.
+struct CBeatmap {
+    id: u64,
+    map: Beatmap,
+}
+
+impl CBeatmap {
+    fn into_raw(map: Beatmap) -> *mut c_void {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        let map = Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            map,
+        };
+
+        Box::into_raw(Box::new(map)).cast::<c_void>()
+    }
+
+    /// # Safety
+    /// - `map` must be a valid pointer returned by `CBeatmap::into_raw`.
+    const unsafe fn from_raw<'a>(map: *const c_void) -> &'a Self {
+        &*map.cast::<Self>()
+    }
+}
+
+/// Memoized difficulty attributes keyed by beatmap id and mods.
+///
+/// Once `capacity` entries are stored, the oldest entry is evicted.
+struct DifficultyCache {
+    entries: HashMap<(u64, u32), DifficultyAttributes>,
+    order: VecDeque<(u64, u32)>,
+    capacity: usize,
+}
+
+impl DifficultyCache {
+    fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+
+        Self {
+            entries: HashMap::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    fn get_or_calculate(&mut self, map: &CBeatmap, mods: u32) -> &DifficultyAttributes {
+        let key = (map.id, mods);
+
+        if !self.entries.contains_key(&key) {
+            if self.order.len() == self.capacity {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.entries.remove(&oldest);
+                }
+            }
+
+            let attrs = Difficulty::new().mods(mods).calculate(&map.map);
+            self.entries.insert(key, attrs);
+            self.order.push_back(key);
+        }
+
+        &self.entries[&key]
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+}
+
 fn mode_into_c(mode: GameMode) -> RosuPpGameMode {
     match mode {
         GameMode::Osu => RosuPpGameMode::Osu,
@@ -116,6 +282,15 @@ fn mode_into_c(mode: GameMode) -> RosuPpGameMode {
     }
 }
 
+const fn mode_from_c(mode: RosuPpGameMode) -> GameMode {
+    match mode {
+        RosuPpGameMode::Osu => GameMode::Osu,
+        RosuPpGameMode::Taiko => GameMode::Taiko,
+        RosuPpGameMode::Catch => GameMode::Catch,
+        RosuPpGameMode::Mania => GameMode::Mania,
+    }
+}
+
 fn suspicion_into_c(sus: crate::model::beatmap::TooSuspicious) -> RosuPpSuspicion {
     use crate::model::beatmap::TooSuspicious;
 
@@ -150,16 +325,18 @@ fn performance_mode(attrs: &PerformanceAttributes) -> RosuPpGameMode {
 
 fn calculate_performance(
     map: &Beatmap,
-    mods: u32,
+    mods: impl Into<GameMods>,
     accuracy: f64,
     combo: u32,
     misses: u32,
+    priority: RosuPpHitResultPriority,
 ) -> PerformanceAttributes {
     Performance::new(map)
         .mods(mods)
         .accuracy(accuracy)
         .combo(combo)
         .misses(misses)
+        .hitresult_priority(priority.into())
         .calculate()
 }
 
@@ -174,11 +351,38 @@ pub extern "C" fn rosu_pp_error_str(err: i32) -> *const c_char {
         x if x == RosuPpError::Panic as i32 => b"Panic\0".as_ptr(),
         x if x == RosuPpError::TooSuspicious as i32 => b"TooSuspicious\0".as_ptr(),
         x if x == RosuPpError::EndOfStream as i32 => b"EndOfStream\0".as_ptr(),
+        x if x == RosuPpError::ConversionFailed as i32 => b"ConversionFailed\0".as_ptr(),
+        x if x == RosuPpError::InvalidArgument as i32 => b"InvalidArgument\0".as_ptr(),
+        x if x == RosuPpError::BufferTooSmall as i32 => b"BufferTooSmall\0".as_ptr(),
         _ => b"Unknown\0".as_ptr(),
     }
     .cast::<c_char>()
 }
 
+/// Version of the C ABI.
+///
+/// Bumped whenever the layout of a `RosuPp*` struct or the signature of an
+/// exported function changes. Layouts never change without a bump.
+pub const ROSU_PP_ABI_VERSION: u32 = 1;
+
+/// NUL-terminated crate version.
+const ROSU_PP_VERSION: &str = concat!(env!("CARGO_PKG_VERSION"), "\0");
+
+/// Return the version of the C ABI.
+///
+/// Bindings should compare this against the version they were generated for
+/// before touching any `RosuPp*` struct.
+#[no_mangle]
+pub const extern "C" fn rosu_pp_abi_version() -> u32 {
+    ROSU_PP_ABI_VERSION
+}
+
+/// Return the crate version as a static, NUL-terminated string.
+#[no_mangle]
+pub const extern "C" fn rosu_pp_version_string() -> *const c_char {
+    ROSU_PP_VERSION.as_ptr().cast::<c_char>()
+}
+
 /// Create a new empty score state.
 #[no_mangle]
 pub extern "C" fn rosu_pp_score_state_new() -> RosuPpScoreState {
@@ -205,7 +409,66 @@ pub unsafe extern "C" fn rosu_pp_beatmap_from_path(
             .map_err(|_| RosuPpError::InvalidUtf8)?;
 
         let map = Beatmap::from_path(path).map_err(|_| RosuPpError::IoError)?;
-        out.write(Box::into_raw(Box::new(map)).cast::<c_void>());
+        out.write(CBeatmap::into_raw(map));
+
+        Ok(())
+    })) {
+        Ok(Ok(())) => RosuPpError::Ok,
+        Ok(Err(err)) => err,
+        Err(_) => RosuPpError::Panic,
+    }
+}
+
+/// Parse multiple beatmaps from `.osu` file paths.
+///
+/// Internal buffers are reused across all beatmaps. For each path, the
+/// parsed beatmap is written into the corresponding slot of `out`. If a
+/// beatmap fails to parse, its slot is set to `NULL` and the remaining paths
+/// are still parsed.
+///
+/// Every non-`NULL` beatmap must be freed through `rosu_pp_beatmap_free`.
+///
+/// # Safety
+/// - If `len != 0`, `paths` must be valid for reads of `len` pointers, each
+///   of which is either `NULL` or a valid, NUL-terminated string.
+/// - If `len != 0`, `out` must be valid for writes of `len` pointers.
+#[no_mangle]
+pub unsafe extern "C" fn rosu_pp_beatmap_from_paths(
+    paths: *const *const c_char,
+    len: usize,
+    out: *mut *mut c_void,
+) -> RosuPpError {
+    if len == 0 {
+        return RosuPpError::Ok;
+    }
+
+    if paths.is_null() || out.is_null() {
+        return RosuPpError::NullPointer;
+    }
+
+    match catch_unwind(AssertUnwindSafe(|| {
+        let paths = std::slice::from_raw_parts(paths, len);
+
+        let valid_paths = paths
+            .iter()
+            .filter(|path| !path.is_null())
+            .filter_map(|&path| CStr::from_ptr(path).to_str().ok());
+
+        let mut maps = Beatmap::from_paths(valid_paths).into_iter();
+
+        for (i, &path) in paths.iter().enumerate() {
+            let is_valid = !path.is_null() && CStr::from_ptr(path).to_str().is_ok();
+
+            let map = if is_valid {
+                maps.next().and_then(Result::ok)
+            } else {
+                None
+            };
+
+            let ptr = map.map_or(std::ptr::null_mut(), CBeatmap::into_raw);
+
+            out.add(i).write(ptr);
+        }
 
         Ok(())
     })) {
@@ -238,7 +501,7 @@ pub unsafe extern "C" fn rosu_pp_beatmap_from_bytes(
         };
 
         let map = Beatmap::from_bytes(bytes).map_err(|_| RosuPpError::IoError)?;
-        out.write(Box::into_raw(Box::new(map)).cast::<c_void>());
+        out.write(CBeatmap::into_raw(map));
 
         Ok(())
     })) {
@@ -258,7 +521,9 @@ pub unsafe extern "C" fn rosu_pp_beatmap_free(map: *mut c_void) {
         return;
     }
 
-    let _ = catch_unwind(AssertUnwindSafe(|| drop(Box::from_raw(map.cast::<Beatmap>()))));
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        drop(Box::from_raw(map.cast::<CBeatmap>()));
+    }));
 }
 
 /// Get a beatmap's mode.
@@ -273,7 +538,7 @@ pub unsafe extern "C" fn rosu_pp_beatmap_mode(map: *const c_void, out: *mut Rosu
     }
 
     match catch_unwind(AssertUnwindSafe(|| {
-        let map = &*map.cast::<Beatmap>();
+        let map = &CBeatmap::from_raw(map).map;
         out.write(mode_into_c(map.mode));
         Ok(())
     })) {
@@ -298,7 +563,7 @@ pub unsafe extern "C" fn rosu_pp_beatmap_check_suspicion(
     }
 
     match catch_unwind(AssertUnwindSafe(|| {
-        let map = &*map.cast::<Beatmap>();
+        let map = &CBeatmap::from_raw(map).map;
 
         match map.check_suspicion() {
             Ok(()) => {
@@ -319,6 +584,9 @@ pub unsafe extern "C" fn rosu_pp_beatmap_check_suspicion(
 
 /// Calculate difficulty attributes from a parsed beatmap.
 ///
+/// Beatmaps without hit objects, e.g. header-only files, return `Ok` with
+/// zero stars.
+///
 /// # Safety
 /// - `map` must be a valid pointer returned by `rosu_pp_beatmap_from_*`.
 /// - `out` must be a valid pointer to writable memory.
@@ -333,7 +601,7 @@ pub unsafe extern "C" fn rosu_pp_difficulty_calculate(
     }
 
     match catch_unwind(AssertUnwindSafe(|| {
-        let map = &*map.cast::<Beatmap>();
+        let map = &CBeatmap::from_raw(map).map;
         let attrs = Difficulty::new().mods(mods).calculate(map);
         out.write(Box::into_raw(Box::new(attrs)).cast::<c_void>());
         Ok(())
@@ -344,6 +612,124 @@ pub unsafe extern "C" fn rosu_pp_difficulty_calculate(
     }
 }
 
+/// Calculate difficulty attributes from a parsed beatmap after converting it
+/// to the given mode.
+///
+/// `mode` must be the value of a `RosuPpGameMode` variant.
+///
+/// Returns `InvalidArgument` if `mode` is not a valid variant and
+/// `ConversionFailed` if the beatmap cannot be converted to `mode`, e.g.
+/// because it is not an osu!standard beatmap.
+///
+/// # Safety
+/// - `map` must be a valid pointer returned by `rosu_pp_beatmap_from_*`.
+/// - `out` must be a valid pointer to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn rosu_pp_difficulty_calculate_mode(
+    map: *const c_void,
+    mode: u32,
+    mods: u32,
+    out: *mut *mut c_void,
+) -> RosuPpError {
+    if map.is_null() || out.is_null() {
+        return RosuPpError::NullPointer;
+    }
+
+    match catch_unwind(AssertUnwindSafe(|| {
+        let mode = RosuPpGameMode::try_from(mode)?;
+        let map = &CBeatmap::from_raw(map).map;
+        let mods = GameMods::from(mods);
+
+        let map = map
+            .convert_ref(mode_from_c(mode), &mods)
+            .map_err(|_| RosuPpError::ConversionFailed)?;
+
+        let attrs = Difficulty::new().mods(mods).calculate(&map);
+        out.write(Box::into_raw(Box::new(attrs)).cast::<c_void>());
+        Ok(())
+    })) {
+        Ok(Ok(())) => RosuPpError::Ok,
+        Ok(Err(err)) => err,
+        Err(_) => RosuPpError::Panic,
+    }
+}
+
+/// Calculate the strain peaks of a single skill of a parsed beatmap.
+///
+/// The skill is selected by its index depending on the beatmap's mode:
+/// - osu!: 0 aim, 1 aim without sliders, 2 speed, 3 flashlight
+/// - taiko: 0 color, 1 reading, 2 rhythm, 3 stamina, 4 single color stamina
+/// - catch: 0 movement
+/// - mania: 0 strain
+///
+/// Strain peaks are 400ms apart, or 750ms for osu!catch.
+///
+/// If `buf` is `NULL`, only the amount of strain peaks is written into `len`.
+/// Otherwise `len` must contain the capacity of `buf` and will be set to the
+/// amount of written values.
+///
+/// Returns `InvalidArgument` if `skill` is out of range and `BufferTooSmall`
+/// if `buf` cannot hold all strain peaks; `len` then holds the required
+/// capacity.
+///
+/// # Safety
+/// - `map` must be a valid pointer returned by `rosu_pp_beatmap_from_*`.
+/// - `len` must be a valid pointer to readable and writable memory.
+/// - If `buf` is not `NULL`, it must be valid for writes of `*len` values.
+#[no_mangle]
+pub unsafe extern "C" fn rosu_pp_difficulty_strains(
+    map: *const c_void,
+    mods: u32,
+    skill: u32,
+    buf: *mut f64,
+    len: *mut usize,
+) -> RosuPpError {
+    if map.is_null() || len.is_null() {
+        return RosuPpError::NullPointer;
+    }
+
+    match catch_unwind(AssertUnwindSafe(|| {
+        let map = &CBeatmap::from_raw(map).map;
+        let strains = Difficulty::new().mods(mods).strains(map);
+
+        let peaks = match (&strains, skill) {
+            (Strains::Osu(strains), 0) => &strains.aim,
+            (Strains::Osu(strains), 1) => &strains.aim_no_sliders,
+            (Strains::Osu(strains), 2) => &strains.speed,
+            (Strains::Osu(strains), 3) => &strains.flashlight,
+            (Strains::Taiko(strains), 0) => &strains.color,
+            (Strains::Taiko(strains), 1) => &strains.reading,
+            (Strains::Taiko(strains), 2) => &strains.rhythm,
+            (Strains::Taiko(strains), 3) => &strains.stamina,
+            (Strains::Taiko(strains), 4) => &strains.single_color_stamina,
+            (Strains::Catch(strains), 0) => &strains.movement,
+            (Strains::Mania(strains), 0) => &strains.strains,
+            _ => return Err(RosuPpError::InvalidArgument),
+        };
+
+        if buf.is_null() {
+            len.write(peaks.len());
+
+            return Ok(());
+        }
+
+        let capacity = len.read();
+        len.write(peaks.len());
+
+        if capacity < peaks.len() {
+            return Err(RosuPpError::BufferTooSmall);
+        }
+
+        std::ptr::copy_nonoverlapping(peaks.as_ptr(), buf, peaks.len());
+
+        Ok(())
+    })) {
+        Ok(Ok(())) => RosuPpError::Ok,
+        Ok(Err(err)) => err,
+        Err(_) => RosuPpError::Panic,
+    }
+}
+
 /// Free difficulty attributes created through `rosu_pp_difficulty_calculate`.
 ///
 /// # Safety
@@ -359,6 +745,93 @@ pub unsafe extern "C" fn rosu_pp_difficulty_attrs_free(attrs: *mut c_void) {
     }));
 }
 
+/// Create a new empty difficulty cache.
+///
+/// The cache memoizes difficulty attributes by beatmap and mods so that
+/// repeated calculations for the same map and mods are skipped.
+///
+/// At most `capacity` entries are stored; once full, the oldest entry is
+/// evicted. A `capacity` of `0` is treated as `1`.
+///
+/// Returns `NULL` if the cache could not be created.
+#[no_mangle]
+pub extern "C" fn rosu_pp_difficulty_cache_new(capacity: usize) -> *mut c_void {
+    catch_unwind(|| Box::into_raw(Box::new(DifficultyCache::new(capacity))).cast::<c_void>())
+        .unwrap_or(std::ptr::null_mut())
+}
+
+/// Remove all entries from a difficulty cache.
+///
+/// # Safety
+/// - `cache` must either be `NULL` or a pointer returned by `rosu_pp_difficulty_cache_new`.
+#[no_mangle]
+pub unsafe extern "C" fn rosu_pp_difficulty_cache_clear(cache: *mut c_void) {
+    if cache.is_null() {
+        return;
+    }
+
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        (*cache.cast::<DifficultyCache>()).clear();
+    }));
+}
+
+/// Free a difficulty cache created through `rosu_pp_difficulty_cache_new`.
+///
+/// # Safety
+/// - `cache` must either be `NULL` or a pointer returned by `rosu_pp_difficulty_cache_new`.
+#[no_mangle]
+pub unsafe extern "C" fn rosu_pp_difficulty_cache_free(cache: *mut c_void) {
+    if cache.is_null() {
+        return;
+    }
+
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        drop(Box::from_raw(cache.cast::<DifficultyCache>()));
+    }));
+}
+
+/// Get difficulty attributes from the cache, calculating and storing them
+/// first if the map and mods have not been requested before.
+///
+/// The written attributes are a copy of the cached ones and must be freed
+/// through `rosu_pp_difficulty_attrs_free`.
+///
+/// Entries are keyed by an id that is unique to each map so maps allocated
+/// after freeing a previous one never receive its stale attributes. Entries
+/// of freed maps stay in the cache until they are evicted or the cache is
+/// cleared.
+///
+/// # Safety
+/// - `cache` must be a valid pointer returned by `rosu_pp_difficulty_cache_new`.
+/// - `map` must be a valid pointer returned by `rosu_pp_beatmap_from_*`.
+/// - `out` must be a valid pointer to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn rosu_pp_difficulty_cache_get(
+    cache: *mut c_void,
+    map: *const c_void,
+    mods: u32,
+    out: *mut *mut c_void,
+) -> RosuPpError {
+    if cache.is_null() || map.is_null() || out.is_null() {
+        return RosuPpError::NullPointer;
+    }
+
+    match catch_unwind(AssertUnwindSafe(|| {
+        let cache = &mut *cache.cast::<DifficultyCache>();
+        let attrs = cache
+            .get_or_calculate(CBeatmap::from_raw(map), mods)
+            .clone();
+
+        out.write(Box::into_raw(Box::new(attrs)).cast::<c_void>());
+
+        Ok(())
+    })) {
+        Ok(Ok(())) => RosuPpError::Ok,
+        Ok(Err(err)) => err,
+        Err(_) => RosuPpError::Panic,
+    }
+}
+
 /// Extract common values from difficulty attributes.
 ///
 /// # Safety
@@ -403,18 +876,53 @@ pub unsafe extern "C" fn rosu_pp_performance_calculate(
     combo: u32,
     misses: u32,
     out: *mut *mut c_void,
+) -> RosuPpError {
+    rosu_pp_performance_calculate_ex(
+        difficulty,
+        mods,
+        accuracy,
+        combo,
+        misses,
+        RosuPpHitResultPriority::BestCase as u32,
+        out,
+    )
+}
+
+/// Calculate performance attributes from previously calculated difficulty
+/// attributes with the given hitresult priority.
+///
+/// Same as [`rosu_pp_performance_calculate`] except that `priority`
+/// determines how hitresults are generated for `accuracy`. It must be the
+/// value of a `RosuPpHitResultPriority` variant.
+///
+/// Returns `InvalidArgument` if `priority` is not a valid variant.
+///
+/// # Safety
+/// - `difficulty` must be a valid pointer returned by `rosu_pp_difficulty_calculate`.
+/// - `out` must be a valid pointer to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn rosu_pp_performance_calculate_ex(
+    difficulty: *const c_void,
+    mods: u32,
+    accuracy: f64,
+    combo: u32,
+    misses: u32,
+    priority: u32,
+    out: *mut *mut c_void,
 ) -> RosuPpError {
     if difficulty.is_null() || out.is_null() {
         return RosuPpError::NullPointer;
     }
 
     match catch_unwind(AssertUnwindSafe(|| {
+        let priority = RosuPpHitResultPriority::try_from(priority)?;
         let difficulty = (&*difficulty.cast::<DifficultyAttributes>()).clone();
         let attrs = Performance::new(difficulty)
             .mods(mods)
             .combo(combo)
             .accuracy(accuracy)
             .misses(misses)
+            .hitresult_priority(priority.into())
             .calculate();
 
         out.write(Box::into_raw(Box::new(attrs)).cast::<c_void>());
@@ -522,7 +1030,7 @@ pub unsafe extern "C" fn rosu_pp_gradual_performance_new(
     }
 
     match catch_unwind(AssertUnwindSafe(|| {
-        let map = &*map.cast::<Beatmap>();
+        let map = &CBeatmap::from_raw(map).map;
         let mut difficulty = Difficulty::new().mods(mods);
         if clock_rate > 0.0 {
             difficulty = difficulty.clock_rate(clock_rate);
@@ -634,6 +1142,9 @@ pub unsafe extern "C" fn rosu_pp_gradual_performance_last(
 
 /// Calculate performance attributes from a `.osu` file path.
 ///
+/// Beatmaps without hit objects, e.g. header-only files, return `Ok` with
+/// zero stars and zero pp.
+///
 /// # Safety
 /// - `path` must be a valid, NUL-terminated UTF-8 string.
 /// - `out` must be a valid pointer to writable memory.
@@ -657,7 +1168,14 @@ pub unsafe extern "C" fn rosu_pp_performance_from_path(
 
         let map = Beatmap::from_path(path).map_err(|_| RosuPpError::IoError)?;
         let mode = mode_into_c(map.mode);
-        let attrs = calculate_performance(&map, mods, accuracy, combo, misses);
+        let attrs = calculate_performance(
+            &map,
+            mods,
+            accuracy,
+            combo,
+            misses,
+            RosuPpHitResultPriority::BestCase,
+        );
 
         out.write(RosuPpPerformanceAttributes {
             pp: attrs.pp(),
@@ -674,8 +1192,94 @@ pub unsafe extern "C" fn rosu_pp_performance_from_path(
     }
 }
 
+/// Calculate performance attributes from a `.osu` file path with mods
+/// given as JSON.
+///
+/// Unlike [`rosu_pp_performance_from_path`], this supports lazer mods and
+/// their settings, e.g. `[{"acronym":"DT","settings":{"speed_change":1.3}}]`,
+/// and `priority` determines how hitresults are generated for `accuracy`.
+/// `mods_json` may also be an integer of legacy bitflags or a string of
+/// acronyms. Mods are deserialized for the beatmap's mode.
+///
+/// `priority` must be the value of a `RosuPpHitResultPriority` variant.
+///
+/// Returns `InvalidArgument` if `mods_json` could not be deserialized or if
+/// `priority` is not a valid variant.
+///
+/// # Safety
+/// - `path` and `mods_json` must be valid, NUL-terminated UTF-8 strings.
+/// - `out` must be a valid pointer to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn rosu_pp_performance_from_path_ex(
+    path: *const c_char,
+    mods_json: *const c_char,
+    accuracy: f64,
+    combo: u32,
+    misses: u32,
+    priority: u32,
+    out: *mut RosuPpPerformanceAttributes,
+) -> RosuPpError {
+    if path.is_null() || mods_json.is_null() || out.is_null() {
+        return RosuPpError::NullPointer;
+    }
+
+    match catch_unwind(AssertUnwindSafe(|| {
+        let priority = RosuPpHitResultPriority::try_from(priority)?;
+
+        let path = CStr::from_ptr(path)
+            .to_str()
+            .map_err(|_| RosuPpError::InvalidUtf8)?;
+
+        let mods_json = CStr::from_ptr(mods_json)
+            .to_str()
+            .map_err(|_| RosuPpError::InvalidUtf8)?;
+
+        let map = Beatmap::from_path(path).map_err(|_| RosuPpError::IoError)?;
+        let mode = mode_into_c(map.mode);
+        let mods = mods_from_json(mods_json, map.mode)?;
+        let attrs = calculate_performance(&map, mods, accuracy, combo, misses, priority);
+
+        out.write(RosuPpPerformanceAttributes {
+            pp: attrs.pp(),
+            stars: attrs.stars(),
+            max_combo: attrs.max_combo(),
+            mode,
+        });
+
+        Ok(())
+    })) {
+        Ok(Ok(())) => RosuPpError::Ok,
+        Ok(Err(err)) => err,
+        Err(_) => RosuPpError::Panic,
+    }
+}
+
+/// Deserialize lazer mods from JSON for the given mode.
+fn mods_from_json(json: &str, mode: GameMode) -> Result<GameModsLazer, RosuPpError> {
+    let mode = match mode {
+        GameMode::Osu => ModsGameMode::Osu,
+        GameMode::Taiko => ModsGameMode::Taiko,
+        GameMode::Catch => ModsGameMode::Catch,
+        GameMode::Mania => ModsGameMode::Mania,
+    };
+
+    let seed = GameModsSeed::Mode {
+        mode,
+        deny_unknown_fields: true,
+    };
+
+    let mut d = serde_json::Deserializer::from_str(json);
+
+    seed.deserialize(&mut d)
+        .and_then(|mods| d.end().map(|()| mods))
+        .map_err(|_| RosuPpError::InvalidArgument)
+}
+
 /// Calculate performance attributes from an in-memory `.osu` file.
 ///
+/// Empty input and beatmaps without hit objects return `Ok` with zero stars
+/// and zero pp.
+///
 /// # Safety
 /// - If `len != 0`, `bytes` must be valid for reads of `len` bytes.
 /// - `out` must be a valid pointer to writable memory.
@@ -694,14 +1298,16 @@ pub unsafe extern "C" fn rosu_pp_performance_from_bytes(
     }
 
     match catch_unwind(AssertUnwindSafe(|| {
-        let bytes = if len == 0 {
-            &[]
-        } else {
-            std::slice::from_raw_parts(bytes, len)
-        };
-        let map = Beatmap::from_bytes(bytes).map_err(|_| RosuPpError::IoError)?;
+        let map = beatmap_from_raw_bytes(bytes, len)?;
         let mode = mode_into_c(map.mode);
-        let attrs = calculate_performance(&map, mods, accuracy, combo, misses);
+        let attrs = calculate_performance(
+            &map,
+            mods,
+            accuracy,
+            combo,
+            misses,
+            RosuPpHitResultPriority::BestCase,
+        );
 
         out.write(RosuPpPerformanceAttributes {
             pp: attrs.pp(),
@@ -717,3 +1323,472 @@ pub unsafe extern "C" fn rosu_pp_performance_from_bytes(
         Err(_) => RosuPpError::Panic,
     }
 }
+
+/// Calculate performance attributes from an in-memory `.osu` file with mods
+/// given as JSON.
+///
+/// Mods and `priority` are handled the same way as for
+/// [`rosu_pp_performance_from_path_ex`].
+///
+/// Returns `InvalidArgument` if `mods_json` could not be deserialized or if
+/// `priority` is not a valid variant.
+///
+/// # Safety
+/// - If `len != 0`, `bytes` must be valid for reads of `len` bytes.
+/// - `mods_json` must be a valid, NUL-terminated UTF-8 string.
+/// - `out` must be a valid pointer to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn rosu_pp_performance_from_bytes_ex(
+    bytes: *const u8,
+    len: usize,
+    mods_json: *const c_char,
+    accuracy: f64,
+    combo: u32,
+    misses: u32,
+    priority: u32,
+    out: *mut RosuPpPerformanceAttributes,
+) -> RosuPpError {
+    if out.is_null() || mods_json.is_null() || (bytes.is_null() && len != 0) {
+        return RosuPpError::NullPointer;
+    }
+
+    match catch_unwind(AssertUnwindSafe(|| {
+        let priority = RosuPpHitResultPriority::try_from(priority)?;
+
+        let mods_json = CStr::from_ptr(mods_json)
+            .to_str()
+            .map_err(|_| RosuPpError::InvalidUtf8)?;
+
+        let map = beatmap_from_raw_bytes(bytes, len)?;
+        let mode = mode_into_c(map.mode);
+        let mods = mods_from_json(mods_json, map.mode)?;
+        let attrs = calculate_performance(&map, mods, accuracy, combo, misses, priority);
+
+        out.write(RosuPpPerformanceAttributes {
+            pp: attrs.pp(),
+            stars: attrs.stars(),
+            max_combo: attrs.max_combo(),
+            mode,
+        });
+
+        Ok(())
+    })) {
+        Ok(Ok(())) => RosuPpError::Ok,
+        Ok(Err(err)) => err,
+        Err(_) => RosuPpError::Panic,
+    }
+}
+
+/// Decode a beatmap from a pointer and length where a length of zero
+/// denotes empty input.
+unsafe fn beatmap_from_raw_bytes(bytes: *const u8, len: usize) -> Result<Beatmap, RosuPpError> {
+    let bytes = if len == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(bytes, len)
+    };
+
+    Beatmap::from_bytes(bytes).map_err(|_| RosuPpError::IoError)
+}
+
+/// Calculate osu!catch performance attributes from a `.osu` file path.
+///
+/// Every count of `state` is passed on as is and clamped the same way
+/// [`CatchPerformance`] clamps them. osu!standard beatmaps are converted.
+///
+/// Returns `ConversionFailed` if the beatmap is neither an osu!standard nor
+/// an osu!catch beatmap.
+///
+/// # Safety
+/// - `path` must be a valid, NUL-terminated UTF-8 string.
+/// - `out` must be a valid pointer to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn rosu_pp_performance_catch_from_path(
+    path: *const c_char,
+    mods: u32,
+    state: RosuPpCatchScoreState,
+    out: *mut RosuPpPerformanceAttributes,
+) -> RosuPpError {
+    if path.is_null() || out.is_null() {
+        return RosuPpError::NullPointer;
+    }
+
+    match catch_unwind(AssertUnwindSafe(|| {
+        let path = CStr::from_ptr(path)
+            .to_str()
+            .map_err(|_| RosuPpError::InvalidUtf8)?;
+
+        let map = Beatmap::from_path(path).map_err(|_| RosuPpError::IoError)?;
+
+        let attrs = CatchPerformance::new(&map)
+            .mods(mods)
+            .combo(state.max_combo)
+            .fruits(state.fruits)
+            .droplets(state.droplets)
+            .tiny_droplets(state.tiny_droplets)
+            .tiny_droplet_misses(state.tiny_droplet_misses)
+            .misses(state.misses)
+            .calculate()
+            .map_err(|_| RosuPpError::ConversionFailed)?;
+
+        out.write(RosuPpPerformanceAttributes {
+            pp: attrs.pp,
+            stars: attrs.stars(),
+            max_combo: attrs.max_combo(),
+            mode: RosuPpGameMode::Catch,
+        });
+
+        Ok(())
+    })) {
+        Ok(Ok(())) => RosuPpError::Ok,
+        Ok(Err(err)) => err,
+        Err(_) => RosuPpError::Panic,
+    }
+}
+
+/// Calculate osu!mania performance attributes from a `.osu` file path.
+///
+/// Every count of `state` is passed on as is and clamped the same way
+/// [`ManiaPerformance`] clamps them. osu!standard beatmaps are converted.
+///
+/// Returns `ConversionFailed` if the beatmap is neither an osu!standard nor
+/// an osu!mania beatmap.
+///
+/// # Safety
+/// - `path` must be a valid, NUL-terminated UTF-8 string.
+/// - `out` must be a valid pointer to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn rosu_pp_performance_mania_from_path(
+    path: *const c_char,
+    mods: u32,
+    state: RosuPpManiaScoreState,
+    out: *mut RosuPpPerformanceAttributes,
+) -> RosuPpError {
+    if path.is_null() || out.is_null() {
+        return RosuPpError::NullPointer;
+    }
+
+    match catch_unwind(AssertUnwindSafe(|| {
+        let path = CStr::from_ptr(path)
+            .to_str()
+            .map_err(|_| RosuPpError::InvalidUtf8)?;
+
+        let map = Beatmap::from_path(path).map_err(|_| RosuPpError::IoError)?;
+
+        let attrs = ManiaPerformance::new(&map)
+            .mods(mods)
+            .n320(state.n320)
+            .n300(state.n300)
+            .n200(state.n200)
+            .n100(state.n100)
+            .n50(state.n50)
+            .misses(state.misses)
+            .calculate()
+            .map_err(|_| RosuPpError::ConversionFailed)?;
+
+        out.write(RosuPpPerformanceAttributes {
+            pp: attrs.pp,
+            stars: attrs.stars(),
+            max_combo: attrs.max_combo(),
+            mode: RosuPpGameMode::Mania,
+        });
+
+        Ok(())
+    })) {
+        Ok(Ok(())) => RosuPpError::Ok,
+        Ok(Err(err)) => err,
+        Err(_) => RosuPpError::Panic,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, mem::MaybeUninit, ptr};
+
+    use super::*;
+
+    const OSU: &str = "./resources/2785319.osu";
+
+    fn path() -> CString {
+        CString::new(OSU).unwrap()
+    }
+
+    unsafe fn from_path(mods: u32) -> RosuPpPerformanceAttributes {
+        let mut out = MaybeUninit::uninit();
+        let err =
+            rosu_pp_performance_from_path(path().as_ptr(), mods, 95.0, 500, 2, out.as_mut_ptr());
+        assert_eq!(err, RosuPpError::Ok);
+
+        out.assume_init()
+    }
+
+    unsafe fn from_path_ex(
+        mods_json: &str,
+        priority: u32,
+    ) -> Result<RosuPpPerformanceAttributes, RosuPpError> {
+        let mods_json = CString::new(mods_json).unwrap();
+        let mut out = MaybeUninit::uninit();
+
+        let err = rosu_pp_performance_from_path_ex(
+            path().as_ptr(),
+            mods_json.as_ptr(),
+            95.0,
+            500,
+            2,
+            priority,
+            out.as_mut_ptr(),
+        );
+
+        match err {
+            RosuPpError::Ok => Ok(out.assume_init()),
+            err => Err(err),
+        }
+    }
+
+    #[test]
+    fn ex_variants_default_to_best_case() {
+        unsafe {
+            let plain = from_path(8);
+            let ex = from_path_ex("8", RosuPpHitResultPriority::BestCase as u32).unwrap();
+            assert_eq!(plain.pp.to_bits(), ex.pp.to_bits());
+
+            let bytes = std::fs::read(OSU).unwrap();
+            let mods_json = CString::new("8").unwrap();
+            let mut out = MaybeUninit::uninit();
+
+            let err = rosu_pp_performance_from_bytes(
+                bytes.as_ptr(),
+                bytes.len(),
+                8,
+                95.0,
+                500,
+                2,
+                out.as_mut_ptr(),
+            );
+            assert_eq!(err, RosuPpError::Ok);
+            assert_eq!(out.assume_init().pp.to_bits(), plain.pp.to_bits());
+
+            let err = rosu_pp_performance_from_bytes_ex(
+                bytes.as_ptr(),
+                bytes.len(),
+                mods_json.as_ptr(),
+                95.0,
+                500,
+                2,
+                RosuPpHitResultPriority::BestCase as u32,
+                out.as_mut_ptr(),
+            );
+            assert_eq!(err, RosuPpError::Ok);
+            assert_eq!(out.assume_init().pp.to_bits(), plain.pp.to_bits());
+
+            let err = rosu_pp_performance_from_bytes_ex(
+                bytes.as_ptr(),
+                bytes.len(),
+                ptr::null(),
+                95.0,
+                500,
+                2,
+                RosuPpHitResultPriority::BestCase as u32,
+                out.as_mut_ptr(),
+            );
+            assert_eq!(err, RosuPpError::NullPointer);
+        }
+    }
+
+    #[test]
+    fn ex_variants_respect_mod_settings() {
+        unsafe {
+            let ss = |mods_json: &str| {
+                let mods_json = CString::new(mods_json).unwrap();
+                let mut out = MaybeUninit::uninit();
+
+                let err = rosu_pp_performance_from_path_ex(
+                    path().as_ptr(),
+                    mods_json.as_ptr(),
+                    100.0,
+                    u32::MAX,
+                    0,
+                    RosuPpHitResultPriority::BestCase as u32,
+                    out.as_mut_ptr(),
+                );
+                assert_eq!(err, RosuPpError::Ok);
+
+                out.assume_init().pp
+            };
+
+            let dt = ss("64");
+            let custom_dt = ss(r#"[{"acronym":"DT","settings":{"speed_change":1.3}}]"#);
+
+            assert!((dt - 741.4).abs() < 0.05, "{dt}");
+            assert!((custom_dt - 500.0).abs() < 0.05, "{custom_dt}");
+        }
+    }
+
+    #[test]
+    fn ex_variants_reject_invalid_mods() {
+        unsafe {
+            for mods_json in [
+                "",
+                "{",
+                r#"[{"acronym":"DT","settings":{"speed_change":"fast"}}]"#,
+            ] {
+                let err =
+                    from_path_ex(mods_json, RosuPpHitResultPriority::BestCase as u32).unwrap_err();
+                assert_eq!(err, RosuPpError::InvalidArgument, "{mods_json}");
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_priority() {
+        unsafe {
+            let mut map = MaybeUninit::uninit();
+            let err = rosu_pp_beatmap_from_path(path().as_ptr(), map.as_mut_ptr());
+            assert_eq!(err, RosuPpError::Ok);
+            let map = map.assume_init();
+
+            let mut difficulty = MaybeUninit::uninit();
+            let err = rosu_pp_difficulty_calculate(map, 0, difficulty.as_mut_ptr());
+            assert_eq!(err, RosuPpError::Ok);
+            let difficulty = difficulty.assume_init();
+
+            let mut out = MaybeUninit::uninit();
+
+            let err = rosu_pp_performance_calculate_ex(
+                difficulty,
+                0,
+                95.0,
+                500,
+                2,
+                RosuPpHitResultPriority::Balanced as u32,
+                out.as_mut_ptr(),
+            );
+            assert_eq!(err, RosuPpError::Ok);
+            rosu_pp_performance_attrs_free(out.assume_init());
+
+            let err =
+                rosu_pp_performance_calculate_ex(difficulty, 0, 95.0, 500, 2, 4, out.as_mut_ptr());
+            assert_eq!(err, RosuPpError::InvalidArgument);
+
+            rosu_pp_difficulty_attrs_free(difficulty);
+            rosu_pp_beatmap_free(map);
+        }
+    }
+
+    #[test]
+    fn invalid_mode() {
+        unsafe {
+            let mut map = MaybeUninit::uninit();
+            let err = rosu_pp_beatmap_from_path(path().as_ptr(), map.as_mut_ptr());
+            assert_eq!(err, RosuPpError::Ok);
+            let map = map.assume_init();
+
+            let mut out = MaybeUninit::uninit();
+
+            let err = rosu_pp_difficulty_calculate_mode(
+                map,
+                RosuPpGameMode::Taiko as u32,
+                0,
+                out.as_mut_ptr(),
+            );
+            assert_eq!(err, RosuPpError::Ok);
+            rosu_pp_difficulty_attrs_free(out.assume_init());
+
+            let err = rosu_pp_difficulty_calculate_mode(map, 4, 0, out.as_mut_ptr());
+            assert_eq!(err, RosuPpError::InvalidArgument);
+
+            rosu_pp_beatmap_free(map);
+        }
+    }
+
+    #[test]
+    fn ex_variants_reject_invalid_priority() {
+        unsafe {
+            let balanced = from_path_ex("8", RosuPpHitResultPriority::Balanced as u32);
+            assert!(balanced.is_ok());
+
+            let err = from_path_ex("8", 4).unwrap_err();
+            assert_eq!(err, RosuPpError::InvalidArgument);
+
+            let bytes = std::fs::read(OSU).unwrap();
+            let mods_json = CString::new("8").unwrap();
+            let mut out = MaybeUninit::uninit();
+
+            let err = rosu_pp_performance_from_bytes_ex(
+                bytes.as_ptr(),
+                bytes.len(),
+                mods_json.as_ptr(),
+                95.0,
+                500,
+                2,
+                u32::MAX,
+                out.as_mut_ptr(),
+            );
+            assert_eq!(err, RosuPpError::InvalidArgument);
+        }
+    }
+
+    unsafe fn cached_stars(cache: *mut c_void, map: *const c_void, mods: u32) -> f64 {
+        let mut attrs = MaybeUninit::uninit();
+        let err = rosu_pp_difficulty_cache_get(cache, map, mods, attrs.as_mut_ptr());
+        assert_eq!(err, RosuPpError::Ok);
+        let attrs = attrs.assume_init();
+
+        let mut values = MaybeUninit::uninit();
+        let err = rosu_pp_difficulty_attrs_values(attrs, values.as_mut_ptr());
+        assert_eq!(err, RosuPpError::Ok);
+        rosu_pp_difficulty_attrs_free(attrs);
+
+        values.assume_init().stars
+    }
+
+    #[test]
+    fn difficulty_cache() {
+        unsafe {
+            let bytes = std::fs::read(OSU).unwrap();
+            let cache = rosu_pp_difficulty_cache_new(2);
+            assert!(!cache.is_null());
+            let entries = |cache: *mut c_void| (*cache.cast::<DifficultyCache>()).entries.len();
+
+            let mut map = MaybeUninit::uninit();
+            let err = rosu_pp_beatmap_from_path(path().as_ptr(), map.as_mut_ptr());
+            assert_eq!(err, RosuPpError::Ok);
+            let map = map.assume_init();
+
+            let nomod = cached_stars(cache, map, 0);
+            assert_eq!(cached_stars(cache, map, 0).to_bits(), nomod.to_bits());
+            assert_eq!(entries(cache), 1);
+            rosu_pp_beatmap_free(map);
+
+            // A new map must not hit the entry of the freed one, even if it
+            // is allocated at the same address.
+            let mut other = MaybeUninit::uninit();
+            let modified = String::from_utf8(bytes)
+                .unwrap()
+                .replace("OverallDifficulty:8.8", "OverallDifficulty:5");
+            let err =
+                rosu_pp_beatmap_from_bytes(modified.as_ptr(), modified.len(), other.as_mut_ptr());
+            assert_eq!(err, RosuPpError::Ok);
+            let other = other.assume_init();
+
+            let expected = Difficulty::new()
+                .calculate(&Beatmap::from_bytes(modified.as_bytes()).unwrap())
+                .stars();
+            assert_eq!(cached_stars(cache, other, 0).to_bits(), expected.to_bits());
+            assert_ne!(expected.to_bits(), nomod.to_bits());
+            assert_eq!(entries(cache), 2);
+
+            // Capacity is reached so the oldest entry is evicted.
+            cached_stars(cache, other, 64);
+            assert_eq!(entries(cache), 2);
+            assert_eq!((*cache.cast::<DifficultyCache>()).order.len(), 2);
+
+            rosu_pp_difficulty_cache_clear(cache);
+            assert_eq!(entries(cache), 0);
+            assert_eq!(cached_stars(cache, other, 0).to_bits(), expected.to_bits());
+
+            rosu_pp_beatmap_free(other);
+            rosu_pp_difficulty_cache_free(cache);
+        }
+    }
+}
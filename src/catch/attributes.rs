@@ -1,9 +1,13 @@
-use std::mem;
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    mem,
+};
 
 use crate::catch::performance::CatchPerformance;
 
 /// The result of a difficulty calculation on an osu!catch map.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CatchDifficultyAttributes {
     /// The final star rating
     pub stars: f64,
@@ -58,11 +62,25 @@ impl CatchDifficultyAttributes {
 
 /// The result of a performance calculation on an osu!catch map.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CatchPerformanceAttributes {
     /// The difficulty attributes that were used for the performance calculation
     pub difficulty: CatchDifficultyAttributes,
     /// The final performance points.
     pub pp: f64,
+    /// The accuracy of the score between `0.0` and `1.0`.
+    pub accuracy: f64,
+    /// Misses of the score.
+    ///
+    /// Since osu!catch has no slider breaks, this is simply the amount of
+    /// missed fruits and droplets.
+    pub effective_miss_count: f64,
+    /// The factor between `0.0` and `1.0` that the pp were scaled with based
+    /// on the score's combo.
+    pub combo_scaling: f64,
+    /// The factor between `0.0` and `1.0` that the pp were scaled with based
+    /// on the score's accuracy.
+    pub acc_scaling: f64,
 }
 
 impl CatchPerformanceAttributes {
@@ -76,6 +94,11 @@ impl CatchPerformanceAttributes {
         self.pp
     }
 
+    /// Return the accuracy of the score between `0.0` and `1.0`.
+    pub const fn accuracy(&self) -> f64 {
+        self.accuracy
+    }
+
     /// Return the maximum combo of the map.
     pub const fn max_combo(&self) -> u32 {
         self.difficulty.max_combo()
@@ -94,6 +117,19 @@ impl CatchPerformanceAttributes {
     }
 }
 
+impl Display for CatchPerformanceAttributes {
+    /// Format a one-line summary, e.g. `215.33pp 98.50% 5.12*`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{:.2}pp {:.2}% {:.2}*",
+            self.pp,
+            self.accuracy * 100.0,
+            self.stars(),
+        )
+    }
+}
+
 impl From<CatchPerformanceAttributes> for CatchDifficultyAttributes {
     fn from(attributes: CatchPerformanceAttributes) -> Self {
         attributes.difficulty
@@ -107,6 +143,12 @@ pub struct ObjectCount {
     tiny_droplets: u32,
 }
 
+impl ObjectCount {
+    pub const fn max_combo(&self) -> u32 {
+        self.fruits + self.droplets
+    }
+}
+
 #[derive(Copy, Clone, Default)]
 pub struct GradualObjectCount {
     fruit: bool,
@@ -77,6 +77,24 @@ pub fn convert_objects(
     palpable_objects
 }
 
+pub fn object_combo_values(map: &Beatmap) -> Vec<u32> {
+    let mut bufs = JuiceStreamBufs {
+        curve: CurveBuffers::default(),
+        nested_objects: Vec::with_capacity(16),
+        ticks: Vec::new(),
+    };
+
+    map.hit_objects
+        .iter()
+        .map(|h| {
+            let mut count = ObjectCountBuilder::new_regular(usize::MAX);
+            let _ = convert_object(h, map, &mut count, &mut bufs);
+
+            count.into_regular().max_combo()
+        })
+        .collect()
+}
+
 fn convert_object<'a>(
     h: &'a HitObject,
     map: &Beatmap,
@@ -11,6 +11,7 @@ use crate::{
         CatchDifficultyAttributes,
     },
     model::mode::ConvertError,
+    util::snapshots::{Rewind, Snapshots},
     Beatmap, Difficulty,
 };
 
@@ -63,12 +64,20 @@ pub struct CatchGradualDifficulty {
     count: Vec<GradualObjectCount>,
     diff_objects: Box<[CatchDifficultyObject]>,
     movement: Movement,
+    snapshots: Snapshots<Snapshot>,
+}
+
+struct Snapshot {
+    attrs: CatchDifficultyAttributes,
+    movement: <Movement as Rewind>::Snapshot,
 }
 
 impl CatchGradualDifficulty {
     /// Create a new difficulty attributes iterator for osu!catch maps.
     pub fn new(difficulty: Difficulty, map: &Beatmap) -> Result<Self, ConvertError> {
         let map = map.convert_ref(GameMode::Catch, difficulty.get_mods())?;
+        let map = difficulty.apply_slider_overrides(map);
+        let map = difficulty.apply_time_range(map);
 
         let clock_rate = difficulty.get_clock_rate();
 
@@ -99,6 +108,11 @@ impl CatchGradualDifficulty {
         let count = count.into_gradual();
         let movement = Movement::new(half_catcher_width, clock_rate);
 
+        let snapshots = Snapshots::new(Snapshot {
+            attrs: attrs.clone(),
+            movement: movement.snapshot(),
+        });
+
         Ok(Self {
             idx: 0,
             difficulty,
@@ -106,14 +120,50 @@ impl CatchGradualDifficulty {
             count,
             diff_objects,
             movement,
+            snapshots,
         })
     }
-}
 
-impl Iterator for CatchGradualDifficulty {
-    type Item = CatchDifficultyAttributes;
+    /// Restore the latest snapshot for at most `idx` processed objects if
+    /// `idx` objects were already processed.
+    ///
+    /// From then on, snapshots are stored periodically while processing.
+    pub(crate) fn rewind(&mut self, idx: usize) {
+        self.snapshots.enable();
 
-    fn next(&mut self) -> Option<Self::Item> {
+        if idx >= self.idx {
+            return;
+        }
+
+        let Some((snapshot_idx, snapshot)) = self.snapshots.get(idx) else {
+            return;
+        };
+
+        self.idx = snapshot_idx;
+        self.attrs = snapshot.attrs.clone();
+        self.movement.rewind(&snapshot.movement);
+        self.snapshots.truncate(snapshot_idx);
+    }
+
+    /// Process the next palpable object and return its movement strain
+    /// without evaluating the difficulty attributes.
+    pub(crate) fn next_strain(&mut self) -> Option<f64> {
+        self.process_next()?;
+
+        if self.idx < 2 {
+            return Some(0.0);
+        }
+
+        Some(
+            self.movement
+                .object_strains()
+                .last()
+                .copied()
+                .unwrap_or(0.0),
+        )
+    }
+
+    fn process_next(&mut self) -> Option<()> {
         // The first difficulty object belongs to the second palpable object
         // since each difficulty object requires the current and the last note.
         // Hence, if we're still on the first object, we don't have a difficulty
@@ -127,6 +177,24 @@ impl Iterator for CatchGradualDifficulty {
 
         self.attrs.add_object_count(self.count[self.idx]);
         self.idx += 1;
+        self.store_snapshot();
+
+        Some(())
+    }
+
+    fn store_snapshot(&mut self) {
+        self.snapshots.store(self.idx, || Snapshot {
+            attrs: self.attrs.clone(),
+            movement: self.movement.snapshot(),
+        });
+    }
+}
+
+impl Iterator for CatchGradualDifficulty {
+    type Item = CatchDifficultyAttributes;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.process_next()?;
 
         let mut attrs = self.attrs.clone();
 
@@ -159,6 +227,11 @@ impl Iterator for CatchGradualDifficulty {
 
             self.attrs.add_object_count(self.count[self.idx]);
             self.idx += 1;
+
+            self.snapshots.store(self.idx, || Snapshot {
+                attrs: self.attrs.clone(),
+                movement: self.movement.snapshot(),
+            });
         }
 
         self.next()
@@ -27,6 +27,8 @@ pub fn difficulty(
     map: &Beatmap,
 ) -> Result<CatchDifficultyAttributes, ConvertError> {
     let map = map.convert_ref(GameMode::Catch, difficulty.get_mods())?;
+    let map = difficulty.apply_slider_overrides(map);
+    let map = difficulty.apply_time_range(map);
 
     let DifficultyValues {
         movement,
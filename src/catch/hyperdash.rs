@@ -0,0 +1,56 @@
+use rosu_map::section::general::GameMode;
+
+use crate::{
+    catch::{attributes::ObjectCountBuilder, convert::convert_objects},
+    model::mode::ConvertError,
+    Beatmap, Difficulty,
+};
+
+/// The amount of hyperdashes that are required to catch all fruits and
+/// droplets of an osu!catch map.
+///
+/// The mods and slider overrides of the given [`Difficulty`] are taken into
+/// account, e.g. HR and EZ change the circle size and object positions.
+/// osu!standard maps are converted first.
+///
+/// # Example
+///
+/// ```
+/// use rosu_pp::{catch::catch_hyperdash_count, Beatmap, Difficulty};
+///
+/// let map = Beatmap::from_path("./resources/2118524.osu").unwrap();
+///
+/// let nomod = catch_hyperdash_count(&Difficulty::new(), &map).unwrap();
+/// let hardrock = catch_hyperdash_count(&Difficulty::new().mods(16), &map).unwrap();
+///
+/// assert_eq!(nomod, 67);
+/// assert_eq!(hardrock, 72);
+/// ```
+pub fn catch_hyperdash_count(difficulty: &Difficulty, map: &Beatmap) -> Result<u32, ConvertError> {
+    let map = map.convert_ref(GameMode::Catch, difficulty.get_mods())?;
+    let map = difficulty.apply_slider_overrides(map);
+    let cs = map.attributes().difficulty(difficulty).build().cs;
+
+    let palpable_objects = convert_objects(
+        &map,
+        &mut ObjectCountBuilder::new_regular(usize::MAX),
+        difficulty.get_mods().reflection(),
+        difficulty.get_hardrock_offsets(),
+        cs as f32,
+    );
+
+    let count = palpable_objects.iter().filter(|h| h.hyper_dash).count();
+
+    Ok(count as u32)
+}
+
+/// Whether catching all fruits and droplets of an osu!catch map requires
+/// hyperdashes.
+///
+/// See [`catch_hyperdash_count`] for more details.
+pub fn catch_requires_hyperdash(
+    difficulty: &Difficulty,
+    map: &Beatmap,
+) -> Result<bool, ConvertError> {
+    catch_hyperdash_count(difficulty, map).map(|count| count > 0)
+}
@@ -11,6 +11,7 @@ use crate::{
 pub use self::{
     attributes::{CatchDifficultyAttributes, CatchPerformanceAttributes},
     difficulty::gradual::CatchGradualDifficulty,
+    hyperdash::{catch_hyperdash_count, catch_requires_hyperdash},
     performance::{gradual::CatchGradualPerformance, CatchPerformance},
     score_state::CatchScoreState,
     strains::CatchStrains,
@@ -20,8 +21,10 @@ mod attributes;
 mod catcher;
 mod convert;
 mod difficulty;
+mod hyperdash;
 mod object;
 mod performance;
+mod positions;
 mod score_state;
 mod strains;
 
@@ -37,6 +40,20 @@ impl Catch {
         debug_assert!(!map.is_convert && map.mode == GameMode::Osu);
         convert::convert(map);
     }
+
+    /// The combo of each hitobject of an osu!catch [`Beatmap`].
+    pub(crate) fn object_combo_values(map: &Beatmap) -> Vec<u32> {
+        convert::object_combo_values(map)
+    }
+
+    /// The start time and x-position of each fruit and droplet of an
+    /// osu!catch [`Beatmap`] after applying mods and slider overrides.
+    pub(crate) fn fruit_positions(
+        difficulty: &Difficulty,
+        map: &Beatmap,
+    ) -> Result<Vec<(f64, f32)>, ConvertError> {
+        positions::fruit_positions(difficulty, map)
+    }
 }
 
 impl IGameMode for Catch {
@@ -43,14 +43,19 @@ impl CatchPerformanceCalculator<'_> {
 
         pp *= len_bonus;
 
+        let effective_miss_count = f64::from(self.state.misses);
+
         // Penalize misses exponentially
-        pp *= 0.97_f64.powf(f64::from(self.state.misses));
+        pp *= 0.97_f64.powf(effective_miss_count);
 
         // Combo scaling
-        if self.state.max_combo > 0 {
-            pp *= (f64::from(self.state.max_combo).powf(0.8) / f64::from(max_combo).powf(0.8))
-                .min(1.0);
-        }
+        let combo_scaling = if self.state.max_combo > 0 {
+            (f64::from(self.state.max_combo).powf(0.8) / f64::from(max_combo).powf(0.8)).min(1.0)
+        } else {
+            1.0
+        };
+
+        pp *= combo_scaling;
 
         // AR scaling
         let ar = attributes.ar;
@@ -77,7 +82,8 @@ impl CatchPerformanceCalculator<'_> {
         }
 
         // Accuracy scaling
-        pp *= self.state.accuracy().powf(5.5);
+        let acc_scaling = self.state.accuracy().powf(5.5);
+        pp *= acc_scaling;
 
         // NF penalty
         if self.mods.nf() {
@@ -87,6 +93,10 @@ impl CatchPerformanceCalculator<'_> {
         CatchPerformanceAttributes {
             difficulty: self.attrs,
             pp,
+            accuracy: self.state.accuracy(),
+            effective_miss_count,
+            combo_scaling,
+            acc_scaling,
         }
     }
 
@@ -1,4 +1,5 @@
 use crate::{
+    any::performance::judgement::JudgementTotals,
     catch::{CatchGradualDifficulty, CatchPerformanceAttributes, CatchScoreState},
     model::mode::ConvertError,
     Beatmap, Difficulty,
@@ -85,6 +86,7 @@ use crate::{
 /// [`nth`]: CatchGradualPerformance::nth
 pub struct CatchGradualPerformance {
     difficulty: CatchGradualDifficulty,
+    pub(crate) totals: JudgementTotals,
 }
 
 impl CatchGradualPerformance {
@@ -92,7 +94,10 @@ impl CatchGradualPerformance {
     pub fn new(difficulty: Difficulty, map: &Beatmap) -> Result<Self, ConvertError> {
         let difficulty = CatchGradualDifficulty::new(difficulty, map)?;
 
-        Ok(Self { difficulty })
+        Ok(Self {
+            difficulty,
+            totals: JudgementTotals::default(),
+        })
     }
 
     /// Process the next hit object and calculate the performance attributes
@@ -130,6 +135,28 @@ impl CatchGradualPerformance {
         Some(performance)
     }
 
+    /// Process everything up to the hit object at index `idx` and calculate
+    /// the performance attributes for the resulting score state.
+    ///
+    /// Unlike [`nth`](Self::nth), `idx` is not relative to the current
+    /// position so already processed objects can be revisited. The result is
+    /// the same as calling `next` `idx + 1` times on a new instance.
+    ///
+    /// Returns `None` if `idx` is out of bounds.
+    pub fn at_index(
+        &mut self,
+        state: CatchScoreState,
+        idx: usize,
+    ) -> Option<CatchPerformanceAttributes> {
+        if idx >= self.difficulty.idx + self.difficulty.len() {
+            return None;
+        }
+
+        self.difficulty.rewind(idx);
+
+        self.nth(state, idx - self.difficulty.idx)
+    }
+
     /// Returns the amount of remaining objects.
     #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
@@ -139,7 +166,9 @@ impl CatchGradualPerformance {
 
 #[cfg(test)]
 mod tests {
-    use crate::{catch::CatchPerformance, Beatmap};
+    use rosu_map::section::general::GameMode;
+
+    use crate::{any::PerformanceAttributes, catch::CatchPerformance, Beatmap, GradualPerformance};
 
     use super::*;
 
@@ -185,4 +214,42 @@ mod tests {
             assert_eq!(next_gradual, expected);
         }
     }
+
+    #[test]
+    fn converges_to_full_calculation() {
+        let catch = Beatmap::from_path("./resources/2118524.osu").unwrap();
+        let osu = Beatmap::from_path("./resources/2785319.osu").unwrap();
+
+        for map in [catch, osu] {
+            let difficulty = Difficulty::new().mods(88); // HDHRDT
+
+            let mut performance = CatchPerformance::new(&map)
+                .difficulty(difficulty.clone())
+                .accuracy(97.5)
+                .misses(2);
+            let state = performance.generate_state().unwrap();
+            let expected = performance.calculate().unwrap();
+
+            let mut gradual = CatchGradualPerformance::new(difficulty.clone(), &map).unwrap();
+            let mut last = None;
+
+            while let Some(attrs) = gradual.next(state.clone()) {
+                last = Some(attrs);
+            }
+
+            assert_eq!(last.as_ref(), Some(&expected), "{:?}", map.mode);
+
+            let any = GradualPerformance::new_with_mode(difficulty, &map, GameMode::Catch)
+                .unwrap()
+                .last(state.into())
+                .unwrap();
+
+            assert_eq!(
+                any,
+                PerformanceAttributes::Catch(expected),
+                "{:?}",
+                map.mode
+            );
+        }
+    }
 }
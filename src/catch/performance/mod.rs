@@ -30,6 +30,8 @@ pub struct CatchPerformance<'map> {
     tiny_droplets: Option<u32>,
     tiny_droplet_misses: Option<u32>,
     misses: Option<u32>,
+    fruit_misses: Option<u32>,
+    droplet_misses: Option<u32>,
 }
 
 impl<'map> CatchPerformance<'map> {
@@ -130,6 +132,32 @@ impl<'map> CatchPerformance<'map> {
         self
     }
 
+    /// Specify the amount of missed fruits of the play.
+    ///
+    /// Unlike [`CatchPerformance::misses`], this specifies which kind of
+    /// object was missed. If fruit or droplet misses are specified, the total
+    /// amount of misses is their sum and [`CatchPerformance::misses`] is
+    /// ignored.
+    ///
+    /// Missed fruits and droplets both break combo and each lower accuracy by
+    /// one hit, so for a fixed amount of misses, it makes no difference for
+    /// pp how they are split between fruits and droplets. Tiny droplet misses
+    /// only lower accuracy, see [`CatchPerformance::tiny_droplet_misses`].
+    pub const fn fruit_misses(mut self, n_fruit_misses: u32) -> Self {
+        self.fruit_misses = Some(n_fruit_misses);
+
+        self
+    }
+
+    /// Specify the amount of missed droplets of the play.
+    ///
+    /// See [`CatchPerformance::fruit_misses`].
+    pub const fn droplet_misses(mut self, n_droplet_misses: u32) -> Self {
+        self.droplet_misses = Some(n_droplet_misses);
+
+        self
+    }
+
     /// Use the specified settings of the given [`Difficulty`].
     pub fn difficulty(mut self, difficulty: Difficulty) -> Self {
         self.difficulty = difficulty;
@@ -249,6 +277,8 @@ impl<'map> CatchPerformance<'map> {
         self.tiny_droplets = Some(n_tiny_droplets);
         self.tiny_droplet_misses = Some(n_tiny_droplet_misses);
         self.misses = Some(misses);
+        self.fruit_misses = None;
+        self.droplet_misses = None;
 
         self
     }
@@ -273,9 +303,26 @@ impl<'map> CatchPerformance<'map> {
             MapOrAttrs::Attrs(ref attrs) => attrs,
         };
 
-        let misses = self
-            .misses
-            .map_or(0, |n| cmp::min(n, attrs.n_fruits + attrs.n_droplets));
+        let fruit_misses = self.fruit_misses.map(|n| cmp::min(n, attrs.n_fruits));
+        let droplet_misses = self.droplet_misses.map(|n| cmp::min(n, attrs.n_droplets));
+
+        let misses = match (fruit_misses, droplet_misses) {
+            (None, None) => self
+                .misses
+                .map_or(0, |n| cmp::min(n, attrs.n_fruits + attrs.n_droplets)),
+            (fruit_misses, droplet_misses) => {
+                fruit_misses.unwrap_or(0) + droplet_misses.unwrap_or(0)
+            }
+        };
+
+        // Missed fruits and droplets determine the caught ones unless those
+        // are specified already
+        let fruits = self
+            .fruits
+            .or_else(|| fruit_misses.map(|n| attrs.n_fruits - n));
+        let droplets = self
+            .droplets
+            .or_else(|| droplet_misses.map(|n| attrs.n_droplets - n));
 
         let max_combo = self.combo.unwrap_or_else(|| attrs.max_combo() - misses);
 
@@ -287,7 +334,7 @@ impl<'map> CatchPerformance<'map> {
 
         let mut best_dist = f64::INFINITY;
 
-        let (n_fruits, n_droplets) = match (self.fruits, self.droplets) {
+        let (n_fruits, n_droplets) = match (fruits, droplets) {
             (Some(mut n_fruits), Some(mut n_droplets)) => {
                 let n_remaining = (attrs.n_fruits + attrs.n_droplets)
                     .saturating_sub(n_fruits + n_droplets + misses);
@@ -440,6 +487,8 @@ impl<'map> CatchPerformance<'map> {
             tiny_droplets: None,
             tiny_droplet_misses: None,
             misses: None,
+            fruit_misses: None,
+            droplet_misses: None,
         }
     }
 }
@@ -476,6 +525,7 @@ impl<'map> TryFrom<OsuPerformance<'map>> for CatchPerformance<'map> {
             n100,
             n50,
             misses,
+            slider_breaks: _,
             hitresult_priority: _,
         } = osu;
 
@@ -489,6 +539,8 @@ impl<'map> TryFrom<OsuPerformance<'map>> for CatchPerformance<'map> {
             tiny_droplets: n50,
             tiny_droplet_misses: None,
             misses,
+            fruit_misses: None,
+            droplet_misses: None,
         })
     }
 }
@@ -737,6 +789,75 @@ mod test {
         assert_eq!(state, expected);
     }
 
+    #[test]
+    fn fruit_and_droplet_misses() {
+        let state = CatchPerformance::from(attrs())
+            .fruit_misses(3)
+            .droplet_misses(1)
+            .misses(100)
+            .generate_state()
+            .unwrap();
+
+        assert_eq!(state.misses, 4);
+        assert_eq!(state.fruits, N_FRUITS - 3);
+        assert_eq!(state.droplets, N_DROPLETS - 1);
+        assert_eq!(state.tiny_droplets, N_TINY_DROPLETS);
+    }
+
+    #[test]
+    fn miss_kinds_affect_pp() {
+        let pp = |fruit_misses, tiny_droplet_misses| {
+            CatchPerformance::from(attrs())
+                .fruit_misses(fruit_misses)
+                .tiny_droplet_misses(tiny_droplet_misses)
+                .calculate()
+                .unwrap()
+                .pp
+        };
+
+        let mostly_fruits = pp(8, 2);
+        let mostly_tiny_droplets = pp(2, 8);
+
+        assert!(
+            mostly_fruits < mostly_tiny_droplets,
+            "{mostly_fruits} >= {mostly_tiny_droplets}"
+        );
+    }
+
+    #[test]
+    fn fruit_and_droplet_misses_are_equivalent() {
+        let state = |fruit_misses, droplet_misses| {
+            CatchPerformance::from(attrs())
+                .fruit_misses(fruit_misses)
+                .droplet_misses(droplet_misses)
+                .generate_state()
+                .unwrap()
+        };
+
+        // Both count as a miss and as one missing hit for accuracy so only
+        // the total amount matters
+        let fruits = state(N_DROPLETS, 0);
+        let droplets = state(0, N_DROPLETS);
+
+        assert_eq!(fruits.misses, droplets.misses);
+        assert_eq!(fruits.max_combo, droplets.max_combo);
+        assert_eq!(fruits.accuracy().to_bits(), droplets.accuracy().to_bits());
+        assert_ne!(fruits.fruits, droplets.fruits);
+
+        let pp = |state| {
+            CatchPerformance::from(attrs())
+                .state(state)
+                .calculate()
+                .unwrap()
+                .pp
+                .to_bits()
+        };
+
+        let droplets_pp = pp(droplets);
+        assert_eq!(pp(fruits), droplets_pp);
+        assert_eq!(pp(state(1, 1)), droplets_pp);
+    }
+
     #[test]
     fn create() {
         let mut map = beatmap();
@@ -0,0 +1,59 @@
+use rosu_map::section::general::GameMode;
+
+use crate::{
+    catch::{attributes::ObjectCountBuilder, convert::convert_objects},
+    model::mode::ConvertError,
+    Beatmap, Difficulty,
+};
+
+/// The start time and effective x-position of each fruit and droplet of an
+/// osu!catch map after applying mods and slider overrides.
+pub fn fruit_positions(
+    difficulty: &Difficulty,
+    map: &Beatmap,
+) -> Result<Vec<(f64, f32)>, ConvertError> {
+    let map = map.convert_ref(GameMode::Catch, difficulty.get_mods())?;
+    let map = difficulty.apply_slider_overrides(map);
+    let cs = map.attributes().difficulty(difficulty).build().cs;
+
+    let palpable_objects = convert_objects(
+        &map,
+        &mut ObjectCountBuilder::new_regular(usize::MAX),
+        difficulty.get_mods().reflection(),
+        difficulty.get_hardrock_offsets(),
+        cs as f32,
+    );
+
+    let positions = palpable_objects
+        .iter()
+        .map(|h| (h.start_time, h.effective_x()))
+        .collect();
+
+    Ok(positions)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::catch::Catch;
+
+    use super::*;
+
+    #[test]
+    fn matches_object_count() {
+        let map = Beatmap::from_path("./resources/2118524.osu").unwrap();
+
+        for mods in [0, 2, 16] {
+            let positions = map.catch_fruit_positions(mods);
+            let attrs = Difficulty::new()
+                .mods(mods)
+                .calculate_for_mode::<Catch>(&map)
+                .unwrap();
+
+            assert_eq!(positions.len(), attrs.max_combo() as usize, "{mods}");
+            assert!(positions.windows(2).all(|w| w[0].0 <= w[1].0), "{mods}");
+        }
+
+        let taiko = Beatmap::from_path("./resources/1028484.osu").unwrap();
+        assert!(taiko.catch_fruit_positions(0).is_empty());
+    }
+}
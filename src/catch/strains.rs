@@ -23,6 +23,8 @@ impl CatchStrains {
 
 pub fn strains(difficulty: &Difficulty, map: &Beatmap) -> Result<CatchStrains, ConvertError> {
     let map = map.convert_ref(GameMode::Catch, difficulty.get_mods())?;
+    let map = difficulty.apply_slider_overrides(map);
+    let map = difficulty.apply_time_range(map);
     let DifficultyValues { movement, .. } = DifficultyValues::calculate(difficulty, &map);
 
     Ok(CatchStrains {
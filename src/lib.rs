@@ -130,6 +130,9 @@
 //! | `raw_strains` | With this feature, internal strain values will be stored in a plain `Vec`. This introduces an out-of-memory risk on maliciously long maps (see [/b/3739922](https://osu.ppy.sh/b/3739922)), but comes with a ~5% gain in performance. |
 //! | `sync`        | Some gradual calculation types can only be shared across threads if this feature is enabled. This feature adds a small performance penalty. |
 //! | `tracing`     | Any error encountered during beatmap decoding will be logged through `tracing::error`. If this feature is **not** enabled, errors will be ignored. | [`tracing`]
+//! | `libm`        | Float functions used by the difficulty helpers are provided by [`libm`] when they're compiled without `std` through `--cfg rosu_pp_no_std`. Builds with `std` keep using `std`'s float functions so results are unaffected. | [`libm`]
+//! | `rayon`       | Enables `calculate_many` to calculate the difficulty of multiple maps in parallel. | [`rayon`]
+//! | `serde`       | Implements `Serialize` and `Deserialize` for all difficulty and performance attributes. | [`serde`]
 //!
 //! ## Bindings
 //!
@@ -166,10 +169,18 @@
 //! use the default Android NDK installation automatically. If the NDK lives elsewhere,
 //! set `ANDROID_NDK_HOME` before building.
 //!
+//! The layout of the `RosuPp*` structs only changes alongside a bump of the
+//! ABI version. Bindings that load the library dynamically should check
+//! `rosu_pp_abi_version()` at load time; `rosu_pp_version_string()` returns
+//! the crate version.
+//!
 //! [osu!]: https://osu.ppy.sh/home
 //! [osu!lazer]: https://github.com/ppy/osu
 //! [osu!tools]: https://github.com/ppy/osu-tools
 //! [`tracing`]: https://docs.rs/tracing
+//! [`libm`]: https://docs.rs/libm
+//! [`rayon`]: https://docs.rs/rayon
+//! [`serde`]: https://docs.rs/serde
 //! [rosu-pp-js]: https://github.com/MaxOhn/rosu-pp-js
 //! [rosu-pp-py]: https://github.com/MaxOhn/rosu-pp-py
 //! [benchmark]: https://gist.github.com/MaxOhn/625af10011f6d7e13a171b08ccf959ff
@@ -190,7 +201,8 @@
     clippy::explicit_iter_loop,
     clippy::similar_names,
     clippy::cast_possible_wrap,
-    clippy::manual_midpoint
+    clippy::manual_midpoint,
+    clippy::manual_is_multiple_of
 )]
 
 #[doc(inline)]
@@ -199,6 +211,10 @@ pub use self::{
     model::{beatmap::Beatmap, mods::GameMods},
 };
 
+#[cfg(feature = "rayon")]
+#[doc(inline)]
+pub use self::any::calculate_many;
+
 #[macro_use]
 mod util;
 
@@ -226,14 +242,20 @@ pub mod capi;
 
 #[cfg(feature = "capi")]
 pub use self::capi::{
-    rosu_pp_beatmap_check_suspicion, rosu_pp_beatmap_free, rosu_pp_beatmap_from_bytes,
-    rosu_pp_beatmap_from_path, rosu_pp_beatmap_mode, rosu_pp_difficulty_attrs_free,
-    rosu_pp_difficulty_attrs_values, rosu_pp_difficulty_calculate, rosu_pp_error_str,
-    rosu_pp_gradual_performance_free, rosu_pp_gradual_performance_last,
-    rosu_pp_gradual_performance_new, rosu_pp_gradual_performance_next,
-    rosu_pp_performance_attrs_free, rosu_pp_performance_attrs_max_pp,
-    rosu_pp_performance_attrs_values, rosu_pp_performance_calculate,
-    rosu_pp_performance_from_bytes, rosu_pp_performance_from_path, rosu_pp_score_state_new,
-    RosuPpDifficultyAttributes, RosuPpError, RosuPpGameMode, RosuPpPerformanceAttributes,
-    RosuPpScoreState, RosuPpSuspicion,
+    rosu_pp_abi_version, rosu_pp_beatmap_check_suspicion, rosu_pp_beatmap_free,
+    rosu_pp_beatmap_from_bytes, rosu_pp_beatmap_from_path, rosu_pp_beatmap_from_paths,
+    rosu_pp_beatmap_mode, rosu_pp_difficulty_attrs_free, rosu_pp_difficulty_attrs_values,
+    rosu_pp_difficulty_cache_clear, rosu_pp_difficulty_cache_free, rosu_pp_difficulty_cache_get,
+    rosu_pp_difficulty_cache_new, rosu_pp_difficulty_calculate, rosu_pp_difficulty_calculate_mode,
+    rosu_pp_difficulty_strains, rosu_pp_error_str, rosu_pp_gradual_performance_free,
+    rosu_pp_gradual_performance_last, rosu_pp_gradual_performance_new,
+    rosu_pp_gradual_performance_next, rosu_pp_performance_attrs_free,
+    rosu_pp_performance_attrs_max_pp, rosu_pp_performance_attrs_values,
+    rosu_pp_performance_calculate, rosu_pp_performance_calculate_ex,
+    rosu_pp_performance_catch_from_path, rosu_pp_performance_from_bytes,
+    rosu_pp_performance_from_bytes_ex, rosu_pp_performance_from_path,
+    rosu_pp_performance_from_path_ex, rosu_pp_performance_mania_from_path, rosu_pp_score_state_new,
+    rosu_pp_version_string, RosuPpCatchScoreState, RosuPpDifficultyAttributes, RosuPpError,
+    RosuPpGameMode, RosuPpHitResultPriority, RosuPpManiaScoreState, RosuPpPerformanceAttributes,
+    RosuPpScoreState, RosuPpSuspicion, ROSU_PP_ABI_VERSION,
 };
@@ -1,7 +1,10 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
 use crate::mania::performance::ManiaPerformance;
 
 /// The result of a difficulty calculation on an osu!mania map.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ManiaDifficultyAttributes {
     /// The final star rating.
     pub stars: f64,
@@ -43,6 +46,7 @@ impl ManiaDifficultyAttributes {
 
 /// The result of a performance calculation on an osu!mania map.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ManiaPerformanceAttributes {
     /// The difficulty attributes that were used for the performance calculation.
     pub difficulty: ManiaDifficultyAttributes,
@@ -50,6 +54,8 @@ pub struct ManiaPerformanceAttributes {
     pub pp: f64,
     /// The difficulty portion of the final pp.
     pub pp_difficulty: f64,
+    /// The accuracy of the score between `0.0` and `1.0`.
+    pub accuracy: f64,
 }
 
 impl ManiaPerformanceAttributes {
@@ -63,6 +69,11 @@ impl ManiaPerformanceAttributes {
         self.pp
     }
 
+    /// Return the accuracy of the score between `0.0` and `1.0`.
+    pub const fn accuracy(&self) -> f64 {
+        self.accuracy
+    }
+
     /// Return the maximum combo of the map.
     pub const fn max_combo(&self) -> u32 {
         self.difficulty.max_combo
@@ -86,6 +97,21 @@ impl ManiaPerformanceAttributes {
     }
 }
 
+impl Display for ManiaPerformanceAttributes {
+    /// Format a one-line summary, e.g.
+    /// `421.08pp (difficulty 395.62) 98.50% 4.29*`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{:.2}pp (difficulty {:.2}) {:.2}% {:.2}*",
+            self.pp,
+            self.pp_difficulty,
+            self.accuracy * 100.0,
+            self.stars(),
+        )
+    }
+}
+
 impl From<ManiaPerformanceAttributes> for ManiaDifficultyAttributes {
     fn from(attributes: ManiaPerformanceAttributes) -> Self {
         attributes.difficulty
@@ -167,7 +167,7 @@ impl Default for PrevValues {
     }
 }
 
-fn target_columns(map: &Beatmap, mods: &GameMods) -> f32 {
+pub fn target_columns(map: &Beatmap, mods: &GameMods) -> f32 {
     if let Some(keys) = mods.mania_keys() {
         return keys;
     }
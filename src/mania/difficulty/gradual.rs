@@ -5,7 +5,8 @@ use rosu_map::section::general::GameMode;
 use crate::{
     any::difficulty::skills::StrainSkill,
     mania::{convert, object::ObjectParams},
-    model::{hit_object::HitObject, mode::ConvertError},
+    model::mode::ConvertError,
+    util::snapshots::{Rewind, Snapshots},
     Beatmap, Difficulty,
 };
 
@@ -49,23 +50,43 @@ use super::{
 pub struct ManiaGradualDifficulty {
     pub(crate) idx: usize,
     pub(crate) difficulty: Difficulty,
-    objects_is_circle: Box<[bool]>,
+    object_combos: Box<[ObjectCombo]>,
     is_convert: bool,
     strain: Strain,
     diff_objects: Box<[ManiaDifficultyObject]>,
     note_state: NoteState,
+    snapshots: Snapshots<Snapshot>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 struct NoteState {
     curr_combo: u32,
     n_hold_notes: u32,
 }
 
+impl NoteState {
+    fn increment(&mut self, object: &ObjectCombo) {
+        self.curr_combo += object.combo;
+        self.n_hold_notes += u32::from(object.is_hold);
+    }
+}
+
+/// The combo that a hit object contributes.
+struct ObjectCombo {
+    combo: u32,
+    is_hold: bool,
+}
+
+struct Snapshot {
+    strain: <Strain as Rewind>::Snapshot,
+    note_state: NoteState,
+}
+
 impl ManiaGradualDifficulty {
     /// Create a new difficulty attributes iterator for osu!mania maps.
     pub fn new(difficulty: Difficulty, map: &Beatmap) -> Result<Self, ConvertError> {
-        let mut map = map.convert_ref(GameMode::Mania, difficulty.get_mods())?;
+        let map = map.convert_ref(GameMode::Mania, difficulty.get_mods())?;
+        let mut map = difficulty.apply_time_range(map);
 
         if difficulty.get_mods().ho() {
             convert::apply_hold_off_to_beatmap(map.to_mut());
@@ -80,52 +101,94 @@ impl ManiaGradualDifficulty {
         }
 
         let take = difficulty.get_passed_objects();
-        let total_columns = map.cs.round_ties_even().max(1.0);
+        let total_columns = map.mania_total_columns();
         let clock_rate = difficulty.get_clock_rate();
+        let holds_as_notes = difficulty.get_holds_as_notes();
         let mut params = ObjectParams::new(&map);
 
-        let mania_objects = map
+        let (mania_objects, object_combos): (Vec<_>, Vec<_>) = map
             .hit_objects
             .iter()
-            .map(|h| ManiaObject::new(h, total_columns, &mut params))
-            .take(take);
+            .map(|h| {
+                let prev_combo = params.max_combo();
+                let prev_hold_notes = params.n_hold_notes();
+                let obj = ManiaObject::new(h, total_columns, &mut params);
 
-        let diff_objects = DifficultyValues::create_difficulty_objects(clock_rate, mania_objects);
+                let combo = ObjectCombo {
+                    combo: params.max_combo() - prev_combo,
+                    is_hold: params.n_hold_notes() > prev_hold_notes,
+                };
 
-        let strain = Strain::new(total_columns as usize);
+                let obj = if holds_as_notes { obj.into_note() } else { obj };
 
-        let mut note_state = NoteState::default();
+                (obj, combo)
+            })
+            .unzip();
 
-        let objects_is_circle: Box<[_]> =
-            map.hit_objects.iter().map(HitObject::is_circle).collect();
+        let diff_objects = DifficultyValues::create_difficulty_objects(
+            clock_rate,
+            mania_objects.into_iter().take(take),
+        );
 
-        if let Some(h) = map.hit_objects.first() {
-            let hit_object = ManiaObject::new(h, total_columns, &mut params);
+        let strain = Strain::new(total_columns as usize);
 
-            increment_combo_raw(
-                objects_is_circle[0],
-                hit_object.start_time,
-                hit_object.end_time,
-                &mut note_state,
-            );
+        let mut note_state = NoteState::default();
+
+        if let Some(combo) = object_combos.first() {
+            note_state.increment(combo);
         }
 
+        let snapshots = Snapshots::new(Snapshot {
+            strain: strain.snapshot(),
+            note_state: note_state.clone(),
+        });
+
         Ok(Self {
             idx: 0,
             difficulty,
-            objects_is_circle,
+            object_combos: object_combos.into_boxed_slice(),
             is_convert: map.is_convert,
             strain,
             diff_objects,
             note_state,
+            snapshots,
         })
     }
-}
 
-impl Iterator for ManiaGradualDifficulty {
-    type Item = ManiaDifficultyAttributes;
+    /// Restore the latest snapshot for at most `idx` processed objects if
+    /// `idx` objects were already processed.
+    ///
+    /// From then on, snapshots are stored periodically while processing.
+    pub(crate) fn rewind(&mut self, idx: usize) {
+        self.snapshots.enable();
 
-    fn next(&mut self) -> Option<Self::Item> {
+        if idx >= self.idx {
+            return;
+        }
+
+        let Some((snapshot_idx, snapshot)) = self.snapshots.get(idx) else {
+            return;
+        };
+
+        self.idx = snapshot_idx;
+        self.strain.rewind(&snapshot.strain);
+        self.note_state = snapshot.note_state.clone();
+        self.snapshots.truncate(snapshot_idx);
+    }
+
+    /// Process the next note and return its strain without evaluating the
+    /// difficulty attributes.
+    pub(crate) fn next_strain(&mut self) -> Option<f64> {
+        self.process_next()?;
+
+        if self.idx < 2 {
+            return Some(0.0);
+        }
+
+        Some(self.strain.object_strains().last().copied().unwrap_or(0.0))
+    }
+
+    fn process_next(&mut self) -> Option<()> {
         // The first difficulty object belongs to the second note since each
         // difficulty object requires the current and the last note. Hence, if
         // we're still on the first object, we don't have a difficulty object
@@ -134,18 +197,30 @@ impl Iterator for ManiaGradualDifficulty {
             let curr = self.diff_objects.get(self.idx - 1)?;
             self.strain.process(curr, &self.diff_objects);
 
-            let is_circle = self.objects_is_circle[self.idx];
-            increment_combo(
-                is_circle,
-                curr,
-                &mut self.note_state,
-                self.difficulty.get_clock_rate(),
-            );
-        } else if self.objects_is_circle.is_empty() {
+            self.note_state.increment(&self.object_combos[self.idx]);
+        } else if self.object_combos.is_empty() {
             return None;
         }
 
         self.idx += 1;
+        self.store_snapshot();
+
+        Some(())
+    }
+
+    fn store_snapshot(&mut self) {
+        self.snapshots.store(self.idx, || Snapshot {
+            strain: self.strain.snapshot(),
+            note_state: self.note_state.clone(),
+        });
+    }
+}
+
+impl Iterator for ManiaGradualDifficulty {
+    type Item = ManiaDifficultyAttributes;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.process_next()?;
 
         Some(ManiaDifficultyAttributes {
             stars: self.strain.cloned_difficulty_value() * DIFFICULTY_MULTIPLIER,
@@ -166,7 +241,7 @@ impl Iterator for ManiaGradualDifficulty {
         let skip_iter = self
             .diff_objects
             .iter()
-            .zip(self.objects_is_circle.iter().skip(1))
+            .zip(self.object_combos.iter().skip(1))
             .skip(self.idx.saturating_sub(1));
 
         let mut take = cmp::min(n, self.len().saturating_sub(1));
@@ -177,12 +252,15 @@ impl Iterator for ManiaGradualDifficulty {
             self.idx += 1;
         }
 
-        let clock_rate = self.difficulty.get_clock_rate();
-
-        for (curr, is_circle) in skip_iter.take(take) {
-            increment_combo(*is_circle, curr, &mut self.note_state, clock_rate);
+        for (curr, combo) in skip_iter.take(take) {
+            self.note_state.increment(combo);
             self.strain.process(curr, &self.diff_objects);
             self.idx += 1;
+
+            self.snapshots.store(self.idx, || Snapshot {
+                strain: self.strain.snapshot(),
+                note_state: self.note_state.clone(),
+            });
         }
 
         self.next()
@@ -195,29 +273,6 @@ impl ExactSizeIterator for ManiaGradualDifficulty {
     }
 }
 
-fn increment_combo(
-    is_circle: bool,
-    diff_obj: &ManiaDifficultyObject,
-    state: &mut NoteState,
-    clock_rate: f64,
-) {
-    increment_combo_raw(
-        is_circle,
-        diff_obj.start_time * clock_rate,
-        diff_obj.end_time * clock_rate,
-        state,
-    );
-}
-
-fn increment_combo_raw(is_circle: bool, start_time: f64, end_time: f64, state: &mut NoteState) {
-    if is_circle {
-        state.curr_combo += 1;
-    } else {
-        state.curr_combo += 1 + ((end_time - start_time) / 100.0) as u32;
-        state.n_hold_notes += 1;
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use crate::{mania::Mania, Beatmap};
@@ -24,7 +24,8 @@ pub fn difficulty(
     difficulty: &Difficulty,
     map: &Beatmap,
 ) -> Result<ManiaDifficultyAttributes, ConvertError> {
-    let mut map = map.convert_ref(GameMode::Mania, difficulty.get_mods())?;
+    let map = map.convert_ref(GameMode::Mania, difficulty.get_mods())?;
+    let mut map = difficulty.apply_time_range(map);
 
     if difficulty.get_mods().ho() {
         convert::apply_hold_off_to_beatmap(map.to_mut());
@@ -60,14 +61,23 @@ pub struct DifficultyValues {
 impl DifficultyValues {
     pub fn calculate(difficulty: &Difficulty, map: &Beatmap) -> Self {
         let take = difficulty.get_passed_objects();
-        let total_columns = map.cs.round_ties_even().max(1.0);
+        let total_columns = map.mania_total_columns();
         let clock_rate = difficulty.get_clock_rate();
+        let holds_as_notes = difficulty.get_holds_as_notes();
         let mut params = ObjectParams::new(map);
 
         let mania_objects = map
             .hit_objects
             .iter()
-            .map(|h| ManiaObject::new(h, total_columns, &mut params))
+            .map(|h| {
+                let obj = ManiaObject::new(h, total_columns, &mut params);
+
+                if holds_as_notes {
+                    obj.into_note()
+                } else {
+                    obj
+                }
+            })
             .take(take);
 
         let diff_objects = Self::create_difficulty_objects(clock_rate, mania_objects);
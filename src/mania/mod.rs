@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use rosu_map::section::general::GameMode;
 
 use crate::{
@@ -8,11 +10,14 @@ use crate::{
     Difficulty, GameMods,
 };
 
+use self::object::{ManiaObject, ObjectParams};
+
 pub use self::{
     attributes::{ManiaDifficultyAttributes, ManiaPerformanceAttributes},
     difficulty::gradual::ManiaGradualDifficulty,
     performance::{gradual::ManiaGradualPerformance, ManiaPerformance},
     score_state::ManiaScoreState,
+    stats::{mania_stats, ManiaStats},
     strains::ManiaStrains,
 };
 
@@ -22,6 +27,7 @@ mod difficulty;
 mod object;
 mod performance;
 mod score_state;
+mod stats;
 mod strains;
 
 /// Marker type for [`GameMode::Mania`].
@@ -34,6 +40,49 @@ impl Mania {
         debug_assert!(!map.is_convert && map.mode == GameMode::Osu);
         convert::convert(map, mods);
     }
+
+    /// The amount of key columns of an osu!standard [`Beatmap`] after being
+    /// converted to osu!mania.
+    pub(crate) fn target_columns(map: &Beatmap, mods: &GameMods) -> f32 {
+        debug_assert!(!map.is_convert && map.mode == GameMode::Osu);
+        convert::target_columns(map, mods)
+    }
+
+    /// The column of each hitobject of an osu!mania [`Beatmap`].
+    pub(crate) fn object_columns(map: &Beatmap) -> Vec<usize> {
+        let total_columns = map.mania_total_columns();
+
+        map.hit_objects
+            .iter()
+            .map(|h| ManiaObject::column(h.pos.x, total_columns))
+            .collect()
+    }
+
+    /// The combo of each hitobject of an osu!mania [`Beatmap`].
+    pub(crate) fn object_combo_values(map: &Beatmap, mods: &GameMods) -> Vec<u32> {
+        let mut map = Cow::Borrowed(map);
+
+        if mods.ho() {
+            convert::apply_hold_off_to_beatmap(map.to_mut());
+        }
+
+        if mods.invert() {
+            convert::apply_invert_to_beatmap(map.to_mut());
+        }
+
+        let total_columns = map.mania_total_columns();
+        let mut params = ObjectParams::new(&map);
+
+        map.hit_objects
+            .iter()
+            .map(|h| {
+                let prev = params.max_combo();
+                ManiaObject::new(h, total_columns, &mut params);
+
+                params.max_combo() - prev
+            })
+            .collect()
+    }
 }
 
 impl IGameMode for Mania {
@@ -69,6 +69,14 @@ impl ManiaObject {
         }
     }
 
+    /// Treat the object as a regular note by dropping its duration.
+    pub const fn into_note(self) -> Self {
+        Self {
+            end_time: self.start_time,
+            ..self
+        }
+    }
+
     pub fn column(x: f32, total_columns: f32) -> usize {
         let x_divisor = 512.0 / total_columns;
 
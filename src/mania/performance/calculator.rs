@@ -7,6 +7,7 @@ pub(super) struct ManiaPerformanceCalculator<'mods> {
     attrs: ManiaDifficultyAttributes,
     mods: &'mods GameMods,
     state: ManiaScoreState,
+    classic: bool,
 }
 
 impl<'a> ManiaPerformanceCalculator<'a> {
@@ -14,8 +15,14 @@ impl<'a> ManiaPerformanceCalculator<'a> {
         attrs: ManiaDifficultyAttributes,
         mods: &'a GameMods,
         state: ManiaScoreState,
+        classic: bool,
     ) -> Self {
-        Self { attrs, mods, state }
+        Self {
+            attrs,
+            mods,
+            state,
+            classic,
+        }
     }
 }
 
@@ -38,9 +45,92 @@ impl ManiaPerformanceCalculator<'_> {
             difficulty: self.attrs,
             pp,
             pp_difficulty: difficulty_value,
+            accuracy: self.state.accuracy(self.classic),
+        }
+    }
+
+    /// Calculate pp through the score-based formula that was used before
+    /// accuracy replaced score in 2022.
+    pub fn calculate_legacy(self, score: u32, great_hit_window: f64) -> ManiaPerformanceAttributes {
+        let score = self.legacy_score(score);
+        let strain_value = self.compute_legacy_strain_value(score);
+        let acc_value = Self::compute_legacy_acc_value(strain_value, score, great_hit_window);
+
+        let mut multiplier = 0.8;
+
+        if self.mods.nf() {
+            multiplier *= 0.9;
+        }
+
+        if self.mods.so() {
+            multiplier *= 0.95;
+        }
+
+        if self.mods.ez() {
+            multiplier *= 0.5;
+        }
+
+        let pp = (strain_value.powf(1.1) + acc_value.powf(1.1)).powf(1.0 / 1.1) * multiplier;
+
+        ManiaPerformanceAttributes {
+            difficulty: self.attrs,
+            pp,
+            pp_difficulty: strain_value,
+            accuracy: self.state.accuracy(self.classic),
         }
     }
 
+    /// Scale the score up so that it's comparable across mods.
+    fn legacy_score(&self, score: u32) -> f64 {
+        let mut score_multiplier = 1.0;
+
+        if self.mods.ez() {
+            score_multiplier *= 0.5;
+        }
+
+        if self.mods.nf() {
+            score_multiplier *= 0.5;
+        }
+
+        if self.mods.ht() {
+            score_multiplier *= 0.5;
+        }
+
+        f64::from(score) / score_multiplier
+    }
+
+    fn compute_legacy_strain_value(&self, score: f64) -> f64 {
+        let mut strain_value =
+            f64::powf(5.0 * f64::max(1.0, self.attrs.stars / 0.2) - 4.0, 2.2) / 135.0;
+
+        strain_value *= 1.0 + 0.1 * f64::min(1.0, self.total_hits() / 1500.0);
+
+        strain_value
+            * if score <= 500_000.0 {
+                0.0
+            } else if score <= 600_000.0 {
+                (score - 500_000.0) / 100_000.0 * 0.3
+            } else if score <= 700_000.0 {
+                0.3 + (score - 600_000.0) / 100_000.0 * 0.25
+            } else if score <= 800_000.0 {
+                0.55 + (score - 700_000.0) / 100_000.0 * 0.2
+            } else if score <= 900_000.0 {
+                0.75 + (score - 800_000.0) / 100_000.0 * 0.15
+            } else {
+                0.9 + (score - 900_000.0) / 100_000.0 * 0.1
+            }
+    }
+
+    fn compute_legacy_acc_value(strain_value: f64, score: f64, great_hit_window: f64) -> f64 {
+        if great_hit_window <= 0.0 {
+            return 0.0;
+        }
+
+        f64::max(0.0, 0.2 - (great_hit_window - 34.0) * 0.006_667)
+            * strain_value
+            * f64::powf(f64::max(0.0, score - 960_000.0) / 40_000.0, 1.1)
+    }
+
     fn compute_difficulty_value(&self) -> f64 {
         // * Star rating to pp curve
         8.0 * f64::powf(f64::max(self.attrs.stars - 0.15, 0.05), 2.2)
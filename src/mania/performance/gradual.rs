@@ -1,4 +1,7 @@
-use crate::{mania::ManiaGradualDifficulty, model::mode::ConvertError, Beatmap, Difficulty};
+use crate::{
+    any::performance::judgement::JudgementTotals, mania::ManiaGradualDifficulty,
+    model::mode::ConvertError, Beatmap, Difficulty,
+};
 
 use super::{ManiaPerformanceAttributes, ManiaScoreState};
 
@@ -66,6 +69,7 @@ use super::{ManiaPerformanceAttributes, ManiaScoreState};
 /// [`nth`]: ManiaGradualPerformance::nth
 pub struct ManiaGradualPerformance {
     difficulty: ManiaGradualDifficulty,
+    pub(crate) totals: JudgementTotals,
 }
 
 impl ManiaGradualPerformance {
@@ -73,7 +77,10 @@ impl ManiaGradualPerformance {
     pub fn new(difficulty: Difficulty, map: &Beatmap) -> Result<Self, ConvertError> {
         let difficulty = ManiaGradualDifficulty::new(difficulty, map)?;
 
-        Ok(Self { difficulty })
+        Ok(Self {
+            difficulty,
+            totals: JudgementTotals::default(),
+        })
     }
 
     /// Process the next hit object and calculate the performance attributes
@@ -108,6 +115,28 @@ impl ManiaGradualPerformance {
         Some(performance)
     }
 
+    /// Process everything up to the hit object at index `idx` and calculate
+    /// the performance attributes for the resulting score state.
+    ///
+    /// Unlike [`nth`](Self::nth), `idx` is not relative to the current
+    /// position so already processed objects can be revisited. The result is
+    /// the same as calling `next` `idx + 1` times on a new instance.
+    ///
+    /// Returns `None` if `idx` is out of bounds.
+    pub fn at_index(
+        &mut self,
+        state: ManiaScoreState,
+        idx: usize,
+    ) -> Option<ManiaPerformanceAttributes> {
+        if idx >= self.difficulty.idx + self.difficulty.len() {
+            return None;
+        }
+
+        self.difficulty.rewind(idx);
+
+        self.nth(state, idx - self.difficulty.idx)
+    }
+
     /// Returns the amount of remaining objects.
     #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
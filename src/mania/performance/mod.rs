@@ -6,7 +6,7 @@ use self::calculator::ManiaPerformanceCalculator;
 
 use crate::{
     any::{Difficulty, HitResultPriority, IntoModePerformance, IntoPerformance},
-    model::{mode::ConvertError, mods::GameMods},
+    model::{beatmap::BeatmapAttributesBuilder, mode::ConvertError, mods::GameMods},
     osu::OsuPerformance,
     util::map_or_attrs::MapOrAttrs,
     Performance,
@@ -30,6 +30,8 @@ pub struct ManiaPerformance<'map> {
     n50: Option<u32>,
     misses: Option<u32>,
     acc: Option<f64>,
+    classic_score: Option<u32>,
+    lazer_total_score: Option<u32>,
     hitresult_priority: HitResultPriority,
 }
 
@@ -161,6 +163,31 @@ impl<'map> ManiaPerformance<'map> {
         self
     }
 
+    /// Specify the classic score of an osu!stable play, i.e. `ScoreV1` with a
+    /// maximum of `1_000_000`.
+    ///
+    /// If specified, pp will be calculated through the score-based formula
+    /// that was used before accuracy replaced score in 2022. Hitresults are
+    /// still used for the length bonus and the accuracy attribute.
+    pub const fn classic_score(mut self, score: u32) -> Self {
+        self.classic_score = Some(score);
+
+        self
+    }
+
+    /// Specify the standardised total score of an osu!lazer play with a
+    /// maximum of `1_000_000`.
+    ///
+    /// If no accuracy or hitresults are specified, the accuracy will be
+    /// derived from the score and used to generate hitresults. Since the
+    /// score's combo portion is unknown, a full combo is assumed so the
+    /// derived accuracy is a lower bound.
+    pub const fn lazer_total_score(mut self, score: u32) -> Self {
+        self.lazer_total_score = Some(score);
+
+        self
+    }
+
     /// Specify how hitresults should be generated.
     ///
     /// Defauls to [`HitResultPriority::BestCase`].
@@ -625,6 +652,48 @@ impl<'map> ManiaPerformance<'map> {
                             best.n320 = 0;
                         }
                     }
+                    HitResultPriority::Balanced => {
+                        // Meet halfway between both shifts
+                        if self.n100.is_none() && self.n200.is_none() {
+                            let to_best = best.n200 / 2;
+                            let to_worst = cmp::min(best.n320, best.n100);
+
+                            if to_best >= to_worst {
+                                let n = (to_best - to_worst) / 2;
+                                best.n320 += n;
+                                best.n200 -= 2 * n;
+                                best.n100 += n;
+                            } else {
+                                let n = (to_worst - to_best) / 2;
+                                best.n320 -= n;
+                                best.n200 += 2 * n;
+                                best.n100 -= n;
+                            }
+                        }
+
+                        if self.n50.is_none() && self.n200.is_none() {
+                            let to_best = best.n200 / 5;
+                            let to_worst = cmp::min(best.n320 / 3, best.n50 / 2);
+
+                            if to_best >= to_worst {
+                                let n = (to_best - to_worst) / 2;
+                                best.n320 += n * 3;
+                                best.n200 -= n * 5;
+                                best.n50 += n * 2;
+                            } else {
+                                let n = (to_worst - to_best) / 2;
+                                best.n320 -= n * 3;
+                                best.n200 += n * 5;
+                                best.n50 -= n * 2;
+                            }
+                        }
+
+                        if self.n300.is_none() {
+                            let n = best.n320 / 2;
+                            best.n300 += n;
+                            best.n320 -= n;
+                        }
+                    }
                 }
             }
 
@@ -643,6 +712,25 @@ impl<'map> ManiaPerformance<'map> {
                             n320 += remaining;
                         }
                         HitResultPriority::WorstCase => n50 += remaining,
+                        HitResultPriority::Balanced => {
+                            let state = |n320, n50| ManiaScoreState {
+                                n320,
+                                n300,
+                                n200,
+                                n100,
+                                n50,
+                                misses,
+                            };
+
+                            let worst_acc = state(n320, n50 + remaining).accuracy(classic);
+                            let best_acc = state(n320 + remaining, n50).accuracy(classic);
+                            let n = HitResultPriority::balanced_split(
+                                remaining, acc, worst_acc, best_acc,
+                            );
+
+                            n320 += n;
+                            n50 += remaining - n;
+                        }
                     }
                 }
 
@@ -671,7 +759,9 @@ impl<'map> ManiaPerformance<'map> {
             let remaining = n_remaining.saturating_sub(n320 + n300 + n200 + n100 + n50);
 
             match priority {
-                HitResultPriority::BestCase | HitResultPriority::Fastest => {
+                HitResultPriority::BestCase
+                | HitResultPriority::Fastest
+                | HitResultPriority::Balanced => {
                     match (self.n320, self.n300, self.n200, self.n100, self.n50) {
                         (None, ..) => n320 = remaining,
                         (_, None, ..) => n300 = remaining,
@@ -713,6 +803,14 @@ impl<'map> ManiaPerformance<'map> {
 
     /// Calculate all performance related values, including pp and stars.
     pub fn calculate(mut self) -> Result<ManiaPerformanceAttributes, ConvertError> {
+        if let (None, Some(score)) = (self.acc, self.lazer_total_score) {
+            self.acc = Some(lazer_score_to_accuracy(score));
+        }
+
+        let legacy_hit_window = self
+            .classic_score
+            .map(|score| (score, self.legacy_great_hit_window()));
+
         let state = self.generate_state()?;
 
         let attrs = match self.map_or_attrs {
@@ -720,7 +818,36 @@ impl<'map> ManiaPerformance<'map> {
             MapOrAttrs::Map(ref map) => self.difficulty.calculate_for_mode::<Mania>(map)?,
         };
 
-        Ok(ManiaPerformanceCalculator::new(attrs, self.difficulty.get_mods(), state).calculate())
+        let classic = !self.difficulty.get_lazer() || self.difficulty.get_mods().cl();
+        let inner =
+            ManiaPerformanceCalculator::new(attrs, self.difficulty.get_mods(), state, classic);
+
+        let attrs = match legacy_hit_window {
+            Some((score, great_hit_window)) => inner.calculate_legacy(score, great_hit_window),
+            None => inner.calculate(),
+        };
+
+        Ok(attrs)
+    }
+
+    /// The great hit window as it was used by the score-based formula,
+    /// i.e. without accounting for the clock rate.
+    fn legacy_great_hit_window(&self) -> f64 {
+        let builder = match self.map_or_attrs {
+            MapOrAttrs::Map(ref map) => BeatmapAttributesBuilder::new().map(map).mode(
+                GameMode::Mania,
+                map.is_convert || map.mode != GameMode::Mania,
+            ),
+            MapOrAttrs::Attrs(ref attrs) => {
+                BeatmapAttributesBuilder::new().mode(GameMode::Mania, attrs.is_convert)
+            }
+        };
+
+        builder
+            .difficulty(&self.difficulty)
+            .clock_rate(1.0)
+            .hit_windows()
+            .od_great
     }
 
     pub(crate) const fn from_map_or_attrs(map_or_attrs: MapOrAttrs<'map, Mania>) -> Self {
@@ -734,11 +861,39 @@ impl<'map> ManiaPerformance<'map> {
             n50: None,
             misses: None,
             acc: None,
+            classic_score: None,
+            lazer_total_score: None,
             hitresult_priority: HitResultPriority::DEFAULT,
         }
     }
 }
 
+/// Invert lazer's standardised scoring for osu!mania, assuming a full combo.
+fn lazer_score_to_accuracy(score: u32) -> f64 {
+    // * 150_000 * comboProgress + 850_000 * acc^(2 + 2 * acc) * accuracyProgress
+    let target = (f64::from(score) - 150_000.0) / 850_000.0;
+
+    if target <= 0.0 {
+        return 0.0;
+    } else if target >= 1.0 {
+        return 1.0;
+    }
+
+    let (mut low, mut high) = (0.0, 1.0);
+
+    for _ in 0..64 {
+        let mid = (low + high) / 2.0;
+
+        if f64::powf(mid, 2.0 + 2.0 * mid) < target {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    (low + high) / 2.0
+}
+
 impl<'map> TryFrom<OsuPerformance<'map>> for ManiaPerformance<'map> {
     type Error = OsuPerformance<'map>;
 
@@ -771,6 +926,7 @@ impl<'map> TryFrom<OsuPerformance<'map>> for ManiaPerformance<'map> {
             n100,
             n50,
             misses,
+            slider_breaks: _,
             hitresult_priority,
         } = osu;
 
@@ -784,6 +940,8 @@ impl<'map> TryFrom<OsuPerformance<'map>> for ManiaPerformance<'map> {
             n50,
             misses,
             acc,
+            classic_score: None,
+            lazer_total_score: None,
             hitresult_priority,
         })
     }
@@ -797,6 +955,15 @@ impl<'map, T: IntoModePerformance<'map, Mania>> From<T> for ManiaPerformance<'ma
 
 #[cfg(test)]
 mod tests {
+    #![allow(
+        clippy::default_trait_access,
+        clippy::doc_markdown,
+        clippy::float_cmp,
+        clippy::items_after_statements,
+        clippy::too_many_arguments,
+        clippy::uninlined_format_args
+    )]
+
     use std::{cmp::Ordering, sync::OnceLock, time::Instant};
 
     use proptest::{
@@ -1375,6 +1542,30 @@ mod tests {
         assert_eq!(state, expected);
     }
 
+    #[test]
+    fn hitresults_acc_balanced() {
+        let classic = true;
+
+        let state = |priority| {
+            ManiaPerformance::from(attrs())
+                .lazer(!classic)
+                .mods(mods(classic))
+                .accuracy(95.0)
+                .misses(2)
+                .hitresult_priority(priority)
+                .generate_state()
+                .unwrap()
+        };
+
+        let best = state(HitResultPriority::BestCase);
+        let worst = state(HitResultPriority::WorstCase);
+        let balanced = state(HitResultPriority::Balanced);
+
+        assert!(best.n320 > balanced.n320 && balanced.n320 > worst.n320);
+        assert!(best.n200 < balanced.n200 && balanced.n200 < worst.n200);
+        assert!((balanced.accuracy(classic) - best.accuracy(classic)).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn hitresults_n100_n50_misses_worst() {
         let classic = true;
@@ -1401,6 +1592,114 @@ mod tests {
         assert_eq!(state, expected);
     }
 
+    #[test]
+    fn classic_score_reference_values() {
+        // * Output of osu-performance's `ManiaScore` for the fixture's
+        // * 3.3583 stars and 594 objects. It computes in `f32` so the values
+        // * only match up to its precision.
+        let cases = [
+            (1_000_000, 8.0, 0, 105.995_17),
+            (985_432, 8.0, 0, 99.731_25),
+            (876_543, 8.0, 0, 81.815_79),
+            (490_000, 8.0, 1, 87.798_28),
+            (987_654, 8.0, 4096, 95.616_49),
+            (500_000, 8.0, 0, 0.0),
+        ];
+
+        for (score, od, mods, expected) in cases {
+            let result = ManiaPerformance::from(attrs())
+                .lazer(false)
+                .mods(mods)
+                .od(od, false)
+                .classic_score(score)
+                .calculate()
+                .unwrap();
+
+            assert!(
+                (result.pp - expected).abs() < 1e-4,
+                "{score} {mods}: {} != {expected}",
+                result.pp
+            );
+        }
+    }
+
+    #[test]
+    fn classic_score_mod_multiplier() {
+        let nomod = ManiaPerformance::from(attrs())
+            .lazer(false)
+            .classic_score(450_000)
+            .calculate()
+            .unwrap();
+
+        assert_eq!(nomod.pp, 0.0);
+
+        // * NF halves the score so 450k are scaled back up to 900k
+        let nofail = ManiaPerformance::from(attrs())
+            .lazer(false)
+            .mods(1)
+            .classic_score(450_000)
+            .calculate()
+            .unwrap();
+
+        let scaled = ManiaPerformance::from(attrs())
+            .lazer(false)
+            .classic_score(900_000)
+            .calculate()
+            .unwrap();
+
+        assert!((nofail.pp - scaled.pp * 0.9).abs() < 1e-9);
+    }
+
+    #[test]
+    fn lazer_total_score() {
+        assert_eq!(lazer_score_to_accuracy(100_000), 0.0);
+        assert_eq!(lazer_score_to_accuracy(1_000_000), 1.0);
+
+        // * lazer's standardised scoring for mania awards a total score of
+        // * 901_421 for a full combo with these hitresults on the fixture
+        let by_hitresults = ManiaPerformance::from(attrs())
+            .n320(N_OBJECTS + N_HOLD_NOTES - 144)
+            .n300(100)
+            .n200(30)
+            .n100(10)
+            .n50(4)
+            .misses(0)
+            .calculate()
+            .unwrap();
+
+        let by_score = ManiaPerformance::from(attrs())
+            .lazer_total_score(901_421)
+            .calculate()
+            .unwrap();
+
+        assert!((by_hitresults.accuracy - 0.969_184_913_4).abs() < 1e-9);
+        assert!(
+            (by_score.accuracy - by_hitresults.accuracy).abs() < 1e-6,
+            "{} != {}",
+            by_score.accuracy,
+            by_hitresults.accuracy
+        );
+
+        let ss = ManiaPerformance::from(attrs())
+            .lazer_total_score(1_000_000)
+            .calculate()
+            .unwrap();
+
+        let by_acc = ManiaPerformance::from(attrs())
+            .accuracy(100.0)
+            .calculate()
+            .unwrap();
+
+        assert_eq!(ss, by_acc);
+
+        let lower = ManiaPerformance::from(attrs())
+            .lazer_total_score(900_000)
+            .calculate()
+            .unwrap();
+
+        assert!(lower.pp < ss.pp);
+    }
+
     #[test]
     fn create() {
         let mut map = beatmap();
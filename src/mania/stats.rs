@@ -0,0 +1,97 @@
+use rosu_map::section::general::GameMode;
+
+use crate::{
+    mania::{
+        convert,
+        object::{ManiaObject, ObjectParams},
+    },
+    model::mode::ConvertError,
+    Beatmap, Difficulty, GameMods,
+};
+
+/// Note density and column statistics of an osu!mania map.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ManiaStats {
+    /// Average amount of notes per second between the first and last note,
+    /// inclusive of rate-adjusting mods (DT/HT/etc).
+    pub notes_per_second: f64,
+    /// Amount of notes for each column, starting at the leftmost column.
+    pub column_counts: Vec<u32>,
+    /// Ratio of hold notes to all notes between `0.0` and `1.0`.
+    pub ln_ratio: f64,
+}
+
+impl ManiaStats {
+    /// Total amount of notes.
+    pub fn n_notes(&self) -> u32 {
+        self.column_counts.iter().sum()
+    }
+}
+
+/// Gather note density and column statistics of an osu!mania map.
+///
+/// osu!standard maps are converted first.
+///
+/// # Example
+///
+/// ```
+/// use rosu_pp::{mania::mania_stats, Beatmap};
+///
+/// let map = Beatmap::from_path("./resources/1638954.osu").unwrap();
+/// let stats = mania_stats(&map, 0).unwrap();
+///
+/// assert_eq!(stats.column_counts.len(), 4);
+/// assert_eq!(stats.n_notes() as usize, map.hit_objects.len());
+/// ```
+pub fn mania_stats(map: &Beatmap, mods: impl Into<GameMods>) -> Result<ManiaStats, ConvertError> {
+    let difficulty = Difficulty::new().mods(mods);
+    let mods = difficulty.get_mods();
+    let mut map = map.convert_ref(GameMode::Mania, mods)?;
+
+    if mods.ho() {
+        convert::apply_hold_off_to_beatmap(map.to_mut());
+    }
+
+    if mods.invert() {
+        convert::apply_invert_to_beatmap(map.to_mut());
+    }
+
+    if let Some(seed) = mods.random_seed() {
+        convert::apply_random_to_beatmap(map.to_mut(), seed);
+    }
+
+    let total_columns = map.mania_total_columns();
+    let mut params = ObjectParams::new(&map);
+    let mut column_counts = vec![0; total_columns as usize];
+    let mut start_time = f64::MAX;
+    let mut end_time = f64::MIN;
+
+    for h in map.hit_objects.iter() {
+        let obj = ManiaObject::new(h, total_columns, &mut params);
+
+        column_counts[obj.column] += 1;
+        start_time = start_time.min(obj.start_time);
+        end_time = end_time.max(obj.end_time);
+    }
+
+    let n_notes = map.hit_objects.len();
+    let duration = (end_time - start_time) / difficulty.get_clock_rate();
+
+    let notes_per_second = if duration > 0.0 {
+        n_notes as f64 / (duration / 1000.0)
+    } else {
+        0.0
+    };
+
+    let ln_ratio = if n_notes > 0 {
+        f64::from(params.n_hold_notes()) / n_notes as f64
+    } else {
+        0.0
+    };
+
+    Ok(ManiaStats {
+        notes_per_second,
+        column_counts,
+        ln_ratio,
+    })
+}
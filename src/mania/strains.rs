@@ -23,6 +23,7 @@ impl ManiaStrains {
 
 pub fn strains(difficulty: &Difficulty, map: &Beatmap) -> Result<ManiaStrains, ConvertError> {
     let map = map.convert_ref(GameMode::Mania, difficulty.get_mods())?;
+    let map = difficulty.apply_time_range(map);
     let values = DifficultyValues::calculate(difficulty, &map);
 
     Ok(ManiaStrains {
@@ -2,7 +2,11 @@ use std::collections::HashMap;
 
 use crate::model::{control_point::TimingPoint, hit_object::HitObject};
 
-pub fn bpm(last_hit_object: Option<&HitObject>, timing_points: &[TimingPoint]) -> f64 {
+/// The most common beat length, weighted by duration.
+pub fn most_common_beat_len(
+    last_hit_object: Option<&HitObject>,
+    timing_points: &[TimingPoint],
+) -> f64 {
     // This is incorrect if the last object is a slider since there
     // is no reasonable way to get the slider end time at this point.
     let last_time = last_hit_object
@@ -31,14 +35,27 @@ pub fn bpm(last_hit_object: Option<&HitObject>, timing_points: &[TimingPoint]) -
         bpm_points.add(curr.beat_len, curr.time, last_time);
     }
 
-    let most_common_beat_len = bpm_points
+    bpm_points
         .map
         .into_iter()
         // * Get the most common one, or 0 as a suitable default
         .max_by(|(_, a), (_, b)| a.total_cmp(b))
-        .map_or(0.0, |(beat_len, _)| f64::from_bits(beat_len));
+        .map_or(0.0, |(beat_len, _)| f64::from_bits(beat_len))
+}
+
+/// The shortest and longest beat length.
+pub fn beat_len_range(timing_points: &[TimingPoint]) -> (f64, f64) {
+    if timing_points.is_empty() {
+        let beat_len = TimingPoint::DEFAULT_BEAT_LEN;
+
+        return (beat_len, beat_len);
+    }
 
-    60_000.0 / most_common_beat_len
+    timing_points
+        .iter()
+        .fold((f64::MAX, f64::MIN), |(min, max), point| {
+            (min.min(point.beat_len), max.max(point.beat_len))
+        })
 }
 
 /// Maps `beat_len` to a cumulative duration
@@ -0,0 +1,267 @@
+use rosu_map::section::{general::GameMode, hit_objects::hit_samples::HitSoundType};
+
+use crate::{
+    model::{
+        control_point::{DifficultyPoint, EffectPoint, TimingPoint},
+        hit_object::{HitObject, HitObjectKind, PathControlPoint, Pos, Slider, Spinner},
+    },
+    util::sort,
+};
+
+use super::{Beatmap, BreakPeriod};
+
+/// Builder to construct a [`Beatmap`] without a `.osu` file.
+///
+/// Difficulty settings are clamped and hitobjects and control points are
+/// sorted by time when calling [`BeatmapBuilder::build`], just like when
+/// decoding a `.osu` file.
+///
+/// # Example
+///
+/// ```
+/// use rosu_pp::{
+///     model::{beatmap::BeatmapBuilder, hit_object::Pos},
+///     Difficulty,
+/// };
+///
+/// let map = BeatmapBuilder::new()
+///     .od(8.0)
+///     .timing_point(0.0, 500.0)
+///     .circle(Pos::new(256.0, 192.0), 1000.0)
+///     .circle(Pos::new(356.0, 192.0), 1125.0)
+///     .build();
+///
+/// assert_eq!(map.hit_objects.len(), 2);
+///
+/// let attrs = Difficulty::new().calculate(&map);
+/// assert_eq!(attrs.max_combo(), 2);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[must_use]
+pub struct BeatmapBuilder {
+    map: Beatmap,
+}
+
+impl BeatmapBuilder {
+    /// Create a new [`BeatmapBuilder`] for an osu!standard map with default
+    /// difficulty settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specify the [`GameMode`] of the map.
+    pub const fn mode(mut self, mode: GameMode) -> Self {
+        self.map.mode = mode;
+
+        self
+    }
+
+    /// Specify the approach rate.
+    pub const fn ar(mut self, ar: f32) -> Self {
+        self.map.ar = ar;
+
+        self
+    }
+
+    /// Specify the circle size.
+    ///
+    /// For osu!mania this is the amount of keys.
+    pub const fn cs(mut self, cs: f32) -> Self {
+        self.map.cs = cs;
+
+        self
+    }
+
+    /// Specify the drain rate.
+    pub const fn hp(mut self, hp: f32) -> Self {
+        self.map.hp = hp;
+
+        self
+    }
+
+    /// Specify the overall difficulty.
+    pub const fn od(mut self, od: f32) -> Self {
+        self.map.od = od;
+
+        self
+    }
+
+    /// Specify the slider multiplier.
+    pub const fn slider_multiplier(mut self, slider_multiplier: f64) -> Self {
+        self.map.slider_multiplier = slider_multiplier;
+
+        self
+    }
+
+    /// Specify the slider tick rate.
+    pub const fn slider_tick_rate(mut self, slider_tick_rate: f64) -> Self {
+        self.map.slider_tick_rate = slider_tick_rate;
+
+        self
+    }
+
+    /// Specify the stack leniency.
+    pub const fn stack_leniency(mut self, stack_leniency: f32) -> Self {
+        self.map.stack_leniency = stack_leniency;
+
+        self
+    }
+
+    /// Add a timing point i.e. an uninherited control point.
+    pub fn timing_point(mut self, time: f64, beat_len: f64) -> Self {
+        self.map
+            .timing_points
+            .push(TimingPoint::new(time, beat_len));
+
+        self
+    }
+
+    /// Add a difficulty point i.e. an inherited control point.
+    pub fn difficulty_point(mut self, point: DifficultyPoint) -> Self {
+        self.map.difficulty_points.push(point);
+
+        self
+    }
+
+    /// Add an effect point.
+    pub fn effect_point(mut self, time: f64, kiai: bool) -> Self {
+        self.map.effect_points.push(EffectPoint::new(time, kiai));
+
+        self
+    }
+
+    /// Add a break.
+    pub fn break_period(mut self, start_time: f64, end_time: f64) -> Self {
+        self.map.breaks.push(BreakPeriod {
+            start_time,
+            end_time,
+        });
+
+        self
+    }
+
+    /// Add a [`HitObject`] without hitsounds.
+    pub fn hit_object(self, h: HitObject) -> Self {
+        self.hit_object_with_sound(h, HitSoundType::default())
+    }
+
+    /// Add a [`HitObject`] with the given [`HitSoundType`].
+    pub fn hit_object_with_sound(mut self, h: HitObject, sound: HitSoundType) -> Self {
+        self.map.hit_objects.push(h);
+        self.map.hit_sounds.push(sound);
+
+        self
+    }
+
+    /// Add a circle at the given position and time.
+    pub fn circle(self, pos: Pos, start_time: f64) -> Self {
+        self.hit_object(HitObject {
+            pos,
+            start_time,
+            kind: HitObjectKind::Circle,
+        })
+    }
+
+    /// Add a slider at the given position and time.
+    ///
+    /// Positions of the control points are relative to `pos`, i.e. the first
+    /// control point is usually at `(0, 0)` and carries the [`PathType`]. If
+    /// `expected_dist` is `None`, the length of the path is determined by the
+    /// control points alone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::model::{
+    ///     beatmap::BeatmapBuilder,
+    ///     hit_object::{PathControlPoint, PathType, Pos},
+    /// };
+    ///
+    /// let control_points = [
+    ///     PathControlPoint {
+    ///         pos: Pos::new(0.0, 0.0),
+    ///         path_type: Some(PathType::LINEAR),
+    ///     },
+    ///     PathControlPoint::new(Pos::new(100.0, 0.0)),
+    /// ];
+    ///
+    /// let map = BeatmapBuilder::new()
+    ///     .timing_point(0.0, 500.0)
+    ///     .slider(Pos::new(256.0, 192.0), 1000.0, control_points, 1, Some(100.0))
+    ///     .spinner(Pos::new(256.0, 192.0), 3000.0, 2000.0)
+    ///     .build();
+    ///
+    /// assert!(map.hit_objects[0].is_slider());
+    /// assert!(map.hit_objects[1].is_spinner());
+    /// ```
+    ///
+    /// [`PathType`]: crate::model::hit_object::PathType
+    pub fn slider(
+        self,
+        pos: Pos,
+        start_time: f64,
+        control_points: impl Into<Box<[PathControlPoint]>>,
+        repeats: usize,
+        expected_dist: Option<f64>,
+    ) -> Self {
+        let slider = Slider {
+            expected_dist,
+            repeats,
+            control_points: control_points.into(),
+            node_sounds: vec![HitSoundType::default(); repeats + 2].into_boxed_slice(),
+        };
+
+        self.hit_object(HitObject {
+            pos,
+            start_time,
+            kind: HitObjectKind::Slider(slider),
+        })
+    }
+
+    /// Add a spinner at the given position and time.
+    pub fn spinner(self, pos: Pos, start_time: f64, duration: f64) -> Self {
+        self.hit_object(HitObject {
+            pos,
+            start_time,
+            kind: HitObjectKind::Spinner(Spinner {
+                duration: duration.max(0.0),
+            }),
+        })
+    }
+
+    /// Build the [`Beatmap`].
+    pub fn build(self) -> Beatmap {
+        let Self { mut map } = self;
+
+        map.hp = map.hp.clamp(0.0, 10.0);
+
+        map.cs = if map.mode == GameMode::Mania {
+            map.cs.clamp(1.0, 18.0)
+        } else {
+            map.cs.clamp(0.0, 10.0)
+        };
+
+        map.od = map.od.clamp(0.0, 10.0);
+        map.ar = map.ar.clamp(0.0, 10.0);
+        map.slider_multiplier = map.slider_multiplier.clamp(0.4, 3.6);
+        map.slider_tick_rate = map.slider_tick_rate.clamp(0.5, 8.0);
+
+        map.timing_points.sort_by(|a, b| a.time.total_cmp(&b.time));
+        map.difficulty_points
+            .sort_by(|a, b| a.time.total_cmp(&b.time));
+        map.effect_points.sort_by(|a, b| a.time.total_cmp(&b.time));
+
+        let mut sorter = sort::TandemSorter::new_stable(&map.hit_objects, |a, b| {
+            a.start_time.total_cmp(&b.start_time)
+        });
+
+        sorter.sort(&mut map.hit_objects);
+        sorter.sort(&mut map.hit_sounds);
+
+        if map.mode == GameMode::Mania {
+            sort::osu_legacy(&mut map.hit_objects);
+        }
+
+        map
+    }
+}
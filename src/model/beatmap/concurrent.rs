@@ -0,0 +1,149 @@
+use rosu_map::section::general::GameMode;
+
+use crate::{mania::Mania, osu::Osu, Difficulty};
+
+use super::Beatmap;
+
+/// Time ranges in which multiple hit objects are active at the same time.
+///
+/// For osu!mania, only objects within the same column are considered. Slider
+/// durations are based on the map's own slider multiplier and tick rate.
+pub(super) fn concurrent_object_ranges(map: &Beatmap) -> Vec<(f64, f64)> {
+    if map.mode != GameMode::Mania {
+        let end_times = Osu::object_end_times(&Difficulty::new(), map);
+
+        let intervals = map
+            .hit_objects
+            .iter()
+            .zip(end_times)
+            .map(|(h, end_time)| (h.start_time, end_time));
+
+        return overlapping_ranges(intervals);
+    }
+
+    let columns = Mania::object_columns(map);
+    let total_columns = columns.iter().max().map_or(0, |max| max + 1);
+
+    let mut ranges: Vec<_> = (0..total_columns)
+        .flat_map(|column| {
+            let intervals = map
+                .hit_objects
+                .iter()
+                .zip(columns.iter())
+                .filter(|(_, &col)| col == column)
+                .map(|(h, _)| (h.start_time, h.end_time()));
+
+            overlapping_ranges(intervals)
+        })
+        .collect();
+
+    ranges.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut merged: Vec<(f64, f64)> = Vec::with_capacity(ranges.len());
+
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    merged
+}
+
+/// Merge the `(start, end)` intervals, sorted by start time, into ranges in
+/// which at least two of them overlap.
+///
+/// Intervals overlap if one starts before the other ends or if both start at
+/// the same time.
+fn overlapping_ranges(intervals: impl Iterator<Item = (f64, f64)>) -> Vec<(f64, f64)> {
+    let mut ranges = Vec::new();
+
+    // The range of the current group of intervals and whether it contains
+    // overlapping intervals
+    let mut curr: Option<(f64, f64, bool)> = None;
+    let mut prev_start = f64::NAN;
+
+    for (start, end) in intervals {
+        match curr {
+            Some((_, ref mut curr_end, ref mut overlaps))
+                if start < *curr_end || start.total_cmp(&prev_start).is_eq() =>
+            {
+                *curr_end = curr_end.max(end);
+                *overlaps = true;
+            }
+            _ => {
+                if let Some((curr_start, curr_end, true)) = curr {
+                    ranges.push((curr_start, curr_end));
+                }
+
+                curr = Some((start, end, false));
+            }
+        }
+
+        prev_start = start;
+    }
+
+    if let Some((curr_start, curr_end, true)) = curr {
+        ranges.push((curr_start, curr_end));
+    }
+
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        beatmap::BeatmapBuilder,
+        hit_object::{HitObject, HitObjectKind, HoldNote, Pos},
+    };
+
+    use super::*;
+
+    #[test]
+    fn overlaps() {
+        let intervals = [
+            (0.0, 0.0),
+            (100.0, 100.0),
+            (100.0, 100.0),
+            (200.0, 500.0),
+            (300.0, 300.0),
+            (600.0, 600.0),
+        ];
+
+        let ranges = overlapping_ranges(intervals.into_iter());
+
+        assert_eq!(ranges, vec![(100.0, 100.0), (200.0, 500.0)]);
+    }
+
+    #[test]
+    fn mania_chords_are_not_concurrent() {
+        let hold = |x: f32, start_time: f64, duration: f64| HitObject {
+            pos: Pos::new(x, 192.0),
+            start_time,
+            kind: HitObjectKind::Hold(HoldNote { duration }),
+        };
+
+        let map = BeatmapBuilder::new()
+            .mode(GameMode::Mania)
+            .cs(4.0)
+            .hit_object(hold(64.0, 0.0, 1000.0))
+            .hit_object(hold(192.0, 0.0, 1000.0))
+            .hit_object(hold(192.0, 1500.0, 1000.0))
+            .circle(Pos::new(64.0, 192.0), 2000.0)
+            .build();
+
+        assert!(!map.has_concurrent_objects());
+
+        let map = BeatmapBuilder::new()
+            .mode(GameMode::Mania)
+            .cs(4.0)
+            .hit_object(hold(64.0, 0.0, 1000.0))
+            .circle(Pos::new(64.0, 192.0), 500.0)
+            .hit_object(hold(192.0, 800.0, 1000.0))
+            .circle(Pos::new(192.0, 192.0), 1200.0)
+            .build();
+
+        assert_eq!(map.concurrent_object_ranges(), vec![(0.0, 1800.0)]);
+    }
+}
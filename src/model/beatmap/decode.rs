@@ -1,4 +1,4 @@
-use std::{cmp, error, fmt, slice};
+use std::{cell::RefCell, cmp, error, fmt, mem, slice};
 
 use rosu_map::{
     section::{
@@ -51,6 +51,52 @@ pub struct BeatmapState {
     point_split: Vec<*const str>,
 }
 
+/// Scratch buffers of a [`BeatmapState`] that are only used while decoding.
+#[derive(Default)]
+struct ScratchBuffers {
+    curve_points: Vec<PathControlPoint>,
+    vertices: Vec<PathControlPoint>,
+    point_split: Vec<*const str>,
+}
+
+impl ScratchBuffers {
+    fn preallocated() -> Self {
+        Self {
+            // mean=13.11 | median=8
+            curve_points: Vec::with_capacity(8),
+            // mean=16.27 | median=8
+            vertices: Vec::with_capacity(8),
+            // mean=19.97 | median=8
+            point_split: Vec::with_capacity(8),
+        }
+    }
+}
+
+thread_local! {
+    /// Scratch buffers that are handed from one decoded beatmap to the next.
+    ///
+    /// Only `Some` while [`with_scratch_buffers`] runs.
+    static SCRATCH_BUFFERS: RefCell<Option<ScratchBuffers>> = const { RefCell::new(None) };
+}
+
+/// Reuse scratch buffers across all beatmaps that are decoded on the current
+/// thread while `f` runs.
+pub(super) fn with_scratch_buffers<T>(f: impl FnOnce() -> T) -> T {
+    struct Guard(Option<ScratchBuffers>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            let prev = self.0.take();
+            SCRATCH_BUFFERS.with_borrow_mut(|bufs| *bufs = prev);
+        }
+    }
+
+    let prev = SCRATCH_BUFFERS.with_borrow_mut(|bufs| bufs.replace(ScratchBuffers::preallocated()));
+    let _guard = Guard(prev);
+
+    f()
+}
+
 impl BeatmapState {
     fn add_pending_point<P: Pending>(&mut self, time: f64, point: P, timing_change: bool) {
         if time.not_eq(self.pending_control_points_time) {
@@ -246,8 +292,64 @@ impl BeatmapState {
     }
 }
 
+/// Whether the `[HitObjects]` section of a `.osu` file contains more than
+/// `max_objects` lines that would be parsed as hitobjects.
+///
+/// Only scans the bytes without allocating. For UTF-16 encoded content the
+/// lines of the whole file are counted instead which serves as upper bound.
+pub(super) fn exceeds_object_count(bytes: &[u8], max_objects: usize) -> bool {
+    const SECTIONS: [&[u8]; 11] = [
+        b"[General]",
+        b"[Editor]",
+        b"[Metadata]",
+        b"[Difficulty]",
+        b"[Events]",
+        b"[TimingPoints]",
+        b"[Colours]",
+        b"[HitObjects]",
+        b"[Variables]",
+        b"[CatchTheBeat]",
+        b"[Mania]",
+    ];
+
+    if bytes.starts_with(&[0xFF, 0xFE]) || bytes.starts_with(&[0xFE, 0xFF]) {
+        return bytes
+            .split(|&byte| byte == b'\n')
+            .nth(max_objects + 1)
+            .is_some();
+    }
+
+    let mut in_hit_objects = false;
+    let mut count = 0;
+
+    for line in bytes.split(|&byte| byte == b'\n') {
+        let line = line.trim_ascii_end();
+
+        if SECTIONS.contains(&line) {
+            in_hit_objects = line == b"[HitObjects]";
+        } else if in_hit_objects && !line.is_empty() && !line.trim_ascii_start().starts_with(b"//")
+        {
+            count += 1;
+
+            if count > max_objects {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
 impl DecodeState for BeatmapState {
     fn create(version: i32) -> Self {
+        let ScratchBuffers {
+            curve_points,
+            vertices,
+            point_split,
+        } = SCRATCH_BUFFERS
+            .with_borrow_mut(|bufs| bufs.as_mut().map(mem::take))
+            .unwrap_or_else(ScratchBuffers::preallocated);
+
         Self {
             version,
             stack_leniency: DEFAULT_SLIDER_LENIENCY,
@@ -264,12 +366,9 @@ impl DecodeState for BeatmapState {
             pending_timing_point: None,
             pending_difficulty_point: None,
             pending_effect_point: None,
-            // mean=13.11 | median=8
-            curve_points: Vec::with_capacity(8),
-            // mean=16.27 | median=8
-            vertices: Vec::with_capacity(8),
-            // mean=19.97 | median=8
-            point_split: Vec::with_capacity(8),
+            curve_points,
+            vertices,
+            point_split,
         }
     }
 }
@@ -278,6 +377,18 @@ impl From<BeatmapState> for Beatmap {
     fn from(mut state: BeatmapState) -> Self {
         state.flush_pending_points();
 
+        SCRATCH_BUFFERS.with_borrow_mut(|bufs| {
+            if let Some(bufs) = bufs {
+                state.curve_points.clear();
+                state.vertices.clear();
+                state.point_split.clear();
+
+                bufs.curve_points = mem::take(&mut state.curve_points);
+                bufs.vertices = mem::take(&mut state.vertices);
+                bufs.point_split = mem::take(&mut state.point_split);
+            }
+        });
+
         let Difficulty {
             mut hp_drain_rate,
             mut circle_size,
@@ -0,0 +1,154 @@
+use std::mem;
+
+use crate::model::{
+    control_point::{DifficultyPoint, TimingPoint},
+    hit_object::HitObject,
+};
+
+use super::Beatmap;
+
+/// The differences between two [`Beatmap`]s.
+///
+/// Created through [`Beatmap::diff`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BeatmapDiff {
+    /// Changes in hit objects.
+    ///
+    /// Objects are matched by their start time and kind so an object that
+    /// was moved in position or whose properties changed is listed in
+    /// [`Changes::changed`] whereas an object moved in time shows up as both
+    /// removed and added.
+    pub hit_objects: Changes<HitObject>,
+    /// Changes in timing points, matched by their time.
+    pub timing_points: Changes<TimingPoint>,
+    /// Changes in difficulty points, matched by their time.
+    pub difficulty_points: Changes<DifficultyPoint>,
+}
+
+impl BeatmapDiff {
+    /// Whether both maps were identical in their hit objects and control
+    /// points.
+    pub const fn is_empty(&self) -> bool {
+        self.hit_objects.is_empty()
+            && self.timing_points.is_empty()
+            && self.difficulty_points.is_empty()
+    }
+
+    pub(super) fn new(old: &Beatmap, new: &Beatmap) -> Self {
+        Self {
+            hit_objects: Changes::new(
+                &old.hit_objects,
+                &new.hit_objects,
+                |h| h.start_time,
+                |a, b| mem::discriminant(&a.kind) == mem::discriminant(&b.kind),
+            ),
+            timing_points: Changes::new(
+                &old.timing_points,
+                &new.timing_points,
+                |point| point.time,
+                |_, _| true,
+            ),
+            difficulty_points: Changes::new(
+                &old.difficulty_points,
+                &new.difficulty_points,
+                |point| point.time,
+                |_, _| true,
+            ),
+        }
+    }
+}
+
+/// Added, removed, and changed items of a [`BeatmapDiff`], sorted by time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Changes<T> {
+    /// Items that are only present in the other map.
+    pub added: Vec<T>,
+    /// Items that are only present in the original map.
+    pub removed: Vec<T>,
+    /// Items that are present in both maps but differ, as pairs of the
+    /// original and the other item.
+    pub changed: Vec<(T, T)>,
+}
+
+impl<T> Changes<T> {
+    /// Whether there are no added, removed, or changed items.
+    pub const fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl<T: Clone + PartialEq> Changes<T> {
+    fn new(
+        old: &[T],
+        new: &[T],
+        time: impl Fn(&T) -> f64,
+        same_kind: impl Fn(&T, &T) -> bool,
+    ) -> Self {
+        let mut old: Vec<_> = old.iter().collect();
+        let mut new: Vec<_> = new.iter().collect();
+        old.sort_by(|a, b| time(a).total_cmp(&time(b)));
+        new.sort_by(|a, b| time(a).total_cmp(&time(b)));
+
+        let mut changes = Self::default();
+        let (mut old, mut new) = (old.as_slice(), new.as_slice());
+
+        while !old.is_empty() || !new.is_empty() {
+            let curr = match (old.first(), new.first()) {
+                (Some(a), Some(b)) => time(a).min(time(b)),
+                (Some(a), None) => time(a),
+                (None, Some(b)) => time(b),
+                (None, None) => unreachable!(),
+            };
+
+            let old_len = old
+                .iter()
+                .take_while(|item| time(item).total_cmp(&curr).is_eq())
+                .count();
+            let new_len = new
+                .iter()
+                .take_while(|item| time(item).total_cmp(&curr).is_eq())
+                .count();
+            let (old_group, old_rest) = old.split_at(old_len);
+            let (new_group, new_rest) = new.split_at(new_len);
+            changes.push_group(old_group, new_group, &same_kind);
+            old = old_rest;
+            new = new_rest;
+        }
+
+        changes
+    }
+
+    /// Compare items that share the same time.
+    fn push_group(&mut self, old: &[&T], new: &[&T], same_kind: impl Fn(&T, &T) -> bool) {
+        let mut new = new.to_vec();
+        let mut unmatched = Vec::new();
+
+        for &item in old {
+            match new.iter().position(|other| *other == item) {
+                Some(idx) => {
+                    new.remove(idx);
+                }
+                None => unmatched.push(item),
+            }
+        }
+
+        for item in unmatched {
+            match new.iter().position(|other| same_kind(item, other)) {
+                Some(idx) => self.changed.push((item.clone(), new.remove(idx).clone())),
+                None => self.removed.push(item.clone()),
+            }
+        }
+
+        self.added.extend(new.into_iter().cloned());
+    }
+}
+
+impl<T> Default for Changes<T> {
+    fn default() -> Self {
+        Self {
+            added: Vec::new(),
+            removed: Vec::new(),
+            changed: Vec::new(),
+        }
+    }
+}
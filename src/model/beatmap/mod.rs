@@ -1,23 +1,39 @@
-use std::{borrow::Cow, io, path::Path, str::FromStr};
+use std::{
+    borrow::Cow,
+    fs::File,
+    io::{self, BufReader, Read},
+    path::Path,
+    str::FromStr,
+};
 
 use rosu_map::{
     section::{general::GameMode, hit_objects::hit_samples::HitSoundType},
-    LATEST_FORMAT_VERSION,
+    DecodeBeatmap, LATEST_FORMAT_VERSION,
 };
 
 pub use rosu_map::section::events::BreakPeriod;
 
 use crate::{
-    catch::Catch, mania::Mania, taiko::Taiko, Difficulty, GameMods, GradualDifficulty,
-    GradualPerformance, Performance,
+    any::object_combo_values,
+    catch::Catch,
+    mania::Mania,
+    osu::{Osu, OsuNestedObject},
+    taiko::Taiko,
+    util::difficulty::milliseconds_to_bpm,
+    Difficulty, GameMods, GradualDifficulty, GradualPerformance, Performance,
 };
 
 pub use self::{
     attributes::{BeatmapAttributes, BeatmapAttributesBuilder, HitWindows},
+    builder::BeatmapBuilder,
     decode::{BeatmapState, ParseBeatmapError},
+    diff::{BeatmapDiff, Changes},
     suspicious::TooSuspicious,
+    warnings::ParseWarning,
 };
 
+use self::warnings::BeatmapWithWarnings;
+
 use super::{
     control_point::{
         difficulty_point_at, effect_point_at, timing_point_at, DifficultyPoint, EffectPoint,
@@ -29,8 +45,12 @@ use super::{
 
 mod attributes;
 mod bpm;
+mod builder;
+mod concurrent;
 mod decode;
+mod diff;
 mod suspicious;
+mod warnings;
 
 /// All beatmap data that is relevant for difficulty and performance
 /// calculation.
@@ -76,6 +96,140 @@ impl Beatmap {
         rosu_map::from_bytes(bytes)
     }
 
+    /// Parse a [`Beatmap`] by reading the content of a `.osu` file from the
+    /// given reader.
+    ///
+    /// The content is buffered and parsed line by line so it never needs to
+    /// be fully loaded into memory, e.g. when reading from a socket or an
+    /// archive entry.
+    ///
+    /// Lines that fail to parse are skipped like they are for
+    /// [`Beatmap::from_bytes`] so any returned error originates from the
+    /// reader itself and retains its [`io::ErrorKind`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::{fs::File, io::BufReader};
+    ///
+    /// use rosu_pp::Beatmap;
+    ///
+    /// let file = File::open("./resources/2785319.osu").unwrap();
+    /// let map = Beatmap::from_reader(BufReader::new(file)).unwrap();
+    ///
+    /// assert_eq!(map, Beatmap::from_path("./resources/2785319.osu").unwrap());
+    /// ```
+    pub fn from_reader<R: Read>(reader: R) -> Result<Self, io::Error> {
+        Self::decode(BufReader::new(reader))
+    }
+
+    /// Parse a [`Beatmap`] by providing the content of a `.osu` file as a
+    /// slice of bytes while limiting the amount of hitobjects.
+    ///
+    /// The content is scanned for hitobjects before anything is parsed so
+    /// that maps with excessive amounts of objects are never allocated in
+    /// the first place. If there are more than `max_objects` hitobjects, an
+    /// error of kind [`io::ErrorKind::InvalidData`] wrapping
+    /// [`TooSuspicious::ObjectCount`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{model::beatmap::TooSuspicious, Beatmap};
+    ///
+    /// let bytes = std::fs::read("./resources/2785319.osu").unwrap();
+    ///
+    /// assert!(Beatmap::from_bytes_limited(&bytes, 1000).is_ok());
+    ///
+    /// let err = Beatmap::from_bytes_limited(&bytes, 100).unwrap_err();
+    /// let inner = err.get_ref().and_then(|err| err.downcast_ref());
+    /// assert!(matches!(inner, Some(TooSuspicious::ObjectCount)));
+    /// ```
+    pub fn from_bytes_limited(bytes: &[u8], max_objects: usize) -> Result<Self, io::Error> {
+        if decode::exceeds_object_count(bytes, max_objects) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                TooSuspicious::ObjectCount,
+            ));
+        }
+
+        Self::from_bytes(bytes)
+    }
+
+    /// Parse a [`Beatmap`] by providing the content of a `.osu` file as a
+    /// slice of bytes while collecting all lines that could not be processed.
+    ///
+    /// Parsing is lenient so such lines are skipped silently by
+    /// [`Beatmap::from_bytes`]. This method returns them as [`ParseWarning`]s
+    /// instead, e.g. to log maps that could result in unexpected values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::Beatmap;
+    ///
+    /// let bytes = b"osu file format v14
+    ///
+    /// [Difficulty]
+    /// OverallDifficulty:8
+    ///
+    /// [HitObjects]
+    /// 256,192,1000,1,0
+    /// not a hitobject";
+    ///
+    /// let (map, warnings) = Beatmap::from_bytes_with_warnings(bytes).unwrap();
+    ///
+    /// assert_eq!(map.hit_objects.len(), 1);
+    /// assert_eq!(warnings.len(), 1);
+    /// assert_eq!(warnings[0].line, "not a hitobject");
+    /// ```
+    pub fn from_bytes_with_warnings(bytes: &[u8]) -> Result<(Self, Vec<ParseWarning>), io::Error> {
+        let BeatmapWithWarnings { map, warnings } = rosu_map::from_bytes(bytes)?;
+
+        Ok((map, warnings))
+    }
+
+    /// Parse multiple [`Beatmap`]s by providing paths to `.osu` files.
+    ///
+    /// Unlike calling [`Beatmap::from_path`] for each path, internal buffers
+    /// are reused across all beatmaps. A failure to parse one beatmap does not
+    /// affect the others.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::Beatmap;
+    ///
+    /// let maps = Beatmap::from_paths([
+    ///     "./resources/2785319.osu",
+    ///     "./resources/does_not_exist.osu",
+    ///     "./resources/1028484.osu",
+    /// ]);
+    ///
+    /// assert!(maps[0].is_ok());
+    /// assert!(maps[1].is_err());
+    /// assert!(maps[2].is_ok());
+    /// ```
+    pub fn from_paths<I, P>(paths: I) -> Vec<Result<Self, io::Error>>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        decode::with_scratch_buffers(|| {
+            let mut bytes = Vec::new();
+
+            paths
+                .into_iter()
+                .map(|path| {
+                    bytes.clear();
+                    File::open(path)?.read_to_end(&mut bytes)?;
+
+                    Self::from_bytes(&bytes)
+                })
+                .collect()
+        })
+    }
+
     /// Returns a [`BeatmapAttributesBuilder`] to calculate modified beatmap
     /// attributes.
     pub fn attributes(&self) -> BeatmapAttributesBuilder {
@@ -84,7 +238,281 @@ impl Beatmap {
 
     /// The beats per minute of the map.
     pub fn bpm(&self) -> f64 {
-        bpm::bpm(self.hit_objects.last(), &self.timing_points)
+        self.bpm_with_clock_rate(1.0)
+    }
+
+    /// The beats per minute of the map after applying the clock rate, e.g.
+    /// `1.5` for DT.
+    ///
+    /// This is the BPM of the most common beat length, weighted by duration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::Beatmap;
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// assert!((map.bpm_with_clock_rate(1.5) - 1.5 * map.bpm()).abs() < 1e-9);
+    /// assert!(map.min_bpm(1.0) <= map.max_bpm(1.0));
+    /// ```
+    pub fn bpm_with_clock_rate(&self, clock_rate: f64) -> f64 {
+        let beat_len = bpm::most_common_beat_len(self.hit_objects.last(), &self.timing_points);
+
+        milliseconds_to_bpm(beat_len / clock_rate, Some(1))
+    }
+
+    /// The lowest beats per minute of the map's timing points after applying
+    /// the clock rate.
+    pub fn min_bpm(&self, clock_rate: f64) -> f64 {
+        let (_, max_beat_len) = bpm::beat_len_range(&self.timing_points);
+
+        milliseconds_to_bpm(max_beat_len / clock_rate, Some(1))
+    }
+
+    /// The highest beats per minute of the map's timing points after applying
+    /// the clock rate.
+    pub fn max_bpm(&self, clock_rate: f64) -> f64 {
+        let (min_beat_len, _) = bpm::beat_len_range(&self.timing_points);
+
+        milliseconds_to_bpm(min_beat_len / clock_rate, Some(1))
+    }
+
+    /// The ticks, repeats, and tail of the osu!standard slider at the given
+    /// hit object index, sorted by their start time.
+    ///
+    /// Returns `None` if the map is not an osu!standard map or if there is
+    /// no slider at the index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{osu::OsuNestedObjectKind, Beatmap};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    /// let idx = map.hit_objects.iter().position(|h| !h.is_circle()).unwrap();
+    /// let nested = map.osu_slider_nested(idx).unwrap();
+    ///
+    /// assert!(nested.iter().any(|n| n.kind == OsuNestedObjectKind::Tail));
+    /// assert!(map.osu_slider_nested(map.hit_objects.len()).is_none());
+    /// ```
+    pub fn osu_slider_nested(&self, idx: usize) -> Option<Vec<OsuNestedObject>> {
+        if self.mode != GameMode::Osu {
+            return None;
+        }
+
+        Osu::slider_nested_objects(self, idx)
+    }
+
+    /// The start time and x-position of each fruit and droplet of the map
+    /// when played in osu!catch with the given mods, sorted by start time.
+    ///
+    /// These are the objects that the difficulty calculation processes, i.e.
+    /// tiny droplets and bananas are not included. Positions account for the
+    /// offsets of HR as well as mirroring mods. osu!standard maps are
+    /// converted first; maps that cannot be converted return an empty `Vec`.
+    ///
+    /// See [`Difficulty::catch_fruit_positions`] to also account for other
+    /// settings such as slider overrides.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::Beatmap;
+    ///
+    /// let map = Beatmap::from_path("./resources/2118524.osu").unwrap();
+    ///
+    /// let nomod = map.catch_fruit_positions(0);
+    /// let hardrock = map.catch_fruit_positions(16);
+    ///
+    /// assert_eq!(nomod.len(), hardrock.len());
+    /// assert_ne!(nomod, hardrock);
+    /// ```
+    pub fn catch_fruit_positions(&self, mods: impl Into<GameMods>) -> Vec<(f64, f32)> {
+        Difficulty::new()
+            .mods(mods)
+            .catch_fruit_positions(self)
+            .unwrap_or_default()
+    }
+
+    /// The maximum combo of the map when played in the given [`GameMode`]
+    /// with the given mods.
+    ///
+    /// Unlike [`DifficultyAttributes::max_combo`], this only converts the map
+    /// and counts combo-giving objects, e.g. including ticks and repeats of
+    /// osu!standard sliders, without processing any skills.
+    ///
+    /// [`DifficultyAttributes::max_combo`]: crate::any::DifficultyAttributes::max_combo
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{model::mode::GameMode, Beatmap, Difficulty, GameMods};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    /// let mods = GameMods::from(0);
+    ///
+    /// for mode in [GameMode::Osu, GameMode::Taiko, GameMode::Catch, GameMode::Mania] {
+    ///     let converted = map.convert_ref(mode, &mods).unwrap();
+    ///     let attrs = Difficulty::new().calculate(&converted);
+    ///
+    ///     assert_eq!(map.max_combo(mode, &mods).unwrap(), attrs.max_combo());
+    /// }
+    /// ```
+    pub fn max_combo(&self, mode: GameMode, mods: &GameMods) -> Result<u32, ConvertError> {
+        object_combo_values(self, mode, mods.clone()).map(|values| values.into_iter().sum())
+    }
+
+    /// The maximum osu!stable score, i.e. `ScoreV1`, that can be achieved on
+    /// an osu!standard map with the given mods.
+    ///
+    /// This simulates an SS with full combo. Spinners are spun at the
+    /// maximum rate and are assumed to require the minimum amount of
+    /// rotations, i.e. three per second, which maximizes their bonus score.
+    /// Maps of other modes are not supported and return `0`.
+    ///
+    /// See [`Difficulty::max_legacy_score`] for more options.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::Beatmap;
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// // HD
+    /// assert!(map.max_legacy_score(8) > map.max_legacy_score(0));
+    /// ```
+    pub fn max_legacy_score(&self, mods: impl Into<GameMods>) -> u32 {
+        Difficulty::new().mods(mods).max_legacy_score(self)
+    }
+
+    /// Whether the star rating of the map exceeds the given threshold.
+    ///
+    /// The calculation stops as soon as the objects processed so far exceed
+    /// the threshold so maps far above it are faster to check than
+    /// calculating their full difficulty. Maps below the threshold are
+    /// processed entirely.
+    ///
+    /// Stopping early is a heuristic. The star rating of the processed
+    /// objects is usually not above that of the whole map but this is not
+    /// guaranteed, so maps slightly below the threshold may be reported as
+    /// exceeding it.
+    ///
+    /// Returns `None` if the map has no hit objects.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    /// let difficulty = Difficulty::new();
+    ///
+    /// assert_eq!(map.exceeds_stars(&difficulty, 3.0), Some(true));
+    /// assert_eq!(map.exceeds_stars(&difficulty, 10.0), Some(false));
+    /// ```
+    pub fn exceeds_stars(&self, difficulty: &Difficulty, stars: f64) -> Option<bool> {
+        if self.hit_objects.is_empty() {
+            return None;
+        }
+
+        Some(difficulty.exceeds_stars(self, stars))
+    }
+
+    /// Clone the map with the values of its `[Difficulty]` section replaced.
+    ///
+    /// Everything else, including hit objects, is kept as is so there is no
+    /// need to parse the map again. Unlike the custom values of
+    /// [`Difficulty`], the returned map is genuinely modified and all
+    /// calculations on it use the new values.
+    ///
+    /// Values are clamped the same way as when decoding a `.osu` file and
+    /// `NaN` values keep the map's current value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    /// let edited = map.with_difficulty_section(4.0, 10.0, 9.0, 5.0, 1.7, 1.0);
+    ///
+    /// assert_eq!(edited.hit_objects, map.hit_objects);
+    /// assert_eq!(edited.ar, 10.0);
+    ///
+    /// let stars = Difficulty::new().calculate(&map).stars();
+    /// let edited_stars = Difficulty::new().calculate(&edited).stars();
+    /// assert_ne!(stars, edited_stars);
+    ///
+    /// // Out of range and NaN values
+    /// let clamped = map.with_difficulty_section(f32::NAN, 11.0, 9.0, 5.0, 0.0, 10.0);
+    ///
+    /// assert_eq!(clamped.cs, map.cs);
+    /// assert_eq!(clamped.ar, 10.0);
+    /// assert_eq!(clamped.slider_multiplier, 0.4);
+    /// assert_eq!(clamped.slider_tick_rate, 8.0);
+    /// ```
+    #[must_use]
+    pub fn with_difficulty_section(
+        &self,
+        cs: f32,
+        ar: f32,
+        od: f32,
+        hp: f32,
+        slider_multiplier: f64,
+        slider_tick_rate: f64,
+    ) -> Self {
+        let f32_or = |value: f32, current: f32| if value.is_nan() { current } else { value };
+        let f64_or = |value: f64, current: f64| if value.is_nan() { current } else { value };
+
+        // * mania uses "circle size" for key count, thus different allowable range
+        let (min_cs, max_cs) = if self.mode == GameMode::Mania {
+            (1.0, 18.0)
+        } else {
+            (0.0, 10.0)
+        };
+
+        Self {
+            ar: f32_or(ar, self.ar).clamp(0.0, 10.0),
+            cs: f32_or(cs, self.cs).clamp(min_cs, max_cs),
+            hp: f32_or(hp, self.hp).clamp(0.0, 10.0),
+            od: f32_or(od, self.od).clamp(0.0, 10.0),
+            slider_multiplier: f64_or(slider_multiplier, self.slider_multiplier).clamp(0.4, 3.6),
+            slider_tick_rate: f64_or(slider_tick_rate, self.slider_tick_rate).clamp(0.5, 8.0),
+            ..self.clone()
+        }
+    }
+
+    /// The amount of key columns of an osu!mania map.
+    ///
+    /// For osu!standard maps, this is the amount of columns the map would
+    /// have after being converted to osu!mania.
+    ///
+    /// Returns `None` for osu!taiko and osu!catch maps.
+    pub fn mania_key_count(&self) -> Option<u32> {
+        self.mania_key_count_with_mods(GameMods::DEFAULT)
+    }
+
+    /// The amount of key columns of an osu!mania map after applying mods.
+    ///
+    /// Key mods such as `4K` only affect osu!standard maps that are converted
+    /// to osu!mania.
+    ///
+    /// Returns `None` for osu!taiko and osu!catch maps.
+    pub fn mania_key_count_with_mods(&self, mods: impl Into<GameMods>) -> Option<u32> {
+        match self.mode {
+            GameMode::Mania => Some(self.mania_total_columns() as u32),
+            GameMode::Osu if !self.is_convert => {
+                Some(Mania::target_columns(self, &mods.into()) as u32)
+            }
+            GameMode::Osu | GameMode::Taiko | GameMode::Catch => None,
+        }
+    }
+
+    /// The amount of key columns as used in osu!mania difficulty calculation.
+    pub(crate) const fn mania_total_columns(&self) -> f32 {
+        self.cs.round_ties_even().max(1.0)
     }
 
     /// Create a performance calculator for this [`Beatmap`].
@@ -122,6 +550,93 @@ impl Beatmap {
         self.breaks.iter().map(BreakPeriod::duration).sum()
     }
 
+    /// The amount of hit objects per second over time.
+    ///
+    /// For each hit object, the returned pair contains its start time and the
+    /// amount of hit objects per second that started within the preceding
+    /// `window_ms` milliseconds, including itself.
+    ///
+    /// Returns an empty list if `window_ms` is not positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::Beatmap;
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    /// let density = map.density_timeline(1000.0);
+    ///
+    /// assert_eq!(density.len(), map.hit_objects.len());
+    ///
+    /// let peak = density.iter().map(|&(_, nps)| nps).fold(0.0, f64::max);
+    /// println!("Peak NPS: {peak}");
+    /// ```
+    pub fn density_timeline(&self, window_ms: f64) -> Vec<(f64, f64)> {
+        if window_ms.is_nan() || window_ms <= 0.0 {
+            return Vec::new();
+        }
+
+        let per_second = 1000.0 / window_ms;
+        let mut window_start = 0;
+
+        self.hit_objects
+            .iter()
+            .enumerate()
+            .map(|(i, h)| {
+                while h.start_time - self.hit_objects[window_start].start_time >= window_ms {
+                    window_start += 1;
+                }
+
+                let count = i + 1 - window_start;
+
+                (h.start_time, count as f64 * per_second)
+            })
+            .collect()
+    }
+
+    /// Whether the map contains hit objects that are active at the same
+    /// time, e.g. a circle during a slider or two objects with the same
+    /// start time.
+    ///
+    /// Such maps break assumptions of the difficulty calculation so their
+    /// values may be unreliable. See [`Beatmap::concurrent_object_ranges`]
+    /// for details.
+    pub fn has_concurrent_objects(&self) -> bool {
+        !self.concurrent_object_ranges().is_empty()
+    }
+
+    /// The time ranges in which multiple hit objects are active at the same
+    /// time, sorted by start time.
+    ///
+    /// An object is active from its start until its end time, where the
+    /// duration of sliders is based on their osu!standard path. Two objects
+    /// overlap if one starts before the other ends or if both start at the
+    /// same time. For osu!mania, only objects within the same column are
+    /// considered so chords are not reported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{
+    ///     model::{beatmap::BeatmapBuilder, hit_object::Pos},
+    ///     Beatmap,
+    /// };
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    /// assert!(!map.has_concurrent_objects());
+    ///
+    /// let map = BeatmapBuilder::new()
+    ///     .spinner(Pos::new(256.0, 192.0), 1000.0, 2000.0)
+    ///     .circle(Pos::new(100.0, 100.0), 1500.0)
+    ///     .circle(Pos::new(100.0, 100.0), 4000.0)
+    ///     .build();
+    ///
+    /// assert_eq!(map.concurrent_object_ranges(), vec![(1000.0, 3000.0)]);
+    /// ```
+    pub fn concurrent_object_ranges(&self) -> Vec<(f64, f64)> {
+        concurrent::concurrent_object_ranges(self)
+    }
+
     /// Attempt to convert a [`Beatmap`] to the specified mode.
     pub fn convert(mut self, mode: GameMode, mods: &GameMods) -> Result<Self, ConvertError> {
         self.convert_mut(mode, mods)?;
@@ -185,6 +700,33 @@ impl Beatmap {
         Ok(())
     }
 
+    /// Compare this map with another one.
+    ///
+    /// Hit objects are matched by their start time and kind, control points
+    /// by their time. Other fields such as difficulty settings are not
+    /// compared.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::Beatmap;
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    /// assert!(map.diff(&map).is_empty());
+    ///
+    /// let mut edited = map.clone();
+    /// edited.hit_objects[0].pos.x += 10.0;
+    ///
+    /// let diff = map.diff(&edited);
+    /// assert_eq!(diff.hit_objects.changed.len(), 1);
+    /// assert!(diff.hit_objects.added.is_empty());
+    /// assert!(diff.hit_objects.removed.is_empty());
+    /// assert!(diff.timing_points.is_empty());
+    /// ```
+    pub fn diff(&self, other: &Self) -> BeatmapDiff {
+        BeatmapDiff::new(self, other)
+    }
+
     /// Check whether hitobjects appear too suspicious for further calculation.
     ///
     /// Sometimes a [`Beatmap`] isn't created for gameplay but rather to test
@@ -0,0 +1,131 @@
+use rosu_map::{DecodeBeatmap, DecodeState};
+
+use super::{decode::BeatmapState, Beatmap, ParseBeatmapError};
+
+/// A line of a `.osu` file that could not be processed.
+///
+/// Such lines are skipped while parsing so they don't prevent the
+/// [`Beatmap`] from being created but the resulting map might not be
+/// exactly as intended.
+#[derive(Debug)]
+pub struct ParseWarning {
+    /// The content of the line.
+    pub line: String,
+    /// Why the line could not be processed.
+    pub error: ParseBeatmapError,
+}
+
+/// A [`Beatmap`] alongside all [`ParseWarning`]s that occurred while parsing.
+pub(super) struct BeatmapWithWarnings {
+    pub map: Beatmap,
+    pub warnings: Vec<ParseWarning>,
+}
+
+pub(super) struct WarningsState {
+    inner: BeatmapState,
+    warnings: Vec<ParseWarning>,
+}
+
+impl WarningsState {
+    fn process(
+        &mut self,
+        line: &str,
+        f: fn(&mut BeatmapState, &str) -> Result<(), ParseBeatmapError>,
+    ) {
+        if let Err(error) = f(&mut self.inner, line) {
+            self.warnings.push(ParseWarning {
+                line: line.to_owned(),
+                error,
+            });
+        }
+    }
+}
+
+impl DecodeState for WarningsState {
+    fn create(version: i32) -> Self {
+        Self {
+            inner: BeatmapState::create(version),
+            warnings: Vec::new(),
+        }
+    }
+}
+
+impl From<WarningsState> for BeatmapWithWarnings {
+    fn from(state: WarningsState) -> Self {
+        Self {
+            map: state.inner.into(),
+            warnings: state.warnings,
+        }
+    }
+}
+
+impl DecodeBeatmap for BeatmapWithWarnings {
+    type Error = ParseBeatmapError;
+    type State = WarningsState;
+
+    fn parse_general(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.process(line, Beatmap::parse_general);
+
+        Ok(())
+    }
+
+    fn parse_editor(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.process(line, Beatmap::parse_editor);
+
+        Ok(())
+    }
+
+    fn parse_metadata(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.process(line, Beatmap::parse_metadata);
+
+        Ok(())
+    }
+
+    fn parse_difficulty(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.process(line, Beatmap::parse_difficulty);
+
+        Ok(())
+    }
+
+    fn parse_events(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.process(line, Beatmap::parse_events);
+
+        Ok(())
+    }
+
+    fn parse_timing_points(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.process(line, Beatmap::parse_timing_points);
+
+        Ok(())
+    }
+
+    fn parse_colors(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.process(line, Beatmap::parse_colors);
+
+        Ok(())
+    }
+
+    fn parse_hit_objects(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.process(line, Beatmap::parse_hit_objects);
+
+        Ok(())
+    }
+
+    fn parse_variables(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.process(line, Beatmap::parse_variables);
+
+        Ok(())
+    }
+
+    fn parse_catch_the_beat(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.process(line, Beatmap::parse_catch_the_beat);
+
+        Ok(())
+    }
+
+    fn parse_mania(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        state.process(line, Beatmap::parse_mania);
+
+        Ok(())
+    }
+}
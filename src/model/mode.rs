@@ -7,7 +7,7 @@ pub use rosu_map::section::general::GameMode;
 
 use crate::Difficulty;
 
-use super::beatmap::Beatmap;
+use super::{beatmap::Beatmap, mods::ModConflict};
 
 /// A way to specify a gamemode at compile-time.
 ///
@@ -89,3 +89,80 @@ impl Display for ConvertError {
         }
     }
 }
+
+/// Error type when a checked difficulty calculation fails.
+///
+/// Created through [`Difficulty::checked_calculate`].
+///
+/// Both [`ConvertError`] and [`ModConflict`] convert into this type so
+/// existing calls that return either of them can be used with `?` in
+/// functions returning a [`CalculateError`].
+///
+/// # Example
+///
+/// ```
+/// use rosu_pp::{
+///     model::mode::{CalculateError, GameMode},
+///     osu::Osu,
+///     Beatmap, Difficulty, GameMods,
+/// };
+///
+/// fn osu_stars(map: &Beatmap, mods: u32) -> Result<f64, CalculateError> {
+///     GameMods::from(mods).validate_for(GameMode::Osu)?;
+///     let attrs = Difficulty::new().mods(mods).calculate_for_mode::<Osu>(map)?;
+///
+///     Ok(attrs.stars)
+/// }
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+/// assert!(osu_stars(&map, 8).is_ok());
+/// assert!(matches!(osu_stars(&map, 2 + 16), Err(CalculateError::InvalidMods(_))));
+///
+/// let map = Beatmap::from_path("./resources/1028484.osu").unwrap();
+/// assert!(matches!(osu_stars(&map, 0), Err(CalculateError::Convert(_))));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub enum CalculateError {
+    /// The map cannot be converted to the requested mode
+    Convert(ConvertError),
+    /// The map has no hitobjects
+    ///
+    /// Only [`Difficulty::checked_calculate`] reports this. All other
+    /// calculations treat empty maps as valid and canonically return zero
+    /// stars and zero pp.
+    EmptyMap,
+    /// The mods cannot be used for the requested mode
+    InvalidMods(ModConflict),
+}
+
+impl From<ConvertError> for CalculateError {
+    fn from(err: ConvertError) -> Self {
+        Self::Convert(err)
+    }
+}
+
+impl From<ModConflict> for CalculateError {
+    fn from(err: ModConflict) -> Self {
+        Self::InvalidMods(err)
+    }
+}
+
+impl Error for CalculateError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CalculateError::Convert(err) => Some(err),
+            CalculateError::EmptyMap => None,
+            CalculateError::InvalidMods(err) => Some(err),
+        }
+    }
+}
+
+impl Display for CalculateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            CalculateError::Convert(_) => f.write_str("Failed to convert map"),
+            CalculateError::EmptyMap => f.write_str("Map has no hitobjects"),
+            CalculateError::InvalidMods(_) => f.write_str("Invalid mods for the mode"),
+        }
+    }
+}
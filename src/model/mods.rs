@@ -1,10 +1,16 @@
-use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+};
 
+use rosu_map::section::general::GameMode as MapGameMode;
 use rosu_mods::{
     generated_mods::{
         DifficultyAdjustCatch, DifficultyAdjustMania, DifficultyAdjustOsu, DifficultyAdjustTaiko,
     },
-    GameMod, GameModIntermode, GameMods as GameModsLazer, GameModsIntermode, GameModsLegacy,
+    Acronym, GameMod, GameModIntermode, GameMode, GameMods as GameModsLazer, GameModsIntermode,
+    GameModsLegacy,
 };
 
 /// Re-exported [`rosu_mods`].
@@ -34,7 +40,9 @@ pub mod rosu_mods {
 /// let intermode = GameMods::from(GameModsIntermode::new());
 /// ```
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GameMods {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_lazer"))]
     Lazer(GameModsLazer),
     Intermode(GameModsIntermode),
     Legacy(GameModsLegacy),
@@ -53,6 +61,66 @@ impl Debug for GameMods {
 impl GameMods {
     pub(crate) const DEFAULT: Self = Self::Legacy(GameModsLegacy::NoMod);
 
+    /// Check whether the mods can be used together on a map of the given
+    /// mode.
+    ///
+    /// Returns the first found [`ModConflict`], i.e. either a mod that does
+    /// not exist for the mode such as `Relax` in osu!mania or two mods that
+    /// exclude each other such as `HardRock` and `Easy`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{model::mode::GameMode, GameMods};
+    ///
+    /// // HDDT
+    /// assert!(GameMods::from(8 + 64).validate_for(GameMode::Osu).is_ok());
+    ///
+    /// // EZHR
+    /// assert!(GameMods::from(2 + 16).validate_for(GameMode::Osu).is_err());
+    ///
+    /// // RX
+    /// assert!(GameMods::from(128).validate_for(GameMode::Mania).is_err());
+    /// ```
+    pub fn validate_for(&self, mode: MapGameMode) -> Result<(), ModConflict> {
+        let mode = match mode {
+            MapGameMode::Osu => GameMode::Osu,
+            MapGameMode::Taiko => GameMode::Taiko,
+            MapGameMode::Catch => GameMode::Catch,
+            MapGameMode::Mania => GameMode::Mania,
+        };
+
+        let mods = match self {
+            Self::Lazer(ref mods) => Cow::Borrowed(mods),
+            Self::Intermode(ref mods) => Cow::Owned(mods.with_mode(mode)),
+            Self::Legacy(mods) => Cow::Owned(mods.to_intermode().with_mode(mode)),
+        };
+
+        for gamemod in mods.iter() {
+            let unknown = matches!(
+                gamemod,
+                GameMod::UnknownOsu(_)
+                    | GameMod::UnknownTaiko(_)
+                    | GameMod::UnknownCatch(_)
+                    | GameMod::UnknownMania(_)
+            );
+
+            if unknown || gamemod.mode() != mode {
+                return Err(ModConflict::Inapplicable(gamemod.acronym()));
+            }
+        }
+
+        for gamemod in mods.iter() {
+            let incompatible = gamemod.incompatible_mods();
+
+            if let Some(&other) = incompatible.iter().find(|&&m| mods.contains_acronym(m)) {
+                return Err(ModConflict::Incompatible(gamemod.acronym(), other));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns the mods' clock rate.
     ///
     /// In case of variable clock rates like for `WindUp`, this will return
@@ -79,6 +147,78 @@ impl GameMods {
         }
     }
 
+    /// Replace all clock rate adjusting mods with the given one.
+    ///
+    /// Mods other than `DoubleTime`, `Nightcore`, `HalfTime`, and `Daycore`
+    /// are ignored.
+    pub(crate) fn with_rate_mod(self, rate_mod: GameModIntermode) -> Self {
+        const RATE_MODS: [GameModIntermode; 4] = [
+            GameModIntermode::DoubleTime,
+            GameModIntermode::Nightcore,
+            GameModIntermode::HalfTime,
+            GameModIntermode::Daycore,
+        ];
+
+        if !RATE_MODS.contains(&rate_mod) {
+            return self;
+        }
+
+        match self {
+            Self::Lazer(mut mods) => {
+                let mode = mods.iter().next().map_or(GameMode::Osu, GameMod::mode);
+                mods.remove_all_intermode(RATE_MODS);
+                mods.insert(GameMod::new(rate_mod.acronym().as_str(), mode));
+
+                Self::Lazer(mods)
+            }
+            Self::Intermode(mut mods) => {
+                mods.remove_all(RATE_MODS);
+                mods.insert(rate_mod);
+
+                Self::Intermode(mods)
+            }
+            Self::Legacy(mut mods) => {
+                let legacy = match rate_mod {
+                    GameModIntermode::DoubleTime => GameModsLegacy::DoubleTime,
+                    GameModIntermode::Nightcore => GameModsLegacy::Nightcore,
+                    GameModIntermode::HalfTime => GameModsLegacy::HalfTime,
+                    // Daycore has no legacy representation
+                    _ => return Self::Intermode(mods.into()).with_rate_mod(rate_mod),
+                };
+
+                mods.remove(GameModsLegacy::Nightcore | GameModsLegacy::HalfTime);
+                mods.insert(legacy);
+
+                Self::Legacy(mods)
+            }
+        }
+    }
+
+    /// The mods without any of the given mods.
+    pub(crate) fn without(&self, ignored: &Self) -> Self {
+        let ignored: GameModsIntermode = match ignored {
+            Self::Lazer(ref mods) => mods.iter().map(GameMod::intermode).collect(),
+            Self::Intermode(ref mods) => mods.clone(),
+            Self::Legacy(mods) => (*mods).into(),
+        };
+
+        match self {
+            Self::Lazer(ref mods) => {
+                let mut mods = mods.clone();
+                mods.remove_all_intermode(ignored.iter());
+
+                Self::Lazer(mods)
+            }
+            Self::Intermode(ref mods) => {
+                let mut mods = mods.clone();
+                mods.remove_all(ignored.iter());
+
+                Self::Intermode(mods)
+            }
+            Self::Legacy(mods) => Self::Legacy(*mods - ignored.as_legacy()),
+        }
+    }
+
     pub(crate) fn od_ar_hp_multiplier(&self) -> f64 {
         if self.hr() {
             1.4
@@ -154,6 +294,40 @@ impl GameMods {
         }
     }
 
+    /// The mods without those that only affect performance but not
+    /// difficulty attributes, i.e. `NoFail`, `SpunOut`, `SuddenDeath`, and
+    /// `Perfect`.
+    pub(crate) fn without_performance_only(&self) -> Self {
+        const PERFORMANCE_ONLY: [GameModIntermode; 4] = [
+            GameModIntermode::NoFail,
+            GameModIntermode::SpunOut,
+            GameModIntermode::SuddenDeath,
+            GameModIntermode::Perfect,
+        ];
+
+        match self {
+            Self::Lazer(ref mods) => {
+                let mut mods = mods.clone();
+                mods.remove_all_intermode(PERFORMANCE_ONLY);
+
+                Self::Lazer(mods)
+            }
+            Self::Intermode(ref mods) => {
+                let mut mods = mods.clone();
+                mods.remove_all(PERFORMANCE_ONLY);
+
+                Self::Intermode(mods)
+            }
+            Self::Legacy(mods) => Self::Legacy(
+                *mods
+                    - (GameModsLegacy::NoFail
+                        | GameModsLegacy::SpunOut
+                        | GameModsLegacy::SuddenDeath
+                        | GameModsLegacy::Perfect),
+            ),
+        }
+    }
+
     pub(crate) fn mania_keys(&self) -> Option<f32> {
         match self {
             Self::Lazer(ref mods) => {
@@ -328,6 +502,7 @@ macro_rules! impl_has_mod {
 impl_has_mod! {
     nf: + NoFail ["NoFail"],
     ez: + Easy ["Easy"],
+    ht: + HalfTime ["HalfTime"],
     td: + TouchDevice ["TouchDevice"],
     hd: + Hidden ["Hidden"],
     hr: + HardRock ["HardRock"],
@@ -342,6 +517,19 @@ impl_has_mod! {
     tc: - Traceable ["Traceable"],
 }
 
+/// Lazer mods require a mode to be deserialized so it is inferred through
+/// the first mode for which all mods are known.
+#[cfg(feature = "serde")]
+fn deserialize_lazer<'de, D: serde::Deserializer<'de>>(d: D) -> Result<GameModsLazer, D::Error> {
+    use ::rosu_mods::serde::GameModsSeed;
+    use serde::de::DeserializeSeed;
+
+    GameModsSeed::SameModeForEachMod {
+        deny_unknown_fields: false,
+    }
+    .deserialize(d)
+}
+
 impl Default for GameMods {
     fn default() -> Self {
         Self::DEFAULT
@@ -383,6 +571,36 @@ impl From<u32> for GameMods {
     }
 }
 
+/// Error type when [`GameMods`] are not valid for a mode.
+///
+/// Created through [`GameMods::validate_for`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ModConflict {
+    /// The mod does not exist for the mode
+    Inapplicable(Acronym),
+    /// The two mods cannot be used together
+    Incompatible(Acronym, Acronym),
+}
+
+impl Error for ModConflict {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+impl Display for ModConflict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ModConflict::Inapplicable(acronym) => {
+                write!(f, "Mod {acronym} is not available for the mode")
+            }
+            ModConflict::Incompatible(a, b) => {
+                write!(f, "Mods {a} and {b} cannot be used together")
+            }
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub(crate) enum Reflection {
     None,
@@ -390,3 +608,108 @@ pub(crate) enum Reflection {
     Horizontal,
     Both,
 }
+
+#[cfg(test)]
+mod tests {
+    use ::rosu_mods::generated_mods::{DoubleTimeTaiko, HiddenTaiko};
+
+    use super::*;
+
+    #[test]
+    fn validate_for() {
+        let acronym = |s: &str| s.parse::<Acronym>().unwrap();
+
+        assert_eq!(
+            GameMods::from(8 + 64).validate_for(MapGameMode::Osu),
+            Ok(())
+        );
+        assert_eq!(
+            GameMods::from(64 + 512).validate_for(MapGameMode::Osu),
+            Ok(())
+        );
+
+        assert_eq!(
+            GameMods::from(64 + 256).validate_for(MapGameMode::Osu),
+            Err(ModConflict::Incompatible(acronym("HT"), acronym("DT")))
+        );
+
+        assert_eq!(
+            GameMods::from(128).validate_for(MapGameMode::Mania),
+            Err(ModConflict::Inapplicable(acronym("RX")))
+        );
+
+        let lazer: GameModsLazer = [GameMod::HiddenTaiko(HiddenTaiko::default())]
+            .into_iter()
+            .collect();
+
+        assert_eq!(
+            GameMods::from(lazer).validate_for(MapGameMode::Osu),
+            Err(ModConflict::Inapplicable(acronym("HD")))
+        );
+    }
+
+    #[test]
+    fn with_rate_mod() {
+        // Legacy
+        let legacy = GameMods::from(GameModsLegacy::Hidden | GameModsLegacy::Nightcore);
+
+        assert!(matches!(
+            legacy.clone().with_rate_mod(GameModIntermode::HalfTime),
+            GameMods::Legacy(mods) if mods == GameModsLegacy::Hidden | GameModsLegacy::HalfTime
+        ));
+        assert!(matches!(
+            legacy.clone().with_rate_mod(GameModIntermode::DoubleTime),
+            GameMods::Legacy(mods) if mods == GameModsLegacy::Hidden | GameModsLegacy::DoubleTime
+        ));
+        assert!(matches!(
+            legacy.clone().with_rate_mod(GameModIntermode::HardRock),
+            GameMods::Legacy(mods) if mods == GameModsLegacy::Hidden | GameModsLegacy::Nightcore
+        ));
+
+        // Daycore has no legacy representation
+        let GameMods::Intermode(mods) = legacy.with_rate_mod(GameModIntermode::Daycore) else {
+            panic!("expected intermode mods");
+        };
+
+        let expected: GameModsIntermode = [GameModIntermode::Hidden, GameModIntermode::Daycore]
+            .into_iter()
+            .collect();
+        assert_eq!(mods, expected);
+
+        // Intermode
+        let intermode: GameModsIntermode = [GameModIntermode::Hidden, GameModIntermode::DoubleTime]
+            .into_iter()
+            .collect();
+
+        let GameMods::Intermode(mods) =
+            GameMods::from(intermode).with_rate_mod(GameModIntermode::HalfTime)
+        else {
+            panic!("expected intermode mods");
+        };
+
+        let expected: GameModsIntermode = [GameModIntermode::Hidden, GameModIntermode::HalfTime]
+            .into_iter()
+            .collect();
+        assert_eq!(mods, expected);
+
+        // Lazer
+        let lazer: GameModsLazer = [
+            GameMod::HiddenTaiko(HiddenTaiko::default()),
+            GameMod::DoubleTimeTaiko(DoubleTimeTaiko::default()),
+        ]
+        .into_iter()
+        .collect();
+
+        let mods = GameMods::from(lazer).with_rate_mod(GameModIntermode::Nightcore);
+        assert!((mods.clock_rate() - 1.5).abs() < f64::EPSILON);
+
+        let GameMods::Lazer(mods) = mods else {
+            panic!("expected lazer mods");
+        };
+
+        assert_eq!(mods.len(), 2);
+        assert!(mods.contains_intermode(GameModIntermode::Hidden));
+        assert!(mods.contains_intermode(GameModIntermode::Nightcore));
+        assert!(mods.iter().all(|m| m.mode() == GameMode::Taiko));
+    }
+}
@@ -1,7 +1,16 @@
-use crate::{model::beatmap::BeatmapAttributesBuilder, osu::performance::OsuPerformance};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{
+    model::{beatmap::BeatmapAttributesBuilder, mods::GameMods},
+    osu::{
+        difficulty::skills::{flashlight::Flashlight, strain},
+        performance::OsuPerformance,
+    },
+};
 
 /// The result of a difficulty calculation on an osu!standard map.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OsuDifficultyAttributes {
     /// The difficulty of the aim skill.
     pub aim: f64,
@@ -42,12 +51,22 @@ pub struct OsuDifficultyAttributes {
     /// - if set on osu!lazer *without* slider accuracy, this value is the
     ///   amount of hit slider heads, ticks, and repeats
     pub n_large_ticks: u32,
+    /// The amount of slider ends.
+    pub n_slider_ends: u32,
     /// The amount of spinners.
     pub n_spinners: u32,
     /// The final star rating
     pub stars: f64,
     /// The maximum combo.
     pub max_combo: u32,
+    /// The score multiplier that osu!stable derives from the map's
+    /// difficulty settings, object count, and drain time.
+    ///
+    /// Unaffected by mods.
+    pub legacy_score_base_multiplier: f64,
+    /// The combo portion of the maximum osu!stable score, i.e. `ScoreV1`,
+    /// before applying the mod multiplier.
+    pub maximum_legacy_combo_score: f64,
 }
 
 impl OsuDifficultyAttributes {
@@ -61,6 +80,61 @@ impl OsuDifficultyAttributes {
         self.n_circles + self.n_sliders + self.n_spinners
     }
 
+    /// Return how much combo each kind of object contributes to the maximum
+    /// combo.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{osu::Osu, Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    /// let attrs = Difficulty::new().calculate_for_mode::<Osu>(&map).unwrap();
+    ///
+    /// let breakdown = attrs.combo_breakdown();
+    ///
+    /// assert_eq!(breakdown.slider_heads, attrs.n_sliders);
+    /// assert_eq!(breakdown.slider_ends, attrs.n_slider_ends);
+    /// assert_eq!(breakdown.total(), attrs.max_combo);
+    /// ```
+    pub const fn combo_breakdown(&self) -> OsuComboBreakdown {
+        OsuComboBreakdown {
+            circles: self.n_circles,
+            slider_heads: self.n_sliders,
+            slider_ticks: self.n_large_ticks,
+            slider_ends: self.n_slider_ends,
+            spinners: self.n_spinners,
+        }
+    }
+
+    /// The base performance of the aim, speed, and flashlight ratings, in
+    /// that order, before they are combined into the star rating.
+    ///
+    /// The flashlight value only contributes to the star rating if the
+    /// difficulty was calculated with FL.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{osu::Osu, Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    /// let attrs = Difficulty::new().calculate_for_mode::<Osu>(&map).unwrap();
+    ///
+    /// let (aim, speed, flashlight) = attrs.performance_components();
+    ///
+    /// assert!(aim > 0.0);
+    /// assert!(speed > 0.0);
+    /// assert!(flashlight > 0.0);
+    /// ```
+    pub fn performance_components(&self) -> (f64, f64, f64) {
+        (
+            strain::difficulty_to_performance(self.aim),
+            strain::difficulty_to_performance(self.speed),
+            Flashlight::difficulty_to_performance(self.flashlight),
+        )
+    }
+
     /// The overall difficulty
     pub const fn od(&self) -> f64 {
         BeatmapAttributesBuilder::osu_great_hit_window_to_od(self.great_hit_window)
@@ -70,10 +144,45 @@ impl OsuDifficultyAttributes {
     pub fn performance<'a>(self) -> OsuPerformance<'a> {
         self.into()
     }
+
+    /// The performance points of an SS with full combo.
+    ///
+    /// The mods must be the same mods that were used to calculate these
+    /// attributes, otherwise the result will be incorrect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{osu::Osu, Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    /// let attrs = Difficulty::new().mods(8).calculate_for_mode::<Osu>(&map).unwrap();
+    ///
+    /// assert!(attrs.max_pp(8) > attrs.pp_at(99.0, 8));
+    /// ```
+    pub fn max_pp(&self, mods: impl Into<GameMods>) -> f64 {
+        self.pp_at(100.0, mods)
+    }
+
+    /// The performance points of a full combo with the given accuracy
+    /// between `0.0` and `100.0`.
+    ///
+    /// The mods must be the same mods that were used to calculate these
+    /// attributes, otherwise the result will be incorrect.
+    pub fn pp_at(&self, acc: f64, mods: impl Into<GameMods>) -> f64 {
+        self.clone()
+            .performance()
+            .mods(mods)
+            .accuracy(acc)
+            .calculate()
+            // Calculating with attributes cannot fail
+            .map_or(0.0, |attrs| attrs.pp)
+    }
 }
 
 /// The result of a performance calculation on an osu!standard map.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OsuPerformanceAttributes {
     /// The difficulty attributes that were used for the performance calculation
     pub difficulty: OsuDifficultyAttributes,
@@ -91,6 +200,8 @@ pub struct OsuPerformanceAttributes {
     pub effective_miss_count: f64,
     /// Approximated unstable-rate
     pub speed_deviation: Option<f64>,
+    /// The accuracy of the score between `0.0` and `1.0`.
+    pub accuracy: f64,
 }
 
 impl OsuPerformanceAttributes {
@@ -104,6 +215,11 @@ impl OsuPerformanceAttributes {
         self.pp
     }
 
+    /// Return the accuracy of the score between `0.0` and `1.0`.
+    pub const fn accuracy(&self) -> f64 {
+        self.accuracy
+    }
+
     /// Return the maximum combo of the map.
     pub const fn max_combo(&self) -> u32 {
         self.difficulty.max_combo
@@ -113,12 +229,108 @@ impl OsuPerformanceAttributes {
         self.difficulty.n_objects()
     }
 
+    /// Return the pp of each skill component alongside its share of the
+    /// final pp.
+    ///
+    /// Components are combined through a power mean so their raw values do
+    /// not add up to the final pp. The shares account for that combination
+    /// and add up to `1.0`.
+    pub fn component_pp(&self) -> OsuPpComponents {
+        const EXP: f64 = 1.1;
+
+        let aim = self.pp_aim.powf(EXP);
+        let speed = self.pp_speed.powf(EXP);
+        let acc = self.pp_acc.powf(EXP);
+        let flashlight = self.pp_flashlight.powf(EXP);
+        let total = aim + speed + acc + flashlight;
+
+        let share = |value: f64| if total > 0.0 { value / total } else { 0.0 };
+
+        OsuPpComponents {
+            aim: self.pp_aim,
+            speed: self.pp_speed,
+            acc: self.pp_acc,
+            flashlight: self.pp_flashlight,
+            aim_share: share(aim),
+            speed_share: share(speed),
+            acc_share: share(acc),
+            flashlight_share: share(flashlight),
+        }
+    }
+
     /// Returns a builder for performance calculation.
     pub fn performance<'a>(self) -> OsuPerformance<'a> {
         self.difficulty.into()
     }
 }
 
+/// The pp of each osu!standard skill component and its share of the final pp.
+///
+/// See [`OsuPerformanceAttributes::component_pp`].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct OsuPpComponents {
+    /// The aim portion of the final pp.
+    pub aim: f64,
+    /// The speed portion of the final pp.
+    pub speed: f64,
+    /// The accuracy portion of the final pp.
+    pub acc: f64,
+    /// The flashlight portion of the final pp.
+    pub flashlight: f64,
+    /// The aim component's share of the final pp between `0.0` and `1.0`.
+    pub aim_share: f64,
+    /// The speed component's share of the final pp between `0.0` and `1.0`.
+    pub speed_share: f64,
+    /// The accuracy component's share of the final pp between `0.0` and `1.0`.
+    pub acc_share: f64,
+    /// The flashlight component's share of the final pp between `0.0` and
+    /// `1.0`.
+    pub flashlight_share: f64,
+}
+
+/// The combo that each kind of osu!standard object contributes to the
+/// maximum combo.
+///
+/// See [`OsuDifficultyAttributes::combo_breakdown`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct OsuComboBreakdown {
+    /// Combo of circles.
+    pub circles: u32,
+    /// Combo of slider heads.
+    pub slider_heads: u32,
+    /// Combo of slider ticks and repeats.
+    pub slider_ticks: u32,
+    /// Combo of slider ends.
+    pub slider_ends: u32,
+    /// Combo of spinners.
+    pub spinners: u32,
+}
+
+impl OsuComboBreakdown {
+    /// The sum of all combo i.e. the maximum combo.
+    pub const fn total(&self) -> u32 {
+        self.circles + self.slider_heads + self.slider_ticks + self.slider_ends + self.spinners
+    }
+}
+
+impl Display for OsuPerformanceAttributes {
+    /// Format a one-line summary, e.g.
+    /// `287.91pp (aim 113.67, speed 65.73, acc 98.99, fl 0.00) 98.50% 6.25*`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{:.2}pp (aim {:.2}, speed {:.2}, acc {:.2}, fl {:.2}) {:.2}% {:.2}*",
+            self.pp,
+            self.pp_aim,
+            self.pp_speed,
+            self.pp_acc,
+            self.pp_flashlight,
+            self.accuracy * 100.0,
+            self.stars(),
+        )
+    }
+}
+
 impl From<OsuPerformanceAttributes> for OsuDifficultyAttributes {
     fn from(attributes: OsuPerformanceAttributes) -> Self {
         attributes.difficulty
@@ -5,6 +5,7 @@ use crate::model::{beatmap::Beatmap, mods::Reflection};
 use super::{
     attributes::OsuDifficultyAttributes,
     difficulty::scaling_factor::ScalingFactor,
+    legacy_score,
     object::{NestedSliderObjectKind, OsuObject, OsuObjectKind},
 };
 
@@ -30,14 +31,15 @@ pub fn convert_objects(
             }
 
             take -= 1;
-            attrs.max_combo += 1;
+            legacy_score::increment_combo_score(h, attrs);
+            attrs.max_combo += h.combo_value();
 
             match h.kind {
                 OsuObjectKind::Circle => attrs.n_circles += 1,
                 OsuObjectKind::Slider(ref slider) => {
                     attrs.n_sliders += 1;
                     attrs.n_large_ticks += slider.large_tick_count() as u32;
-                    attrs.max_combo += slider.nested_objects.len() as u32;
+                    attrs.n_slider_ends += slider.tail_count() as u32;
                 }
                 OsuObjectKind::Spinner(_) => attrs.n_spinners += 1,
             }
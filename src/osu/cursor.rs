@@ -0,0 +1,170 @@
+use rosu_map::{section::general::GameMode, util::Pos};
+
+use crate::{Beatmap, Difficulty};
+
+use super::{
+    convert::convert_objects,
+    difficulty::OsuDifficultySetup,
+    object::{OsuObject, OsuObjectKind},
+    score_state::OsuScoreState,
+};
+
+/// A cursor position at a point in time, e.g. taken from a replay.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct CursorFrame {
+    /// The time of the frame in milliseconds.
+    pub time: f64,
+    /// The x-coordinate of the cursor in osu!pixels.
+    pub x: f32,
+    /// The y-coordinate of the cursor in osu!pixels.
+    pub y: f32,
+}
+
+/// Estimate the judgements of a play on an osu!standard map based on its
+/// cursor movement.
+///
+/// Each circle and slider head is matched greedily with the frame closest in
+/// time that is within `hit_window` milliseconds of the object and whose
+/// cursor is inside the object's circle. Each frame can only hit a single
+/// object. Key presses are not considered.
+///
+/// The timing offset of a matched frame determines its judgement: a 300 if
+/// it is within a third of `hit_window`, a 100 if it is within two thirds,
+/// and a 50 otherwise. Unmatched objects are misses. Spinners are always
+/// considered as 300s and sliders whose head is hit are considered to hit
+/// all of their ticks and their end.
+///
+/// Object positions take the mods and slider overrides of the given
+/// [`Difficulty`] into account. Frames must be sorted by time. Maps that
+/// cannot be converted to osu!standard return a default [`OsuScoreState`].
+///
+/// # Example
+///
+/// ```
+/// use rosu_pp::{
+///     osu::{estimate_judgements_from_cursor, CursorFrame},
+///     Beatmap, Difficulty,
+/// };
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+///
+/// // The cursor is placed on each object at its start time
+/// let frames: Vec<_> = map
+///     .hit_objects
+///     .iter()
+///     .map(|h| CursorFrame {
+///         time: h.start_time,
+///         x: h.pos.x,
+///         y: h.pos.y,
+///     })
+///     .collect();
+///
+/// let difficulty = Difficulty::new();
+///
+/// let state = estimate_judgements_from_cursor(&difficulty, &map, &frames, 150.0);
+/// assert!(state.n300 > state.misses);
+///
+/// let state = estimate_judgements_from_cursor(&difficulty, &map, &[], 150.0);
+/// assert_eq!(state.n300 as usize, map.hit_objects.iter().filter(|h| h.is_spinner()).count());
+/// ```
+pub fn estimate_judgements_from_cursor(
+    difficulty: &Difficulty,
+    map: &Beatmap,
+    frames: &[CursorFrame],
+    hit_window: f64,
+) -> OsuScoreState {
+    let Ok(map) = map.convert_ref(GameMode::Osu, difficulty.get_mods()) else {
+        return OsuScoreState::default();
+    };
+
+    let map = difficulty.apply_slider_overrides(map);
+
+    let OsuDifficultySetup {
+        scaling_factor,
+        mut attrs,
+        time_preempt,
+        ..
+    } = OsuDifficultySetup::new(difficulty, &map);
+
+    let osu_objects = convert_objects(
+        &map,
+        &scaling_factor,
+        difficulty.get_mods().reflection(),
+        time_preempt,
+        difficulty.get_passed_objects(),
+        &mut attrs,
+    );
+
+    let radius = scaling_factor.radius as f32;
+    let mut used = vec![false; frames.len()];
+    let mut state = OsuScoreState::new();
+    let mut combo = 0;
+
+    for h in osu_objects.iter() {
+        let hit_offset = match h.kind {
+            OsuObjectKind::Spinner(_) => Some(0.0),
+            OsuObjectKind::Circle | OsuObjectKind::Slider(_) => {
+                closest_frame(h, frames, &used, hit_window, radius).map(|idx| {
+                    used[idx] = true;
+
+                    (frames[idx].time - h.start_time).abs()
+                })
+            }
+        };
+
+        let Some(offset) = hit_offset else {
+            state.misses += 1;
+            state.max_combo = state.max_combo.max(combo);
+            combo = 0;
+
+            continue;
+        };
+
+        if offset <= hit_window / 3.0 {
+            state.n300 += 1;
+        } else if offset <= hit_window * 2.0 / 3.0 {
+            state.n100 += 1;
+        } else {
+            state.n50 += 1;
+        }
+
+        if let OsuObjectKind::Slider(ref slider) = h.kind {
+            state.large_tick_hits += slider.large_tick_count() as u32;
+            state.small_tick_hits += 1;
+            state.slider_end_hits += 1;
+        }
+
+        combo += h.combo_value();
+    }
+
+    state.max_combo = state.max_combo.max(combo);
+
+    state
+}
+
+/// The index of the unused frame within the hit window whose cursor is
+/// inside the object and which is closest to the object's start time.
+fn closest_frame(
+    h: &OsuObject,
+    frames: &[CursorFrame],
+    used: &[bool],
+    hit_window: f64,
+    radius: f32,
+) -> Option<usize> {
+    let pos = h.stacked_pos();
+    let start = frames.partition_point(|frame| frame.time < h.start_time - hit_window);
+
+    frames[start..]
+        .iter()
+        .zip(&used[start..])
+        .enumerate()
+        .take_while(|(_, (frame, _))| frame.time <= h.start_time + hit_window)
+        .filter(|(_, (frame, used))| !**used && Pos::new(frame.x, frame.y).distance(pos) <= radius)
+        .min_by(|(_, (a, _)), (_, (b, _))| {
+            let a = (a.time - h.start_time).abs();
+            let b = (b.time - h.start_time).abs();
+
+            a.total_cmp(&b)
+        })
+        .map(|(idx, _)| start + idx)
+}
@@ -0,0 +1,173 @@
+use std::{
+    cmp,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+use rosu_map::section::general::GameMode;
+
+use crate::{
+    model::{beatmap::BeatmapAttributes, mode::ConvertError},
+    osu::convert::convert_objects,
+    Beatmap, Difficulty,
+};
+
+use super::{
+    gradual::{extend_lifetime, osu_objects::OsuObjects},
+    object::OsuDifficultyObject,
+    scaling_factor::ScalingFactor,
+    DifficultyValues, OsuDifficultyAttributes, OsuDifficultySetup,
+};
+
+/// Difficulty objects of an osu!standard map that can be reused across
+/// difficulty calculations.
+///
+/// Creating difficulty objects is the most expensive part of the difficulty
+/// calculation. If the same map is calculated multiple times with settings
+/// that only affect the evaluation of skills, e.g.
+/// [`Difficulty::disable_skill`] or [`Difficulty::performance_base_multiplier`],
+/// the objects only need to be created once.
+///
+/// The [`Difficulty`] passed to [`OsuObjectCache::calculate`] must have the
+/// same mods, clock rate, passed objects, beatmap attribute overrides,
+/// slider overrides, and time range as the one used to create the cache.
+/// Otherwise, a [`CacheMismatchError`] is returned.
+///
+/// # Example
+///
+/// ```
+/// use rosu_pp::{
+///     any::Skill,
+///     osu::{Osu, OsuObjectCache},
+///     Beatmap, Difficulty,
+/// };
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+/// let difficulty = Difficulty::new().mods(8); // HD
+///
+/// let cache = OsuObjectCache::new(&difficulty, &map).unwrap();
+///
+/// let aim_only = difficulty
+///     .clone()
+///     .disable_skill(Skill::Speed)
+///     .disable_skill(Skill::Flashlight);
+/// let boosted = difficulty.clone().performance_base_multiplier(1.5);
+///
+/// let regular_attrs = cache.calculate(&difficulty).unwrap();
+/// let aim_only_attrs = cache.calculate(&aim_only).unwrap();
+/// let boosted_attrs = cache.calculate(&boosted).unwrap();
+///
+/// assert_eq!(
+///     aim_only_attrs,
+///     aim_only.calculate_for_mode::<Osu>(&map).unwrap(),
+/// );
+/// assert_eq!(
+///     boosted_attrs,
+///     boosted.calculate_for_mode::<Osu>(&map).unwrap(),
+/// );
+///
+/// assert!(aim_only_attrs.stars < regular_attrs.stars);
+/// assert!(boosted_attrs.stars > regular_attrs.stars);
+///
+/// // Different mods require different difficulty objects
+/// assert!(cache.calculate(&Difficulty::new().mods(16)).is_err());
+/// ```
+pub struct OsuObjectCache {
+    difficulty: Difficulty,
+    pub(super) scaling_factor: ScalingFactor,
+    pub(super) map_attrs: BeatmapAttributes,
+    pub(super) attrs: OsuDifficultyAttributes,
+    pub(super) time_preempt: f64,
+    pub(super) take_diff_objects: usize,
+    // Lifetimes actually depend on `osu_objects` so this type is
+    // self-referential. This field must be treated with great caution, moving
+    // `osu_objects` will immediately invalidate `diff_objects`.
+    pub(super) diff_objects: Box<[OsuDifficultyObject<'static>]>,
+    _osu_objects: OsuObjects,
+}
+
+impl OsuObjectCache {
+    /// Create the difficulty objects of an osu!standard map.
+    pub fn new(difficulty: &Difficulty, map: &Beatmap) -> Result<Self, ConvertError> {
+        let mods = difficulty.get_mods();
+        let map = map.convert_ref(GameMode::Osu, mods)?;
+        let map = difficulty.apply_slider_overrides(map);
+        let map = difficulty.apply_time_range(map);
+        let take = difficulty.get_passed_objects();
+
+        let OsuDifficultySetup {
+            scaling_factor,
+            map_attrs,
+            mut attrs,
+            time_preempt,
+        } = OsuDifficultySetup::new(difficulty, &map);
+
+        let osu_objects = convert_objects(
+            &map,
+            &scaling_factor,
+            mods.reflection(),
+            time_preempt,
+            take,
+            &mut attrs,
+        );
+
+        let mut osu_objects = OsuObjects::new(osu_objects);
+
+        let diff_objects = DifficultyValues::create_difficulty_objects(
+            difficulty,
+            &scaling_factor,
+            osu_objects.iter_mut(),
+        );
+
+        // The first hit object has no difficulty object
+        let take_diff_objects = cmp::min(map.hit_objects.len(), take).saturating_sub(1);
+
+        Ok(Self {
+            difficulty: difficulty.clone(),
+            scaling_factor,
+            map_attrs,
+            attrs,
+            time_preempt,
+            take_diff_objects,
+            diff_objects: extend_lifetime(diff_objects.into_boxed_slice()),
+            _osu_objects: osu_objects,
+        })
+    }
+
+    /// Perform the difficulty calculation on the cached difficulty objects.
+    ///
+    /// Returns an error if the [`Difficulty`] requires different difficulty
+    /// objects than the ones that were cached.
+    pub fn calculate(
+        &self,
+        difficulty: &Difficulty,
+    ) -> Result<OsuDifficultyAttributes, CacheMismatchError> {
+        if !self.difficulty.same_objects(difficulty) {
+            return Err(CacheMismatchError);
+        }
+
+        let DifficultyValues { skills, mut attrs } =
+            DifficultyValues::from_cached_objects(difficulty, self);
+
+        DifficultyValues::eval(&mut attrs, difficulty, &skills);
+
+        Ok(attrs)
+    }
+}
+
+/// Error type when calculating on an [`OsuObjectCache`] with a [`Difficulty`]
+/// whose settings do not match the ones that were used to create the cache.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CacheMismatchError;
+
+impl Error for CacheMismatchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+impl Display for CacheMismatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("Difficulty settings do not match the cached ones")
+    }
+}
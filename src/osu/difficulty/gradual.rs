@@ -7,8 +7,10 @@ use crate::{
     model::mode::ConvertError,
     osu::{
         convert::convert_objects,
+        legacy_score,
         object::{OsuObject, OsuObjectKind},
     },
+    util::snapshots::{Rewind, Snapshots},
     Beatmap, Difficulty,
 };
 
@@ -62,6 +64,7 @@ pub struct OsuGradualDifficulty {
     // `osu_objects` will immediately invalidate `diff_objects`.
     diff_objects: Box<[OsuDifficultyObject<'static>]>,
     osu_objects: OsuObjects,
+    snapshots: Snapshots<Snapshot>,
     // Additional safety measure that this type can't be cloned as it would
     // invalidate `diff_objects`.
     _not_clonable: NotClonable,
@@ -69,11 +72,18 @@ pub struct OsuGradualDifficulty {
 
 struct NotClonable;
 
+struct Snapshot {
+    attrs: OsuDifficultyAttributes,
+    skills: <OsuSkills as Rewind>::Snapshot,
+}
+
 impl OsuGradualDifficulty {
     /// Create a new difficulty attributes iterator for osu!standard maps.
     pub fn new(difficulty: Difficulty, map: &Beatmap) -> Result<Self, ConvertError> {
         let mods = difficulty.get_mods();
         let map = map.convert_ref(GameMode::Osu, mods)?;
+        let map = difficulty.apply_slider_overrides(map);
+        let map = difficulty.apply_time_range(map);
 
         let OsuDifficultySetup {
             scaling_factor,
@@ -94,8 +104,10 @@ impl OsuGradualDifficulty {
         attrs.n_circles = 0;
         attrs.n_sliders = 0;
         attrs.n_large_ticks = 0;
+        attrs.n_slider_ends = 0;
         attrs.n_spinners = 0;
         attrs.max_combo = 0;
+        attrs.maximum_legacy_combo_score = 0.0;
 
         if let Some(h) = osu_objects.first() {
             Self::increment_combo(h, &mut attrs);
@@ -112,6 +124,11 @@ impl OsuGradualDifficulty {
         let skills = OsuSkills::new(mods, &scaling_factor, &map_attrs, time_preempt);
         let diff_objects = extend_lifetime(diff_objects.into_boxed_slice());
 
+        let snapshots = Snapshots::new(Snapshot {
+            attrs: attrs.clone(),
+            skills: skills.snapshot(),
+        });
+
         Ok(Self {
             idx: 0,
             difficulty,
@@ -119,26 +136,94 @@ impl OsuGradualDifficulty {
             skills,
             diff_objects,
             osu_objects,
+            snapshots,
             _not_clonable: NotClonable,
         })
     }
 
+    /// Restore the latest snapshot for at most `idx` processed objects if
+    /// `idx` objects were already processed.
+    ///
+    /// From then on, snapshots are stored periodically while processing.
+    pub(crate) fn rewind(&mut self, idx: usize) {
+        self.snapshots.enable();
+
+        if idx >= self.idx {
+            return;
+        }
+
+        let Some((snapshot_idx, snapshot)) = self.snapshots.get(idx) else {
+            return;
+        };
+
+        self.idx = snapshot_idx;
+        self.attrs = snapshot.attrs.clone();
+        self.skills.rewind(&snapshot.skills);
+        self.snapshots.truncate(snapshot_idx);
+    }
+
+    /// Process the next hit object and return the sum of its aim and speed
+    /// strain without evaluating the difficulty attributes.
+    pub(crate) fn next_strain(&mut self) -> Option<f64> {
+        self.process_next()?;
+
+        if self.idx < 2 {
+            return Some(0.0);
+        }
+
+        let last = |strains: &[f64]| strains.last().copied().unwrap_or(0.0);
+
+        Some(last(self.skills.aim.object_strains()) + last(self.skills.speed.object_strains()))
+    }
+
+    fn process_next(&mut self) -> Option<()> {
+        // The first difficulty object belongs to the second note since each
+        // difficulty object requires the current and the last note. Hence, if
+        // we're still on the first object, we don't have a difficulty object
+        // yet and just skip processing.
+        if self.idx > 0 {
+            let curr = self.diff_objects.get(self.idx - 1)?;
+
+            self.skills.aim.process(curr, &self.diff_objects);
+            self.skills.aim_no_sliders.process(curr, &self.diff_objects);
+            self.skills.speed.process(curr, &self.diff_objects);
+            self.skills.flashlight.process(curr, &self.diff_objects);
+
+            Self::increment_combo(curr.base, &mut self.attrs);
+        } else if self.osu_objects.is_empty() {
+            return None;
+        }
+
+        self.idx += 1;
+        self.store_snapshot();
+
+        Some(())
+    }
+
+    fn store_snapshot(&mut self) {
+        self.snapshots.store(self.idx, || Snapshot {
+            attrs: self.attrs.clone(),
+            skills: self.skills.snapshot(),
+        });
+    }
+
     fn increment_combo(h: &OsuObject, attrs: &mut OsuDifficultyAttributes) {
-        attrs.max_combo += 1;
+        legacy_score::increment_combo_score(h, attrs);
+        attrs.max_combo += h.combo_value();
 
         match &h.kind {
             OsuObjectKind::Circle => attrs.n_circles += 1,
             OsuObjectKind::Slider(slider) => {
                 attrs.n_sliders += 1;
                 attrs.n_large_ticks += slider.large_tick_count() as u32;
-                attrs.max_combo += slider.nested_objects.len() as u32;
+                attrs.n_slider_ends += slider.tail_count() as u32;
             }
             OsuObjectKind::Spinner { .. } => attrs.n_spinners += 1,
         }
     }
 }
 
-fn extend_lifetime(
+pub(super) fn extend_lifetime(
     diff_objects: Box<[OsuDifficultyObject<'_>]>,
 ) -> Box<[OsuDifficultyObject<'static>]> {
     // SAFETY: Owned values of the references will be contained in the same
@@ -151,28 +236,11 @@ impl Iterator for OsuGradualDifficulty {
     type Item = OsuDifficultyAttributes;
 
     fn next(&mut self) -> Option<Self::Item> {
-        // The first difficulty object belongs to the second note since each
-        // difficulty object requires the current and the last note. Hence, if
-        // we're still on the first object, we don't have a difficulty object
-        // yet and just skip processing.
-        if self.idx > 0 {
-            let curr = self.diff_objects.get(self.idx - 1)?;
-
-            self.skills.aim.process(curr, &self.diff_objects);
-            self.skills.aim_no_sliders.process(curr, &self.diff_objects);
-            self.skills.speed.process(curr, &self.diff_objects);
-            self.skills.flashlight.process(curr, &self.diff_objects);
-
-            Self::increment_combo(curr.base, &mut self.attrs);
-        } else if self.osu_objects.is_empty() {
-            return None;
-        }
-
-        self.idx += 1;
+        self.process_next()?;
 
         let mut attrs = self.attrs.clone();
 
-        DifficultyValues::eval(&mut attrs, self.difficulty.get_mods(), &self.skills);
+        DifficultyValues::eval(&mut attrs, &self.difficulty, &self.skills);
 
         Some(attrs)
     }
@@ -198,6 +266,11 @@ impl Iterator for OsuGradualDifficulty {
             self.skills.process(curr, &self.diff_objects);
             Self::increment_combo(curr.base, &mut self.attrs);
             self.idx += 1;
+
+            self.snapshots.store(self.idx, || Snapshot {
+                attrs: self.attrs.clone(),
+                skills: self.skills.snapshot(),
+            });
         }
 
         self.next()
@@ -210,26 +283,28 @@ impl ExactSizeIterator for OsuGradualDifficulty {
     }
 }
 
-mod osu_objects {
+pub(super) mod osu_objects {
     use std::pin::Pin;
 
     use crate::osu::object::OsuObject;
 
     /// Wrapper to ensure that the data will not be moved
-    pub(super) struct OsuObjects {
+    pub(in crate::osu::difficulty) struct OsuObjects {
         objects: Box<[OsuObject]>,
     }
 
     impl OsuObjects {
-        pub(super) const fn new(objects: Box<[OsuObject]>) -> Self {
+        pub(in crate::osu::difficulty) const fn new(objects: Box<[OsuObject]>) -> Self {
             Self { objects }
         }
 
-        pub(super) const fn is_empty(&self) -> bool {
+        pub(in crate::osu::difficulty) const fn is_empty(&self) -> bool {
             self.objects.is_empty()
         }
 
-        pub(super) fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = Pin<&mut OsuObject>> {
+        pub(in crate::osu::difficulty) fn iter_mut(
+            &mut self,
+        ) -> impl ExactSizeIterator<Item = Pin<&mut OsuObject>> {
             self.objects.iter_mut().map(Pin::new)
         }
     }
@@ -287,4 +362,28 @@ mod tests {
             assert_eq!(next_gradual, expected);
         }
     }
+
+    #[test]
+    fn bounded_snapshots() {
+        // Snapshots only store the length of the growing strain buffers so
+        // their size doesn't depend on the amount of processed objects
+        fn assert_copy<T: Copy>() {}
+        assert_copy::<<OsuSkills as Rewind>::Snapshot>();
+
+        let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+
+        let mut gradual = OsuGradualDifficulty::new(Difficulty::new(), &map).unwrap();
+        let mut fresh = OsuGradualDifficulty::new(Difficulty::new(), &map).unwrap();
+
+        gradual.rewind(0);
+        let last = gradual.by_ref().last();
+
+        gradual.rewind(300);
+        let idx = gradual.idx;
+
+        assert!(idx <= 300);
+        assert_eq!(gradual.skills.aim.object_strains().len(), idx - 1);
+        assert_eq!(gradual.next(), fresh.nth(idx));
+        assert_eq!(gradual.last(), last);
+    }
 }
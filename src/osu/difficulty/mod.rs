@@ -4,23 +4,24 @@ use rosu_map::section::general::GameMode;
 use skills::{aim::Aim, flashlight::Flashlight, speed::Speed, strain::OsuStrainSkill};
 
 use crate::{
-    any::difficulty::{skills::StrainSkill, Difficulty},
-    model::{beatmap::BeatmapAttributes, mode::ConvertError, mods::GameMods},
+    any::difficulty::{skills::StrainSkill, Difficulty, Skill},
+    model::{beatmap::BeatmapAttributes, mode::ConvertError},
     osu::{
         convert::convert_objects,
         difficulty::{object::OsuDifficultyObject, scaling_factor::ScalingFactor},
+        legacy_score,
         object::OsuObject,
-        performance::PERFORMANCE_BASE_MULTIPLIER,
     },
     Beatmap,
 };
 
-use self::skills::OsuSkills;
+use self::{cache::OsuObjectCache, skills::OsuSkills};
 
 use super::attributes::OsuDifficultyAttributes;
 
+pub mod cache;
 pub mod gradual;
-mod object;
+pub mod object;
 pub mod scaling_factor;
 pub mod skills;
 
@@ -34,21 +35,21 @@ pub fn difficulty(
     map: &Beatmap,
 ) -> Result<OsuDifficultyAttributes, ConvertError> {
     let map = map.convert_ref(GameMode::Osu, difficulty.get_mods())?;
+    let map = difficulty.apply_slider_overrides(map);
+    let map = difficulty.apply_time_range(map);
 
     let DifficultyValues { skills, mut attrs } = DifficultyValues::calculate(difficulty, &map);
 
-    let mods = difficulty.get_mods();
-
-    DifficultyValues::eval(&mut attrs, mods, &skills);
+    DifficultyValues::eval(&mut attrs, difficulty, &skills);
 
     Ok(attrs)
 }
 
 pub struct OsuDifficultySetup {
-    scaling_factor: ScalingFactor,
-    map_attrs: BeatmapAttributes,
-    attrs: OsuDifficultyAttributes,
-    time_preempt: f64,
+    pub scaling_factor: ScalingFactor,
+    pub map_attrs: BeatmapAttributes,
+    pub attrs: OsuDifficultyAttributes,
+    pub time_preempt: f64,
 }
 
 impl OsuDifficultySetup {
@@ -63,6 +64,7 @@ impl OsuDifficultySetup {
             great_hit_window: map_attrs.hit_windows.od_great,
             ok_hit_window: map_attrs.hit_windows.od_ok.unwrap_or(0.0),
             meh_hit_window: map_attrs.hit_windows.od_meh.unwrap_or(0.0),
+            legacy_score_base_multiplier: legacy_score::score_base_multiplier(map),
             ..Default::default()
         };
 
@@ -120,8 +122,35 @@ impl DifficultyValues {
         Self { skills, attrs }
     }
 
+    /// Process the skills on previously created difficulty objects.
+    pub fn from_cached_objects(difficulty: &Difficulty, cache: &OsuObjectCache) -> Self {
+        let mut skills = OsuSkills::new(
+            difficulty.get_mods(),
+            &cache.scaling_factor,
+            &cache.map_attrs,
+            cache.time_preempt,
+        );
+
+        for hit_object in cache.diff_objects.iter().take(cache.take_diff_objects) {
+            skills.process(hit_object, &cache.diff_objects);
+        }
+
+        Self {
+            skills,
+            attrs: cache.attrs.clone(),
+        }
+    }
+
     /// Process the difficulty values and store the results in `attrs`.
-    pub fn eval(attrs: &mut OsuDifficultyAttributes, mods: &GameMods, skills: &OsuSkills) {
+    pub fn eval(attrs: &mut OsuDifficultyAttributes, difficulty: &Difficulty, skills: &OsuSkills) {
+        // Without any objects, the skills would still produce a non-zero
+        // star rating because performance values have a lower bound
+        if attrs.n_objects() == 0 {
+            return;
+        }
+
+        let mods = difficulty.get_mods();
+
         let OsuSkills {
             aim,
             aim_no_sliders,
@@ -152,6 +181,18 @@ impl DifficultyValues {
         let mut flashlight_rating =
             f64::sqrt(flashlight.cloned_difficulty_value()) * DIFFICULTY_MULTIPLIER;
 
+        if difficulty.is_skill_disabled(Skill::Aim) {
+            aim_rating = 0.0;
+        }
+
+        if difficulty.is_skill_disabled(Skill::Speed) {
+            speed_rating = 0.0;
+        }
+
+        if difficulty.is_skill_disabled(Skill::Flashlight) {
+            flashlight_rating = 0.0;
+        }
+
         if mods.td() {
             aim_rating = aim_rating.powf(0.8);
             flashlight_rating = flashlight_rating.powf(0.8);
@@ -176,18 +217,12 @@ impl DifficultyValues {
             0.0
         };
 
-        let base_performance = ((base_aim_performance).powf(1.1)
-            + (base_speed_performance).powf(1.1)
-            + (base_flashlight_performance).powf(1.1))
-        .powf(1.0 / 1.1);
-
-        let star_rating = if base_performance > 0.00001 {
-            PERFORMANCE_BASE_MULTIPLIER.cbrt()
-                * 0.027
-                * ((100_000.0 / 2.0_f64.powf(1.0 / 1.1) * base_performance).cbrt() + 4.0)
-        } else {
-            0.0
-        };
+        let star_rating = performance_to_stars(
+            base_aim_performance,
+            base_speed_performance,
+            base_flashlight_performance,
+            difficulty.get_performance_base_multiplier(),
+        );
 
         attrs.aim = aim_rating;
         attrs.aim_difficult_slider_count = difficult_sliders;
@@ -238,3 +273,42 @@ impl DifficultyValues {
             .collect()
     }
 }
+
+/// Combine the base performance of each skill into a star rating.
+fn performance_to_stars(aim: f64, speed: f64, flashlight: f64, base_multiplier: f64) -> f64 {
+    let base_performance = (aim.powf(1.1) + speed.powf(1.1) + flashlight.powf(1.1)).powf(1.0 / 1.1);
+
+    if base_performance > 0.00001 {
+        base_multiplier.cbrt()
+            * 0.027
+            * ((100_000.0 / 2.0_f64.powf(1.0 / 1.1) * base_performance).cbrt() + 4.0)
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::osu::{Osu, PERFORMANCE_BASE_MULTIPLIER};
+
+    use super::*;
+
+    #[test]
+    fn performance_components_combine_to_stars() {
+        let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+
+        for mods in [0, 1024] {
+            let attrs = Difficulty::new()
+                .mods(mods)
+                .calculate_for_mode::<Osu>(&map)
+                .unwrap();
+
+            let (aim, speed, flashlight) = attrs.performance_components();
+            let flashlight = if mods == 0 { 0.0 } else { flashlight };
+
+            let stars = performance_to_stars(aim, speed, flashlight, PERFORMANCE_BASE_MULTIPLIER);
+
+            assert!((stars - attrs.stars).abs() < 1e-9, "{mods}");
+        }
+    }
+}
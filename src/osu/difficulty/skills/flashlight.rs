@@ -6,11 +6,12 @@ use crate::{
         skills::strain_decay,
     },
     osu::{difficulty::object::OsuDifficultyObject, object::OsuObjectKind},
-    util::strains_vec::StrainsVec,
+    util::{snapshots::Rewind, strains_vec::StrainsVec},
     GameMods,
 };
 
 define_skill! {
+    #[derive(Clone)]
     pub struct Flashlight: StrainSkill => [OsuDifficultyObject<'a>][OsuDifficultyObject<'a>] {
         current_strain: f64,
         has_hidden_mod: bool,
@@ -74,12 +75,22 @@ impl Flashlight {
     }
 }
 
-struct FlashlightEvaluator {
+#[derive(Clone)]
+pub struct FlashlightEvaluator {
     scaling_factor: f64,
     time_preempt: f64,
     time_fade_in: f64,
 }
 
+// The evaluator doesn't change while processing
+impl Rewind for FlashlightEvaluator {
+    type Snapshot = ();
+
+    fn snapshot(&self) -> Self::Snapshot {}
+
+    fn rewind(&mut self, (): &Self::Snapshot) {}
+}
+
 impl FlashlightEvaluator {
     const MAX_OPACITY_BONUS: f64 = 0.4;
     const HIDDEN_BONUS: f64 = 0.2;
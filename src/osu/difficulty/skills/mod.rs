@@ -2,6 +2,7 @@ use crate::{
     any::difficulty::skills::StrainSkill,
     model::{beatmap::BeatmapAttributes, mods::GameMods},
     osu::object::OsuObject,
+    util::snapshots::Rewind,
 };
 
 use self::{aim::Aim, flashlight::Flashlight, speed::Speed};
@@ -15,6 +16,7 @@ pub mod flashlight;
 pub mod speed;
 pub mod strain;
 
+#[derive(Clone)]
 pub struct OsuSkills {
     pub aim: Aim,
     pub aim_no_sliders: Aim,
@@ -65,3 +67,28 @@ impl OsuSkills {
         self.flashlight.process(curr, objects);
     }
 }
+
+impl Rewind for OsuSkills {
+    type Snapshot = (
+        <Aim as Rewind>::Snapshot,
+        <Aim as Rewind>::Snapshot,
+        <Speed as Rewind>::Snapshot,
+        <Flashlight as Rewind>::Snapshot,
+    );
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (
+            self.aim.snapshot(),
+            self.aim_no_sliders.snapshot(),
+            self.speed.snapshot(),
+            self.flashlight.snapshot(),
+        )
+    }
+
+    fn rewind(&mut self, (aim, aim_no_sliders, speed, flashlight): &Self::Snapshot) {
+        self.aim.rewind(aim);
+        self.aim_no_sliders.rewind(aim_no_sliders);
+        self.speed.rewind(speed);
+        self.flashlight.rewind(flashlight);
+    }
+}
@@ -0,0 +1,77 @@
+use std::pin::Pin;
+
+use rosu_map::section::general::GameMode;
+
+use crate::{
+    any::difficulty::object::IDifficultyObject, model::mode::ConvertError, Beatmap, Difficulty,
+};
+
+use super::{
+    convert::convert_objects,
+    difficulty::{DifficultyValues, OsuDifficultySetup},
+};
+
+/// Objects that the speed and rhythm evaluation consider doubletappable.
+///
+/// Returns the start time of each object in milliseconds alongside its
+/// doubletapness, a value between `0.0` and `1.0` where higher values mean
+/// the object is easier to doubletap. Objects with a doubletapness of `0.0`
+/// are omitted.
+///
+/// # Example
+///
+/// ```
+/// use rosu_pp::{osu::doubletappable_objects, Beatmap, Difficulty};
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+/// let objects = doubletappable_objects(&Difficulty::new(), &map).unwrap();
+///
+/// assert!(objects
+///     .iter()
+///     .all(|&(_, doubletapness)| doubletapness > 0.0 && doubletapness <= 1.0));
+/// ```
+pub fn doubletappable_objects(
+    difficulty: &Difficulty,
+    map: &Beatmap,
+) -> Result<Vec<(f64, f64)>, ConvertError> {
+    let map = map.convert_ref(GameMode::Osu, difficulty.get_mods())?;
+    let map = difficulty.apply_slider_overrides(map);
+    let map = difficulty.apply_time_range(map);
+
+    let OsuDifficultySetup {
+        scaling_factor,
+        map_attrs,
+        mut attrs,
+        time_preempt,
+    } = OsuDifficultySetup::new(difficulty, &map);
+
+    let take = difficulty.get_passed_objects();
+
+    let mut osu_objects = convert_objects(
+        &map,
+        &scaling_factor,
+        difficulty.get_mods().reflection(),
+        time_preempt,
+        take,
+        &mut attrs,
+    );
+
+    let diff_objects = DifficultyValues::create_difficulty_objects(
+        difficulty,
+        &scaling_factor,
+        osu_objects.iter_mut().map(Pin::new),
+    );
+
+    let hit_window = 2.0 * map_attrs.hit_windows.od_great;
+
+    let objects = diff_objects
+        .iter()
+        .filter_map(|curr| {
+            let doubletapness = curr.get_doubletapness(curr.next(0, &diff_objects), hit_window);
+
+            (doubletapness > 0.0).then_some((curr.base.start_time, doubletapness))
+        })
+        .collect();
+
+    Ok(objects)
+}
@@ -0,0 +1,129 @@
+use std::{cmp, f64::consts::PI, pin::Pin};
+
+use rosu_map::section::general::GameMode;
+
+use crate::{model::mode::ConvertError, Beatmap, Difficulty};
+
+use super::{
+    convert::convert_objects,
+    difficulty::{object::OsuDifficultyObject, DifficultyValues, OsuDifficultySetup},
+};
+
+/// Distribution of angles and jump distances across an osu!standard map.
+///
+/// The bins are fixed so histograms of different maps can be compared
+/// directly, e.g. through [`OsuPatternHistogram::cosine_similarity`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OsuPatternHistogram {
+    /// Amount of objects per angle bin.
+    ///
+    /// Bin `i` covers angles in `[i * w, (i + 1) * w)` where `w` is
+    /// [`OsuPatternHistogram::ANGLE_BIN_WIDTH`] in radians.
+    pub angles: [u32; Self::ANGLE_BINS],
+    /// Amount of objects per jump distance bin.
+    ///
+    /// Bin `i` covers distances in `[i * w, (i + 1) * w)` where `w` is
+    /// [`OsuPatternHistogram::DISTANCE_BIN_WIDTH`]. The last bin also
+    /// includes all larger distances.
+    pub distances: [u32; Self::DISTANCE_BINS],
+}
+
+impl OsuPatternHistogram {
+    /// Amount of angle bins.
+    pub const ANGLE_BINS: usize = 18;
+    /// Width of each angle bin in radians.
+    pub const ANGLE_BIN_WIDTH: f64 = PI / Self::ANGLE_BINS as f64;
+    /// Amount of jump distance bins.
+    pub const DISTANCE_BINS: usize = 24;
+    /// Width of each jump distance bin in normalized osu!pixels, i.e. the
+    /// distance as if the circle radius was 50.
+    pub const DISTANCE_BIN_WIDTH: f64 = 20.0;
+
+    /// Cosine similarity between two histograms.
+    ///
+    /// Angles and distances are treated as a single vector. The result is
+    /// between `0.0` (no similarity) and `1.0` (same distribution).
+    pub fn cosine_similarity(&self, other: &Self) -> f64 {
+        let this = self.angles.iter().chain(self.distances.iter());
+        let other = other.angles.iter().chain(other.distances.iter());
+
+        let (dot, norm_a, norm_b) =
+            this.zip(other)
+                .fold((0.0, 0.0, 0.0), |(dot, norm_a, norm_b), (&a, &b)| {
+                    let (a, b) = (f64::from(a), f64::from(b));
+
+                    (dot + a * b, norm_a + a * a, norm_b + b * b)
+                });
+
+        if norm_a == 0.0 || norm_b == 0.0 {
+            return 0.0;
+        }
+
+        dot / (norm_a.sqrt() * norm_b.sqrt())
+    }
+
+    fn add(&mut self, diff_object: &OsuDifficultyObject<'_>) {
+        if let Some(angle) = diff_object.angle {
+            let idx = (angle / Self::ANGLE_BIN_WIDTH) as usize;
+            self.angles[cmp::min(idx, Self::ANGLE_BINS - 1)] += 1;
+        }
+
+        if !diff_object.base.is_spinner() {
+            let idx = (diff_object.lazy_jump_dist / Self::DISTANCE_BIN_WIDTH) as usize;
+            self.distances[cmp::min(idx, Self::DISTANCE_BINS - 1)] += 1;
+        }
+    }
+}
+
+/// Bucket the angles and jump distances of all difficulty objects.
+///
+/// # Example
+///
+/// ```
+/// use rosu_pp::{osu::aim_pattern_histogram, Beatmap, Difficulty};
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+/// let histogram = aim_pattern_histogram(&Difficulty::new(), &map).unwrap();
+///
+/// assert!((histogram.cosine_similarity(&histogram) - 1.0).abs() < 1e-9);
+/// ```
+pub fn aim_pattern_histogram(
+    difficulty: &Difficulty,
+    map: &Beatmap,
+) -> Result<OsuPatternHistogram, ConvertError> {
+    let map = map.convert_ref(GameMode::Osu, difficulty.get_mods())?;
+    let map = difficulty.apply_slider_overrides(map);
+    let map = difficulty.apply_time_range(map);
+
+    let OsuDifficultySetup {
+        scaling_factor,
+        mut attrs,
+        time_preempt,
+        ..
+    } = OsuDifficultySetup::new(difficulty, &map);
+
+    let take = difficulty.get_passed_objects();
+
+    let mut osu_objects = convert_objects(
+        &map,
+        &scaling_factor,
+        difficulty.get_mods().reflection(),
+        time_preempt,
+        take,
+        &mut attrs,
+    );
+
+    let diff_objects = DifficultyValues::create_difficulty_objects(
+        difficulty,
+        &scaling_factor,
+        osu_objects.iter_mut().map(Pin::new),
+    );
+
+    let mut histogram = OsuPatternHistogram::default();
+
+    for diff_object in diff_objects.iter() {
+        histogram.add(diff_object);
+    }
+
+    Ok(histogram)
+}
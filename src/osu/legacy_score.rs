@@ -0,0 +1,226 @@
+use std::borrow::Cow;
+
+use rosu_map::section::hit_objects::CurveBuffers;
+
+use crate::{model::mods::GameMods, Beatmap, Difficulty};
+
+use super::{
+    attributes::OsuDifficultyAttributes,
+    object::{NestedSliderObjectKind, OsuObject, OsuObjectKind},
+};
+
+/// The score multiplier that osu!stable derives from a map's difficulty
+/// settings, object count, and drain time.
+///
+/// Only the map's own settings are considered, i.e. mods have no effect.
+pub fn score_base_multiplier(map: &Beatmap) -> f64 {
+    let drain_len = match (map.hit_objects.first(), map.hit_objects.last()) {
+        (Some(first), Some(last)) => {
+            let break_len: i32 = map
+                .breaks
+                .iter()
+                .map(|b| {
+                    b.end_time.round_ties_even() as i32 - b.start_time.round_ties_even() as i32
+                })
+                .sum();
+
+            (last.start_time.round_ties_even() as i32
+                - first.start_time.round_ties_even() as i32
+                - break_len)
+                / 1000
+        }
+        _ => 0,
+    };
+
+    let object_count = map.hit_objects.len() as f64;
+
+    let object_to_drain_ratio = if drain_len == 0 {
+        16.0
+    } else {
+        (object_count / f64::from(drain_len) * 8.0)
+            .round_ties_even()
+            .clamp(0.0, 16.0)
+    };
+
+    // * The settings are not rounded individually, only the final result is
+    let sum = f64::from(map.hp) + f64::from(map.od) + f64::from(map.cs) + object_to_drain_ratio;
+
+    (sum / 38.0 * 5.0).round_ties_even()
+}
+
+/// Add the combo portion of the hit object's osu!stable score to
+/// [`OsuDifficultyAttributes::maximum_legacy_combo_score`].
+///
+/// Must be called *before* the object's combo is added to
+/// [`OsuDifficultyAttributes::max_combo`].
+pub fn increment_combo_score(h: &OsuObject, attrs: &mut OsuDifficultyAttributes) {
+    // Sliders are scored after all their nested objects
+    let combo = match h.kind {
+        OsuObjectKind::Circle | OsuObjectKind::Spinner(_) => attrs.max_combo,
+        OsuObjectKind::Slider(_) => attrs.max_combo + h.combo_value(),
+    };
+
+    attrs.maximum_legacy_combo_score += combo_score(combo, attrs.legacy_score_base_multiplier);
+}
+
+/// Simulate the maximum osu!stable score on an osu!standard map.
+///
+/// Spinners are spun at the maximum rate and require rotations at the
+/// difficulty's assumed spin rate.
+pub fn max_legacy_score(difficulty: &Difficulty, map: &Beatmap) -> u32 {
+    let map = difficulty.apply_slider_overrides(Cow::Borrowed(map));
+    let map = difficulty.apply_time_range(map);
+
+    let mut curve_bufs = CurveBuffers::default();
+    let mut ticks_buf = Vec::new();
+
+    let min_spin_rate = difficulty.get_assumed_spin_rate();
+
+    let score_multiplier = score_base_multiplier(&map);
+
+    let mut combo = 0;
+    let mut accuracy_score = 0;
+    let mut combo_score = 0.0;
+    let mut bonus_score = 0;
+
+    let take = difficulty.get_passed_objects();
+
+    for h in map.hit_objects.iter().take(take) {
+        let h = OsuObject::new(h, &map, &mut curve_bufs, &mut ticks_buf);
+
+        match h.kind {
+            OsuObjectKind::Circle => {
+                combo_score += self::combo_score(combo, score_multiplier);
+            }
+            OsuObjectKind::Slider(ref slider) => {
+                // Head
+                accuracy_score += 30;
+
+                for nested in slider.nested_objects.iter() {
+                    accuracy_score += match nested.kind {
+                        NestedSliderObjectKind::Repeat | NestedSliderObjectKind::Tail => 30,
+                        NestedSliderObjectKind::Tick => 10,
+                    };
+                }
+
+                combo_score += self::combo_score(combo + h.combo_value(), score_multiplier);
+            }
+            OsuObjectKind::Spinner(ref spinner) => {
+                bonus_score += spinner_bonus_score(spinner.duration, min_spin_rate);
+                combo_score += self::combo_score(combo, score_multiplier);
+            }
+        }
+
+        accuracy_score += 300;
+        combo += h.combo_value();
+    }
+
+    let total = f64::from(accuracy_score + bonus_score)
+        + (combo_score * legacy_mod_multiplier(difficulty.get_score_mods())).round_ties_even();
+
+    total.min(f64::from(u32::MAX)) as u32
+}
+
+fn combo_score(combo: u32, score_multiplier: f64) -> f64 {
+    // Integer division of the 300 base score, just like osu!stable
+    const SCORE_INCREASE: u32 = 300 / 25;
+
+    (f64::from(combo.saturating_sub(1) * SCORE_INCREASE) * score_multiplier).trunc()
+}
+
+fn spinner_bonus_score(duration: f64, min_rotations_per_second: f64) -> u32 {
+    const MAXIMUM_ROTATIONS_PER_SECOND: f64 = 477.0 / 60.0;
+
+    let seconds_duration = duration / 1000.0;
+
+    let total_half_spins_possible = (seconds_duration * MAXIMUM_ROTATIONS_PER_SECOND * 2.0) as i32;
+    let half_spins_required_for_completion = (seconds_duration * min_rotations_per_second) as i32;
+    let half_spins_required_before_bonus = half_spins_required_for_completion + 3;
+
+    let mut bonus_score = 0;
+
+    for i in 0..=total_half_spins_possible {
+        if i > half_spins_required_before_bonus && (i - half_spins_required_before_bonus) % 2 == 0 {
+            bonus_score += 1100;
+        } else if i > 1 && i % 2 == 0 {
+            bonus_score += 100;
+        }
+    }
+
+    bonus_score
+}
+
+fn legacy_mod_multiplier(mods: &GameMods) -> f64 {
+    let clock_rate = mods.clock_rate();
+
+    let mut multiplier = 1.0;
+
+    if mods.nf() {
+        multiplier *= 0.5;
+    }
+
+    if mods.ez() {
+        multiplier *= 0.5;
+    }
+
+    if clock_rate < 1.0 {
+        multiplier *= 0.3;
+    } else if clock_rate > 1.0 {
+        multiplier *= 1.12;
+    }
+
+    if mods.hd() {
+        multiplier *= 1.06;
+    }
+
+    if mods.hr() {
+        multiplier *= 1.06;
+    }
+
+    if mods.fl() {
+        multiplier *= 1.12;
+    }
+
+    if mods.so() {
+        multiplier *= 0.9;
+    }
+
+    if mods.rx() || mods.ap() {
+        multiplier *= 0.0;
+    }
+
+    multiplier
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::osu::OsuGradualDifficulty;
+
+    use super::*;
+
+    #[test]
+    fn combo_score_matches_attributes() {
+        let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+
+        let attrs = OsuGradualDifficulty::new(Difficulty::new(), &map)
+            .unwrap()
+            .last()
+            .unwrap();
+
+        // (5 + 8.8 + 4.5 + 16) / 38 * 5 = 4.51; rounding the settings
+        // individually would result in 4 instead
+        assert!((attrs.legacy_score_base_multiplier - 5.0).abs() < f64::EPSILON);
+
+        let nomod = f64::from(max_legacy_score(&Difficulty::new(), &map));
+        let without_combo = nomod - attrs.maximum_legacy_combo_score;
+
+        // HD
+        let hidden = f64::from(max_legacy_score(&Difficulty::new().mods(8), &map));
+        let expected = without_combo + (attrs.maximum_legacy_combo_score * 1.06).round_ties_even();
+
+        assert!(
+            (hidden - expected).abs() < f64::EPSILON,
+            "{hidden} != {expected}"
+        );
+    }
+}
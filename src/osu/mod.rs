@@ -1,4 +1,6 @@
-use rosu_map::util::Pos;
+use std::borrow::Cow;
+
+use rosu_map::{section::hit_objects::CurveBuffers, util::Pos};
 
 use crate::{
     model::{
@@ -8,21 +10,42 @@ use crate::{
     Difficulty,
 };
 
+use self::object::OsuObject;
+
 pub use self::{
-    attributes::{OsuDifficultyAttributes, OsuPerformanceAttributes},
-    difficulty::gradual::OsuGradualDifficulty,
-    performance::{gradual::OsuGradualPerformance, OsuPerformance},
+    attributes::{
+        OsuComboBreakdown, OsuDifficultyAttributes, OsuPerformanceAttributes, OsuPpComponents,
+    },
+    cursor::{estimate_judgements_from_cursor, CursorFrame},
+    difficulty::{
+        cache::{CacheMismatchError, OsuObjectCache},
+        gradual::OsuGradualDifficulty,
+    },
+    doubletap::doubletappable_objects,
+    histogram::{aim_pattern_histogram, OsuPatternHistogram},
+    nested::{OsuNestedObject, OsuNestedObjectKind},
+    performance::{
+        gradual::OsuGradualPerformance, min_accuracy_for_pp, speed_deviation_to_accuracy,
+        OsuPerformance, PERFORMANCE_BASE_MULTIPLIER,
+    },
     score_state::{OsuScoreOrigin, OsuScoreState},
-    strains::OsuStrains,
+    strains::{OsuObjectStrain, OsuStrains},
+    style::{classify_osu, MapStyle},
 };
 
 mod attributes;
 mod convert;
+mod cursor;
 mod difficulty;
+mod doubletap;
+mod histogram;
+mod legacy_score;
+mod nested;
 mod object;
 mod performance;
 mod score_state;
 mod strains;
+mod style;
 
 const PLAYFIELD_BASE_SIZE: Pos = Pos::new(512.0, 384.0);
 
@@ -31,6 +54,51 @@ const PLAYFIELD_BASE_SIZE: Pos = Pos::new(512.0, 384.0);
 /// [`GameMode::Osu`]: rosu_map::section::general::GameMode::Osu
 pub struct Osu;
 
+impl Osu {
+    /// The combo of each hitobject of an osu!standard [`Beatmap`].
+    pub(crate) fn object_combo_values(map: &Beatmap) -> Vec<u32> {
+        let mut curve_bufs = CurveBuffers::default();
+        let mut ticks_buf = Vec::new();
+
+        map.hit_objects
+            .iter()
+            .map(|h| OsuObject::new(h, map, &mut curve_bufs, &mut ticks_buf).combo_value())
+            .collect()
+    }
+
+    /// The end time of each hitobject of a [`Beatmap`], including the
+    /// duration of sliders as osu!standard sliders.
+    pub(crate) fn object_end_times(difficulty: &Difficulty, map: &Beatmap) -> Vec<f64> {
+        let map = difficulty.apply_slider_overrides(Cow::Borrowed(map));
+        let mut curve_bufs = CurveBuffers::default();
+        let mut ticks_buf = Vec::new();
+
+        map.hit_objects
+            .iter()
+            .map(|h| OsuObject::new(h, &map, &mut curve_bufs, &mut ticks_buf).end_time())
+            .collect()
+    }
+
+    /// The nested objects of the slider at the given index of an
+    /// osu!standard [`Beatmap`].
+    pub(crate) fn slider_nested_objects(map: &Beatmap, idx: usize) -> Option<Vec<OsuNestedObject>> {
+        nested::slider_nested_objects(map, idx)
+    }
+
+    /// The maximum osu!stable score of an osu!standard [`Beatmap`].
+    pub(crate) fn max_legacy_score(difficulty: &Difficulty, map: &Beatmap) -> u32 {
+        legacy_score::max_legacy_score(difficulty, map)
+    }
+
+    /// The strains of each hitobject of an osu!standard [`Beatmap`].
+    pub(crate) fn object_strains(
+        difficulty: &Difficulty,
+        map: &Beatmap,
+    ) -> Result<Vec<OsuObjectStrain>, ConvertError> {
+        strains::object_strains(difficulty, map)
+    }
+}
+
 impl IGameMode for Osu {
     type DifficultyAttributes = OsuDifficultyAttributes;
     type Strains = OsuStrains;
@@ -0,0 +1,66 @@
+use rosu_map::{section::hit_objects::CurveBuffers, util::Pos};
+
+use crate::{model::hit_object::HitObjectKind, Beatmap};
+
+use super::object::{NestedSliderObjectKind, OsuObject, OsuObjectKind};
+
+/// A nested object of an osu!standard slider, i.e. a tick, repeat, or tail.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OsuNestedObject {
+    /// The start time in milliseconds.
+    pub start_time: f64,
+    /// The position in osu!pixels.
+    ///
+    /// Neither stacking nor mods are considered.
+    pub pos: Pos,
+    /// The kind of nested object.
+    pub kind: OsuNestedObjectKind,
+}
+
+/// The kind of an [`OsuNestedObject`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OsuNestedObjectKind {
+    Tick,
+    Repeat,
+    Tail,
+}
+
+impl From<NestedSliderObjectKind> for OsuNestedObjectKind {
+    fn from(kind: NestedSliderObjectKind) -> Self {
+        match kind {
+            NestedSliderObjectKind::Tick => Self::Tick,
+            NestedSliderObjectKind::Repeat => Self::Repeat,
+            NestedSliderObjectKind::Tail => Self::Tail,
+        }
+    }
+}
+
+pub fn slider_nested_objects(map: &Beatmap, idx: usize) -> Option<Vec<OsuNestedObject>> {
+    let h = map.hit_objects.get(idx)?;
+
+    if !matches!(h.kind, HitObjectKind::Slider(_)) {
+        return None;
+    }
+
+    let mut curve_bufs = CurveBuffers::default();
+    let mut ticks_buf = Vec::new();
+
+    let mut obj = OsuObject::new(h, map, &mut curve_bufs, &mut ticks_buf);
+    obj.finalize_nested();
+
+    let OsuObjectKind::Slider(slider) = obj.kind else {
+        unreachable!()
+    };
+
+    let nested = slider
+        .nested_objects
+        .into_iter()
+        .map(|nested| OsuNestedObject {
+            start_time: nested.start_time,
+            pos: nested.pos,
+            kind: nested.kind.into(),
+        })
+        .collect();
+
+    Some(nested)
+}
@@ -164,6 +164,15 @@ impl OsuObject {
         }
     }
 
+    /// The combo this object contributes, i.e. one for the head plus one
+    /// for each nested object.
+    pub const fn combo_value(&self) -> u32 {
+        match self.kind {
+            OsuObjectKind::Circle | OsuObjectKind::Spinner(_) => 1,
+            OsuObjectKind::Slider(ref slider) => 1 + slider.nested_objects.len() as u32,
+        }
+    }
+
     pub const fn is_circle(&self) -> bool {
         matches!(self.kind, OsuObjectKind::Circle)
     }
@@ -368,6 +377,13 @@ impl OsuSlider {
             .count()
     }
 
+    pub fn tail_count(&self) -> usize {
+        self.nested_objects
+            .iter()
+            .filter(|nested| matches!(nested.kind, NestedSliderObjectKind::Tail))
+            .count()
+    }
+
     pub fn tail(&self) -> Option<&NestedSliderObject> {
         self.nested_objects
             .iter()
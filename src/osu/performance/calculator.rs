@@ -15,8 +15,9 @@ use crate::{
     GameMods,
 };
 
-use super::{n_large_tick_miss, n_slider_ends_dropped, total_imperfect_hits};
+use super::{effective_miss_count, n_large_tick_miss, n_slider_ends_dropped, total_imperfect_hits};
 
+/// Base multiplier of osu!standard star rating and performance calculation.
 // * This is being adjusted to keep the final pp value scaled around what it used to be when changing things.
 pub const PERFORMANCE_BASE_MULTIPLIER: f64 = 1.15;
 
@@ -27,17 +28,22 @@ pub(super) struct OsuPerformanceCalculator<'mods> {
     state: OsuScoreState,
     effective_miss_count: f64,
     using_classic_slider_acc: bool,
+    base_multiplier: f64,
 }
 
 impl<'a> OsuPerformanceCalculator<'a> {
-    pub const fn new(
+    pub fn new(
         attrs: OsuDifficultyAttributes,
         mods: &'a GameMods,
         acc: f64,
         state: OsuScoreState,
-        effective_miss_count: f64,
+        slider_breaks: Option<u32>,
         using_classic_slider_acc: bool,
+        base_multiplier: f64,
     ) -> Self {
+        let effective_miss_count =
+            effective_miss_count(&attrs, &state, slider_breaks, using_classic_slider_acc);
+
         Self {
             attrs,
             mods,
@@ -45,6 +51,7 @@ impl<'a> OsuPerformanceCalculator<'a> {
             state,
             effective_miss_count,
             using_classic_slider_acc,
+            base_multiplier,
         }
     }
 }
@@ -62,7 +69,7 @@ impl OsuPerformanceCalculator<'_> {
 
         let total_hits = f64::from(total_hits);
 
-        let mut multiplier = PERFORMANCE_BASE_MULTIPLIER;
+        let mut multiplier = self.base_multiplier;
 
         if self.mods.nf() {
             multiplier *= (1.0 - 0.02 * self.effective_miss_count).max(0.9);
@@ -118,6 +125,7 @@ impl OsuPerformanceCalculator<'_> {
             pp,
             effective_miss_count: self.effective_miss_count,
             speed_deviation,
+            accuracy: self.acc,
         }
     }
 
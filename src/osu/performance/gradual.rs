@@ -1,4 +1,7 @@
-use crate::{model::mode::ConvertError, osu::OsuGradualDifficulty, Beatmap, Difficulty};
+use crate::{
+    any::performance::judgement::JudgementTotals, model::mode::ConvertError,
+    osu::OsuGradualDifficulty, Beatmap, Difficulty,
+};
 
 use super::{OsuPerformanceAttributes, OsuScoreState};
 
@@ -77,6 +80,7 @@ use super::{OsuPerformanceAttributes, OsuScoreState};
 pub struct OsuGradualPerformance {
     lazer: bool,
     difficulty: OsuGradualDifficulty,
+    pub(crate) totals: JudgementTotals,
 }
 
 impl OsuGradualPerformance {
@@ -85,7 +89,11 @@ impl OsuGradualPerformance {
         let lazer = difficulty.get_lazer();
         let difficulty = OsuGradualDifficulty::new(difficulty, map)?;
 
-        Ok(Self { lazer, difficulty })
+        Ok(Self {
+            lazer,
+            difficulty,
+            totals: JudgementTotals::default(),
+        })
     }
 
     /// Process the next hit object and calculate the performance attributes
@@ -121,6 +129,28 @@ impl OsuGradualPerformance {
         Some(performance)
     }
 
+    /// Process everything up to the hit object at index `idx` and calculate
+    /// the performance attributes for the resulting score state.
+    ///
+    /// Unlike [`nth`](Self::nth), `idx` is not relative to the current
+    /// position so already processed objects can be revisited. The result is
+    /// the same as calling `next` `idx + 1` times on a new instance.
+    ///
+    /// Returns `None` if `idx` is out of bounds.
+    pub fn at_index(
+        &mut self,
+        state: OsuScoreState,
+        idx: usize,
+    ) -> Option<OsuPerformanceAttributes> {
+        if idx >= self.difficulty.idx + self.difficulty.len() {
+            return None;
+        }
+
+        self.difficulty.rewind(idx);
+
+        self.nth(state, idx - self.difficulty.idx)
+    }
+
     /// Returns the amount of remaining objects.
     #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
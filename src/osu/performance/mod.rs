@@ -11,7 +11,7 @@ use crate::{
     mania::ManiaPerformance,
     model::{mode::ConvertError, mods::GameMods},
     taiko::TaikoPerformance,
-    util::map_or_attrs::MapOrAttrs,
+    util::{map_or_attrs::MapOrAttrs, special_functions::erf},
     Beatmap,
 };
 
@@ -39,6 +39,7 @@ pub struct OsuPerformance<'map> {
     pub(crate) n100: Option<u32>,
     pub(crate) n50: Option<u32>,
     pub(crate) misses: Option<u32>,
+    pub(crate) slider_breaks: Option<u32>,
     pub(crate) hitresult_priority: HitResultPriority,
 }
 
@@ -230,6 +231,17 @@ impl<'map> OsuPerformance<'map> {
         self
     }
 
+    /// Specify the amount of slider breaks of a play.
+    ///
+    /// A slider break drops the combo without counting as a miss. If
+    /// specified, the effective miss count will be the sum of misses and
+    /// slider breaks instead of being estimated through the combo.
+    pub const fn slider_breaks(mut self, slider_breaks: u32) -> Self {
+        self.slider_breaks = Some(slider_breaks);
+
+        self
+    }
+
     /// Use the specified settings of the given [`Difficulty`].
     pub fn difficulty(mut self, difficulty: Difficulty) -> Self {
         self.difficulty = difficulty;
@@ -456,6 +468,26 @@ impl<'map> OsuPerformance<'map> {
                             n300 += remaining;
                         }
                         HitResultPriority::WorstCase => n50 += remaining,
+                        HitResultPriority::Balanced => {
+                            let state = |n300, n50| NoComboState {
+                                n300,
+                                n100,
+                                n50,
+                                misses,
+                                large_tick_hits,
+                                small_tick_hits,
+                                slider_end_hits,
+                            };
+
+                            let worst_acc = state(n300, n50 + remaining).accuracy(origin);
+                            let best_acc = state(n300 + remaining, n50).accuracy(origin);
+                            let n = HitResultPriority::balanced_split(
+                                remaining, acc, worst_acc, best_acc,
+                            );
+
+                            n300 += n;
+                            n50 += remaining - n;
+                        }
                     }
                 }
                 (Some(_), Some(_), None) => n50 = n_objects.saturating_sub(n300 + n100 + misses),
@@ -663,6 +695,23 @@ impl<'map> OsuPerformance<'map> {
                                 n100 -= 5 * n;
                                 n50 += 4 * n;
                             }
+                            HitResultPriority::Balanced => {
+                                // Meet halfway between both shifts
+                                let to_best = cmp::min(n300, n50 / 4);
+                                let to_worst = n100 / 5;
+
+                                if to_best >= to_worst {
+                                    let n = (to_best - to_worst) / 2;
+                                    n300 -= n;
+                                    n100 += 5 * n;
+                                    n50 -= 4 * n;
+                                } else {
+                                    let n = (to_worst - to_best) / 2;
+                                    n300 += n;
+                                    n100 -= 5 * n;
+                                    n50 += 4 * n;
+                                }
+                            }
                             HitResultPriority::Fastest => unreachable!(),
                         }
                     }
@@ -672,14 +721,14 @@ impl<'map> OsuPerformance<'map> {
             let remaining = n_objects.saturating_sub(n300 + n100 + n50 + misses);
 
             match priority {
-                HitResultPriority::BestCase | HitResultPriority::Fastest => {
-                    match (self.n300, self.n100, self.n50) {
-                        (None, ..) => n300 = remaining,
-                        (_, None, _) => n100 = remaining,
-                        (.., None) => n50 = remaining,
-                        _ => n300 += remaining,
-                    }
-                }
+                HitResultPriority::BestCase
+                | HitResultPriority::Fastest
+                | HitResultPriority::Balanced => match (self.n300, self.n100, self.n50) {
+                    (None, ..) => n300 = remaining,
+                    (_, None, _) => n100 = remaining,
+                    (.., None) => n50 = remaining,
+                    _ => n300 += remaining,
+                },
                 HitResultPriority::WorstCase => match (self.n50, self.n100, self.n300) {
                     (None, ..) => n50 = remaining,
                     (_, None, _) => n100 = remaining,
@@ -716,77 +765,350 @@ impl<'map> OsuPerformance<'map> {
         })
     }
 
+    /// Create the [`OsuScoreState`] that would be used for performance
+    /// calculation without modifying `self`.
+    ///
+    /// Unlike [`OsuPerformance::generate_state`], neither the generated
+    /// hitresults nor the difficulty attributes are stored. If `self` was
+    /// created through a map, the difficulty attributes are calculated on
+    /// each call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{osu::OsuPerformance, Beatmap};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    /// let perf = OsuPerformance::new(&map).accuracy(98.0).misses(1);
+    ///
+    /// let state = perf.inspect_state().unwrap();
+    /// assert_eq!(state.misses, 1);
+    ///
+    /// let attrs = perf.calculate().unwrap();
+    /// assert!((attrs.accuracy - 0.98).abs() < 0.001);
+    /// ```
+    pub fn inspect_state(&self) -> Result<OsuScoreState, ConvertError> {
+        let map_or_attrs = match self.map_or_attrs {
+            MapOrAttrs::Map(ref map) => {
+                MapOrAttrs::Attrs(self.difficulty.calculate_for_mode::<Osu>(map)?)
+            }
+            MapOrAttrs::Attrs(ref attrs) => MapOrAttrs::Attrs(attrs.clone()),
+        };
+
+        let mut this = Self {
+            map_or_attrs,
+            difficulty: self.difficulty.clone(),
+            acc: self.acc,
+            combo: self.combo,
+            large_tick_hits: self.large_tick_hits,
+            small_tick_hits: self.small_tick_hits,
+            slider_end_hits: self.slider_end_hits,
+            n300: self.n300,
+            n100: self.n100,
+            n50: self.n50,
+            misses: self.misses,
+            slider_breaks: self.slider_breaks,
+            hitresult_priority: self.hitresult_priority,
+        };
+
+        this.generate_state()
+    }
+
     /// Calculate all performance related values, including pp and stars.
-    pub fn calculate(mut self) -> Result<OsuPerformanceAttributes, ConvertError> {
+    pub fn calculate(self) -> Result<OsuPerformanceAttributes, ConvertError> {
+        self.recalculate_with(|_| {})
+    }
+
+    /// Calculate the performance after modifying the difficulty attributes.
+    ///
+    /// The score state is generated from the unmodified attributes so the
+    /// hitresults, mods, and all other score-related settings stay the same.
+    /// This is useful to inspect how changes to single difficulty values,
+    /// e.g. the aim or speed rating, affect the pp.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{osu::OsuPerformance, Beatmap};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// let perf = OsuPerformance::new(&map).accuracy(98.5).misses(2);
+    ///
+    /// let attrs = perf.clone().calculate().unwrap();
+    /// let harder_aim = perf
+    ///     .recalculate_with(|difficulty| difficulty.aim *= 1.1)
+    ///     .unwrap();
+    ///
+    /// assert!(harder_aim.pp_aim > attrs.pp_aim);
+    /// assert_eq!(harder_aim.pp_speed, attrs.pp_speed);
+    /// assert_eq!(harder_aim.accuracy, attrs.accuracy);
+    /// ```
+    pub fn recalculate_with(
+        mut self,
+        f: impl FnOnce(&mut OsuDifficultyAttributes),
+    ) -> Result<OsuPerformanceAttributes, ConvertError> {
         let state = self.generate_state()?;
 
-        let attrs = match self.map_or_attrs {
+        let mut attrs = match self.map_or_attrs {
             MapOrAttrs::Attrs(attrs) => attrs,
             MapOrAttrs::Map(ref map) => self.difficulty.calculate_for_mode::<Osu>(map)?,
         };
 
+        f(&mut attrs);
+
         let mods = self.difficulty.get_mods();
         let lazer = self.difficulty.get_lazer();
         let using_classic_slider_acc = mods.no_slider_head_acc(lazer);
 
-        let mut effective_miss_count = f64::from(state.misses);
+        let acc = state.accuracy(score_origin(&attrs, lazer, using_classic_slider_acc));
 
-        if attrs.n_sliders > 0 {
-            if using_classic_slider_acc {
-                // * Consider that full combo is maximum combo minus dropped slider tails since they don't contribute to combo but also don't break it
-                // * In classic scores we can't know the amount of dropped sliders so we estimate to 10% of all sliders on the map
-                let full_combo_threshold =
-                    f64::from(attrs.max_combo) - 0.1 * f64::from(attrs.n_sliders);
+        let inner = OsuPerformanceCalculator::new(
+            attrs,
+            mods,
+            acc,
+            state,
+            self.slider_breaks,
+            using_classic_slider_acc,
+            self.difficulty.get_performance_base_multiplier(),
+        );
 
-                if f64::from(state.max_combo) < full_combo_threshold {
-                    effective_miss_count =
-                        full_combo_threshold / f64::from(state.max_combo).max(1.0);
-                }
+        Ok(inner.calculate())
+    }
 
-                // * In classic scores there can't be more misses than a sum of all non-perfect judgements
-                effective_miss_count = effective_miss_count.min(total_imperfect_hits(&state));
-            } else {
-                let full_combo_threshold =
-                    f64::from(attrs.max_combo - n_slider_ends_dropped(&attrs, &state));
+    /// Whether the score is a full combo.
+    ///
+    /// Dropped slider tails don't break combo so a score without misses
+    /// whose combo is only missing the combo of dropped tails is still
+    /// considered a full combo. For classic scores, the amount of dropped
+    /// tails is unknown so it is estimated to be 10% of all sliders.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{osu::OsuPerformance, Beatmap};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// let mut fc = OsuPerformance::new(&map).accuracy(98.0);
+    /// assert!(fc.is_fc().unwrap());
+    ///
+    /// let mut choke = fc.combo(100);
+    /// assert!(!choke.is_fc().unwrap());
+    /// ```
+    pub fn is_fc(&mut self) -> Result<bool, ConvertError> {
+        let state = self.generate_state()?;
 
-                if f64::from(state.max_combo) < full_combo_threshold {
-                    effective_miss_count =
-                        full_combo_threshold / f64::from(state.max_combo).max(1.0);
-                }
+        let MapOrAttrs::Attrs(ref attrs) = self.map_or_attrs else {
+            unreachable!("difficulty attributes are stored when generating the state")
+        };
 
-                // * Combine regular misses with tick misses since tick misses break combo as well
-                effective_miss_count = effective_miss_count
-                    .min(f64::from(n_large_tick_miss(&attrs, &state) + state.misses));
-            }
+        let lazer = self.difficulty.get_lazer();
+        let using_classic_slider_acc = self.difficulty.get_mods().no_slider_head_acc(lazer);
+
+        Ok(state.misses == 0
+            && f64::from(state.max_combo)
+                >= full_combo_threshold(attrs, &state, using_classic_slider_acc))
+    }
+
+    /// Turn the play into a full combo with the same accuracy.
+    ///
+    /// Misses, combo, slider breaks, and slider hits are cleared and new
+    /// hitresults without misses are generated for the accuracy of the
+    /// current ones. Hence, the effective miss count will be zero as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{osu::OsuPerformance, Beatmap};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// let play = OsuPerformance::new(&map).accuracy(97.0).misses(3).combo(500);
+    /// let mut fc = play.as_full_combo();
+    ///
+    /// assert_eq!(fc.generate_state().unwrap().misses, 0);
+    /// assert_eq!(fc.calculate().unwrap().effective_miss_count, 0.0);
+    /// ```
+    pub fn as_full_combo(mut self) -> Self {
+        if let (Ok(state), MapOrAttrs::Attrs(attrs)) = (self.generate_state(), &self.map_or_attrs) {
+            let lazer = self.difficulty.get_lazer();
+            let using_classic_slider_acc = self.difficulty.get_mods().no_slider_head_acc(lazer);
+            let origin = score_origin(attrs, lazer, using_classic_slider_acc);
+
+            self.acc = Some(state.accuracy(origin));
         }
 
-        effective_miss_count = effective_miss_count.max(f64::from(state.misses));
-        effective_miss_count = effective_miss_count.min(f64::from(state.total_hits()));
+        self.combo = None;
+        self.large_tick_hits = None;
+        self.small_tick_hits = None;
+        self.slider_end_hits = None;
+        self.n300 = None;
+        self.n100 = None;
+        self.n50 = None;
+        self.misses = None;
+        self.slider_breaks = None;
 
-        let origin = match (lazer, using_classic_slider_acc) {
-            (false, _) => OsuScoreOrigin::Stable,
-            (true, false) => OsuScoreOrigin::WithSliderAcc {
-                max_large_ticks: attrs.n_large_ticks,
-                max_slider_ends: attrs.n_sliders,
-            },
-            (true, true) => OsuScoreOrigin::WithoutSliderAcc {
-                max_large_ticks: attrs.n_sliders + attrs.n_large_ticks,
-                max_small_ticks: attrs.n_sliders,
-            },
+        self
+    }
+
+    /// Calculate how much pp each additional miss costs.
+    ///
+    /// The `k`-th value of the result is the pp difference between `k` and
+    /// `k + 1` misses for `k` in `0..max_misses`. All other settings are
+    /// kept the same for each miss count. Since the effective miss count is
+    /// not linear in the amount of misses, the values are generally not
+    /// constant.
+    ///
+    /// Difficulty attributes are only calculated once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{osu::OsuPerformance, Beatmap};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// let costs = OsuPerformance::new(&map)
+    ///     .accuracy(98.5)
+    ///     .marginal_miss_cost(5)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(costs.len(), 5);
+    /// assert!(costs.iter().all(|&cost| cost > 0.0));
+    /// ```
+    pub fn marginal_miss_cost(mut self, max_misses: u32) -> Result<Vec<f64>, ConvertError> {
+        if let MapOrAttrs::Map(ref map) = self.map_or_attrs {
+            let attrs = self.difficulty.calculate_for_mode::<Osu>(map)?;
+            self.map_or_attrs.insert_attrs(attrs);
+        }
+
+        let mut prev_pp = self.clone().misses(0).calculate()?.pp;
+
+        (1..=max_misses)
+            .map(|misses| {
+                let pp = self.clone().misses(misses).calculate()?.pp;
+                let cost = prev_pp - pp;
+                prev_pp = pp;
+
+                Ok(cost)
+            })
+            .collect()
+    }
+
+    /// Calculate the performance attributes for each of the given
+    /// accuracies.
+    ///
+    /// Accuracies are specified in the same way as for
+    /// [`OsuPerformance::accuracy`]. All other settings are kept the same for
+    /// each accuracy.
+    ///
+    /// Difficulty attributes are only calculated once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{osu::OsuPerformance, Beatmap};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// let attrs = OsuPerformance::new(&map)
+    ///     .mods(8) // HD
+    ///     .calculate_for_accuracies(&[95.0, 97.0, 99.0, 100.0])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(attrs.len(), 4);
+    /// assert!(attrs.windows(2).all(|pair| pair[0].pp < pair[1].pp));
+    /// ```
+    pub fn calculate_for_accuracies(
+        &self,
+        accs: &[f64],
+    ) -> Result<Vec<OsuPerformanceAttributes>, ConvertError> {
+        let mut this = self.clone();
+
+        if let MapOrAttrs::Map(ref map) = this.map_or_attrs {
+            let attrs = this.difficulty.calculate_for_mode::<Osu>(map)?;
+            this.map_or_attrs.insert_attrs(attrs);
+        }
+
+        accs.iter()
+            .map(|&acc| this.clone().accuracy(acc).calculate())
+            .collect()
+    }
+
+    /// Calculate the performance attributes for each of the given mods and
+    /// return the mods that yield the most pp.
+    ///
+    /// All other settings are kept the same for each candidate. Difficulty
+    /// attributes are only calculated once for all candidates that differ
+    /// solely in mods that don't affect difficulty such as `NoFail`. If
+    /// difficulty attributes were specified instead of a map, they're used
+    /// for all candidates.
+    ///
+    /// If `candidates` is empty, the current mods are used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{osu::OsuPerformance, Beatmap, GameMods};
+    ///
+    /// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+    ///
+    /// // NM, NF, HD, HDNF, DT
+    /// let candidates = [0, 1, 8, 9, 64].map(GameMods::from);
+    ///
+    /// let (mods, attrs) = OsuPerformance::new(&map)
+    ///     .accuracy(98.0)
+    ///     .best_mods(&candidates)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(mods, GameMods::from(64));
+    /// ```
+    pub fn best_mods(
+        &self,
+        candidates: &[GameMods],
+    ) -> Result<(GameMods, OsuPerformanceAttributes), ConvertError> {
+        let Some((first, rest)) = candidates.split_first() else {
+            let mods = self.difficulty.get_mods().clone();
+
+            return self.clone().calculate().map(|attrs| (mods, attrs));
         };
 
-        let acc = state.accuracy(origin);
+        let mut difficulties: Vec<(GameMods, OsuDifficultyAttributes)> = Vec::new();
 
-        let inner = OsuPerformanceCalculator::new(
-            attrs,
-            mods,
-            acc,
-            state,
-            effective_miss_count,
-            using_classic_slider_acc,
-        );
+        let mut calculate = |mods: &GameMods| {
+            let mut this = self.clone().mods(mods.clone());
 
-        Ok(inner.calculate())
+            if let MapOrAttrs::Map(ref map) = this.map_or_attrs {
+                let key = mods.without_performance_only();
+
+                let attrs =
+                    if let Some((_, attrs)) = difficulties.iter().find(|(mods, _)| *mods == key) {
+                        attrs.clone()
+                    } else {
+                        let attrs = this.difficulty.calculate_for_mode::<Osu>(map)?;
+                        difficulties.push((key, attrs.clone()));
+
+                        attrs
+                    };
+
+                this.map_or_attrs.insert_attrs(attrs);
+            }
+
+            this.calculate()
+        };
+
+        let mut best = (first.clone(), calculate(first)?);
+
+        for mods in rest {
+            let attrs = calculate(mods)?;
+
+            if attrs.pp > best.1.pp {
+                best = (mods.clone(), attrs);
+            }
+        }
+
+        Ok(best)
     }
 
     pub(crate) const fn from_map_or_attrs(map_or_attrs: MapOrAttrs<'map, Osu>) -> Self {
@@ -802,6 +1124,7 @@ impl<'map> OsuPerformance<'map> {
             n100: None,
             n50: None,
             misses: None,
+            slider_breaks: None,
             hitresult_priority: HitResultPriority::DEFAULT,
         }
     }
@@ -838,6 +1161,191 @@ impl<'map, T: IntoModePerformance<'map, Osu>> From<T> for OsuPerformance<'map> {
     }
 }
 
+/// Estimate the accuracy on a map's circles for a player whose hit errors
+/// are normally distributed with the given standard deviation in ms.
+///
+/// The hit windows are taken from the given difficulty attributes so mods
+/// such as DT or HR are accounted for.
+///
+/// This only approximates the inverse of the `speed_deviation` on
+/// [`OsuPerformanceAttributes`]. That deviation is a pessimistic upper bound
+/// estimated from the score's hitresults, so feeding it back into this
+/// function generally results in a lower accuracy than the score's.
+///
+/// Returns an accuracy between `0.0` and `1.0` that does not increase as
+/// the deviation increases.
+pub fn speed_deviation_to_accuracy(deviation: f64, attrs: &OsuDifficultyAttributes) -> f64 {
+    if deviation.is_nan() {
+        return 0.0;
+    } else if deviation <= 0.0 {
+        return 1.0;
+    }
+
+    // Probability that a normally distributed hit error lands within the
+    // given window
+    let within = |window: f64| erf(window / (f64::sqrt(2.0) * deviation));
+
+    let p300 = within(attrs.great_hit_window);
+    let p100 = within(attrs.ok_hit_window) - p300;
+    let p50 = within(attrs.meh_hit_window) - p300 - p100;
+
+    ((6.0 * p300 + 2.0 * p100 + p50) / 6.0).clamp(0.0, 1.0)
+}
+
+/// Find the minimum accuracy that a score needs to be worth at least
+/// `target_pp`.
+///
+/// The accuracy is specified in percent, i.e. between `0.0` and `100.0`, and
+/// binary searched by repeatedly calculating the performance on the given
+/// difficulty attributes so the difficulty calculation is not repeated. If
+/// `combo` is `None`, a full combo is assumed.
+///
+/// Returns `None` if the target is unreachable even with 100% accuracy.
+///
+/// # Example
+///
+/// ```
+/// use rosu_pp::{
+///     osu::{min_accuracy_for_pp, Osu, OsuPerformance},
+///     Beatmap, Difficulty,
+/// };
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+/// let attrs = Difficulty::new()
+///     .mods(8) // HD
+///     .calculate_for_mode::<Osu>(&map)
+///     .unwrap();
+///
+/// let acc = min_accuracy_for_pp(&attrs, 8, None, 1, 250.0).unwrap();
+///
+/// let pp = OsuPerformance::new(attrs.clone())
+///     .mods(8)
+///     .misses(1)
+///     .accuracy(acc)
+///     .calculate()
+///     .unwrap()
+///     .pp;
+///
+/// assert!(pp >= 250.0 && pp < 251.0);
+///
+/// assert!(min_accuracy_for_pp(&attrs, 8, None, 1, 10_000.0).is_none());
+/// ```
+pub fn min_accuracy_for_pp(
+    attrs: &OsuDifficultyAttributes,
+    mods: impl Into<GameMods>,
+    combo: Option<u32>,
+    misses: u32,
+    target_pp: f64,
+) -> Option<f64> {
+    const ITERATIONS: usize = 30;
+
+    let mut perf = OsuPerformance::new(attrs.clone()).mods(mods).misses(misses);
+
+    if let Some(combo) = combo {
+        perf = perf.combo(combo);
+    }
+
+    let pp = |acc: f64| {
+        perf.clone()
+            .accuracy(acc)
+            .calculate()
+            .map_or(0.0, |attrs| attrs.pp)
+    };
+
+    if pp(100.0) < target_pp {
+        return None;
+    } else if pp(0.0) >= target_pp {
+        return Some(0.0);
+    }
+
+    let mut low = 0.0;
+    let mut high = 100.0;
+
+    for _ in 0..ITERATIONS {
+        let mid = (low + high) / 2.0;
+
+        if pp(mid) >= target_pp {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+
+    Some(high)
+}
+
+/// Misses including an approximated amount of slider breaks.
+fn effective_miss_count(
+    attrs: &OsuDifficultyAttributes,
+    state: &OsuScoreState,
+    slider_breaks: Option<u32>,
+    using_classic_slider_acc: bool,
+) -> f64 {
+    let mut effective_miss_count = f64::from(state.misses);
+
+    if let Some(slider_breaks) = slider_breaks {
+        effective_miss_count += f64::from(slider_breaks);
+    } else if attrs.n_sliders > 0 {
+        let full_combo_threshold = full_combo_threshold(attrs, state, using_classic_slider_acc);
+
+        if f64::from(state.max_combo) < full_combo_threshold {
+            effective_miss_count = full_combo_threshold / f64::from(state.max_combo).max(1.0);
+        }
+
+        effective_miss_count = if using_classic_slider_acc {
+            // * In classic scores there can't be more misses than a sum of all non-perfect judgements
+            effective_miss_count.min(total_imperfect_hits(state))
+        } else {
+            // * Combine regular misses with tick misses since tick misses break combo as well
+            effective_miss_count.min(f64::from(n_large_tick_miss(attrs, state) + state.misses))
+        };
+    }
+
+    effective_miss_count = effective_miss_count.max(f64::from(state.misses));
+    effective_miss_count = effective_miss_count.min(f64::from(state.total_hits()));
+
+    effective_miss_count
+}
+
+/// The minimum combo that is considered a full combo.
+///
+/// Dropped slider tails don't contribute to combo but also don't break it
+/// so they are subtracted from the maximum combo.
+pub(crate) fn full_combo_threshold(
+    attrs: &OsuDifficultyAttributes,
+    state: &OsuScoreState,
+    using_classic_slider_acc: bool,
+) -> f64 {
+    if using_classic_slider_acc {
+        // * In classic scores we can't know the amount of dropped sliders so we estimate to 10% of all sliders on the map
+        f64::from(attrs.max_combo) - 0.1 * f64::from(attrs.n_sliders)
+    } else {
+        f64::from(
+            attrs
+                .max_combo
+                .saturating_sub(n_slider_ends_dropped(attrs, state)),
+        )
+    }
+}
+
+const fn score_origin(
+    attrs: &OsuDifficultyAttributes,
+    lazer: bool,
+    using_classic_slider_acc: bool,
+) -> OsuScoreOrigin {
+    match (lazer, using_classic_slider_acc) {
+        (false, _) => OsuScoreOrigin::Stable,
+        (true, false) => OsuScoreOrigin::WithSliderAcc {
+            max_large_ticks: attrs.n_large_ticks,
+            max_slider_ends: attrs.n_sliders,
+        },
+        (true, true) => OsuScoreOrigin::WithoutSliderAcc {
+            max_large_ticks: attrs.n_sliders + attrs.n_large_ticks,
+            max_small_ticks: attrs.n_sliders,
+        },
+    }
+}
+
 fn total_imperfect_hits(state: &OsuScoreState) -> f64 {
     f64::from(state.n100 + state.n50 + state.misses)
 }
@@ -905,7 +1413,7 @@ mod test {
     use rosu_mods::{GameModIntermode, GameModsIntermode};
 
     use crate::{
-        any::{DifficultyAttributes, PerformanceAttributes},
+        any::{DifficultyAttributes, PerformanceAttributes, Skill},
         taiko::{TaikoDifficultyAttributes, TaikoPerformanceAttributes},
         Beatmap,
     };
@@ -1218,6 +1726,218 @@ mod test {
         assert_eq!(state, expected);
     }
 
+    #[test]
+    fn calculate_for_accuracies() {
+        let map = beatmap();
+        let perf = OsuPerformance::from(&map).mods(8).combo(500);
+
+        let attrs = perf.calculate_for_accuracies(&[95.0, 99.0]).unwrap();
+
+        let expected = [95.0, 99.0].map(|acc| perf.clone().accuracy(acc).calculate().unwrap());
+
+        assert_eq!(attrs, expected);
+    }
+
+    #[test]
+    fn inspect_state() {
+        let map = beatmap();
+        let mut perf = OsuPerformance::from(&map).accuracy(97.0).misses(2);
+
+        let before = perf.clone();
+        let state = perf.inspect_state().unwrap();
+
+        assert_eq!(perf, before);
+        assert_eq!(state, perf.generate_state().unwrap());
+    }
+
+    #[test]
+    fn best_mods() {
+        let map = beatmap();
+        let perf = OsuPerformance::from(&map).accuracy(97.0).misses(3);
+
+        // NM, NF, HD, HDNF, HR, SO, HDHRSO
+        let candidates = [0, 1, 8, 9, 16, 4096, 4120].map(GameMods::from);
+
+        let (mods, attrs) = perf.best_mods(&candidates).unwrap();
+
+        let expected = candidates
+            .iter()
+            .map(|mods| (mods, perf.clone().mods(mods.clone()).calculate().unwrap()))
+            .max_by(|(_, a), (_, b)| a.pp.total_cmp(&b.pp))
+            .unwrap();
+
+        assert_eq!(&mods, expected.0);
+        assert_eq!(attrs, expected.1);
+    }
+
+    #[test]
+    fn speed_deviation_to_accuracy_bounds() {
+        let attrs = attrs();
+
+        assert!((speed_deviation_to_accuracy(0.0, &attrs) - 1.0).abs() < f64::EPSILON);
+        assert!(speed_deviation_to_accuracy(f64::NAN, &attrs).abs() < f64::EPSILON);
+
+        let mut prev = 1.0;
+
+        for deviation in (1..=2000).map(|i| f64::from(i) * 0.25) {
+            let acc = speed_deviation_to_accuracy(deviation, &attrs);
+            assert!(acc <= prev, "{deviation}: {acc} > {prev}");
+
+            // The accuracy lies between the chance to hit a 300 and the
+            // chance to hit anything at all
+            let within = |window: f64| erf(window / (f64::sqrt(2.0) * deviation));
+            let lower = within(attrs.great_hit_window);
+            let upper = within(attrs.meh_hit_window);
+            assert!(
+                lower - 1e-12 <= acc && acc <= upper + 1e-12,
+                "{deviation}: {acc} not in {lower}..={upper}"
+            );
+
+            prev = acc;
+        }
+
+        assert!(prev < 0.5, "{prev}");
+
+        let perf = OsuPerformance::from(attrs.clone())
+            .accuracy(98.0)
+            .calculate()
+            .unwrap();
+        let deviation = perf.speed_deviation.unwrap();
+        let acc = speed_deviation_to_accuracy(deviation, &attrs);
+
+        // The performance calculator estimates a pessimistic upper bound
+        // for the deviation so the resulting accuracy is somewhat lower
+        assert!((0.8..0.98).contains(&acc), "{acc}");
+    }
+
+    #[test]
+    fn min_accuracy_for_pp_roundtrip() {
+        let attrs = attrs();
+
+        for (combo, misses, target) in [(None, 0, 150.0), (Some(500), 2, 100.0)] {
+            let acc = min_accuracy_for_pp(&attrs, 0, combo, misses, target).unwrap();
+
+            let mut perf = OsuPerformance::from(attrs.clone()).misses(misses);
+
+            if let Some(combo) = combo {
+                perf = perf.combo(combo);
+            }
+
+            let pp = perf.clone().accuracy(acc).calculate().unwrap().pp;
+            assert!(pp >= target && pp - target < 1.0, "{target}: {pp}");
+
+            let below = perf.accuracy(acc - 0.1).calculate().unwrap().pp;
+            assert!(below < target, "{target}: {below}");
+        }
+
+        assert!(min_accuracy_for_pp(&attrs, 0, None, 0, 100_000.0).is_none());
+    }
+
+    #[test]
+    fn muted_is_neutral() {
+        let map = beatmap();
+
+        for base in [GameModsIntermode::new(), GameModsIntermode::from_bits(8)] {
+            let mut muted = base.clone();
+            muted.insert(GameModIntermode::Muted);
+
+            let calculate = |mods| {
+                OsuPerformance::from(&map)
+                    .mods(mods)
+                    .accuracy(98.0)
+                    .misses(1)
+                    .calculate()
+                    .unwrap()
+            };
+
+            assert_eq!(calculate(muted), calculate(base));
+        }
+    }
+
+    #[test]
+    fn as_full_combo() {
+        let play = OsuPerformance::from(attrs())
+            .n100(20)
+            .n50(3)
+            .misses(4)
+            .combo(300);
+
+        let acc = play.clone().calculate().unwrap().accuracy;
+        let fc = play.as_full_combo().calculate().unwrap();
+
+        let expected = OsuPerformance::from(attrs())
+            .accuracy(acc * 100.0)
+            .calculate()
+            .unwrap();
+
+        assert_eq!(fc, expected);
+        assert!((fc.accuracy - acc).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn hitresults_acc_balanced() {
+        let state = |priority| {
+            OsuPerformance::from(attrs())
+                .lazer(false)
+                .accuracy(95.0)
+                .misses(2)
+                .hitresult_priority(priority)
+                .generate_state()
+                .unwrap()
+        };
+
+        let best = state(HitResultPriority::BestCase);
+        let worst = state(HitResultPriority::WorstCase);
+        let balanced = state(HitResultPriority::Balanced);
+
+        assert!(best.n50 < balanced.n50 && balanced.n50 < worst.n50);
+        assert!(
+            (balanced.accuracy(OsuScoreOrigin::Stable) - best.accuracy(OsuScoreOrigin::Stable))
+                .abs()
+                < f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn hitresults_n300_n100_n50_acc_balanced() {
+        let state = OsuPerformance::from(attrs())
+            .lazer(false)
+            .accuracy(95.0)
+            .n300(300)
+            .n100(20)
+            .n50(5)
+            .misses(2)
+            .hitresult_priority(HitResultPriority::Balanced)
+            .generate_state()
+            .unwrap();
+
+        assert_eq!(state.n100, 20);
+        assert!(state.n300 > 300 && state.n50 > 5);
+        assert!((state.accuracy(OsuScoreOrigin::Stable) - 0.95).abs() < 0.001);
+    }
+
+    #[test]
+    fn hitresults_n300_n100_n50_balanced() {
+        let state = |priority| {
+            OsuPerformance::from(attrs())
+                .lazer(false)
+                .n300(300)
+                .n100(20)
+                .n50(5)
+                .misses(2)
+                .hitresult_priority(priority)
+                .generate_state()
+                .unwrap()
+        };
+
+        let balanced = state(HitResultPriority::Balanced);
+
+        assert_eq!(balanced, state(HitResultPriority::BestCase));
+        assert_eq!(balanced.n100, 20);
+        assert_eq!(balanced.n50, 5);
+        assert_eq!(balanced.total_hits(), N_OBJECTS);
+    }
+
     #[test]
     fn hitresults_n50_misses_worst() {
         let state = OsuPerformance::from(attrs())
@@ -1270,6 +1990,162 @@ mod test {
         assert_eq!(state, expected);
     }
 
+    #[test]
+    fn default_performance_base_multiplier() {
+        let explicit = Difficulty::new()
+            .performance_base_multiplier(PERFORMANCE_BASE_MULTIPLIER)
+            .calculate_for_mode::<Osu>(&beatmap())
+            .unwrap();
+
+        assert_eq!(explicit, attrs());
+
+        let default = OsuPerformance::from(attrs()).calculate().unwrap();
+
+        let explicit = OsuPerformance::from(attrs())
+            .difficulty(Difficulty::new().performance_base_multiplier(PERFORMANCE_BASE_MULTIPLIER))
+            .calculate()
+            .unwrap();
+
+        assert_eq!(explicit, default);
+    }
+
+    #[test]
+    fn scaled_performance_base_multiplier() {
+        let default = OsuPerformance::from(attrs()).calculate().unwrap();
+
+        let doubled = OsuPerformance::from(attrs())
+            .difficulty(
+                Difficulty::new().performance_base_multiplier(2.0 * PERFORMANCE_BASE_MULTIPLIER),
+            )
+            .calculate()
+            .unwrap();
+
+        assert!((doubled.pp - 2.0 * default.pp).abs() < 1e-9);
+    }
+
+    #[test]
+    fn no_disabled_skills() {
+        let inspect = Difficulty::new().inspect();
+        assert!(inspect.disabled_skills.is_empty());
+
+        let roundtrip = inspect
+            .into_difficulty()
+            .calculate_for_mode::<Osu>(&beatmap())
+            .unwrap();
+
+        assert_eq!(roundtrip, attrs());
+    }
+
+    #[test]
+    fn disabled_skills() {
+        let expected = attrs();
+
+        let difficulty = Difficulty::new()
+            .mods(1024)
+            .disable_skill(Skill::Speed)
+            .disable_skill(Skill::Flashlight);
+
+        assert_eq!(
+            difficulty.clone().inspect().disabled_skills,
+            [Skill::Speed, Skill::Flashlight]
+        );
+
+        let aim_only = difficulty.calculate_for_mode::<Osu>(&beatmap()).unwrap();
+
+        assert!((aim_only.aim - expected.aim).abs() < f64::EPSILON);
+        assert!(aim_only.speed.abs() < f64::EPSILON);
+        assert!(aim_only.flashlight.abs() < f64::EPSILON);
+        assert!(aim_only.stars < expected.stars);
+    }
+
+    #[test]
+    fn slider_breaks_effective_miss_count() {
+        let attrs = OsuPerformance::from(attrs())
+            .combo(300)
+            .misses(2)
+            .slider_breaks(3)
+            .calculate()
+            .unwrap();
+
+        assert!((attrs.effective_miss_count - 5.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn marginal_miss_cost() {
+        let calc = OsuPerformance::from(attrs()).accuracy(97.0);
+        let costs = calc.clone().marginal_miss_cost(10).unwrap();
+
+        assert_eq!(costs.len(), 10);
+
+        let no_miss = calc.clone().misses(0).calculate().unwrap().pp;
+        let ten_misses = calc.misses(10).calculate().unwrap().pp;
+        let total: f64 = costs.iter().sum();
+
+        assert!((total - (no_miss - ten_misses)).abs() < 1e-9);
+        assert!(costs.iter().all(|&cost| cost > 0.0));
+    }
+
+    #[test]
+    fn is_fc_with_dropped_tails() {
+        let attrs = attrs();
+
+        let play = OsuPerformance::from(attrs.clone())
+            .lazer(true)
+            .slider_end_hits(N_SLIDERS - 3)
+            .combo(attrs.max_combo - 3)
+            .misses(0);
+
+        let mut fc = play.clone();
+
+        assert!(fc.generate_state().unwrap().is_fc(&attrs));
+        assert!(fc.is_fc().unwrap());
+
+        let mut choke = play.combo(attrs.max_combo - 4);
+
+        assert!(!choke.generate_state().unwrap().is_fc(&attrs));
+        assert!(!choke.is_fc().unwrap());
+    }
+
+    #[test]
+    fn recalculate_with_modified_attributes() {
+        let perf = OsuPerformance::from(attrs())
+            .lazer(false)
+            .mods(128 + 64) // RX, DT
+            .accuracy(96.0)
+            .misses(2)
+            .slider_breaks(1);
+
+        let attrs = perf.clone().calculate().unwrap();
+        let harder_aim = perf.recalculate_with(|attrs| attrs.aim *= 1.1).unwrap();
+
+        assert!(harder_aim.pp_aim > attrs.pp_aim);
+        assert_eq!(harder_aim.pp_speed.to_bits(), attrs.pp_speed.to_bits());
+        assert_eq!(
+            harder_aim.effective_miss_count.to_bits(),
+            attrs.effective_miss_count.to_bits()
+        );
+        assert_eq!(harder_aim.accuracy.to_bits(), attrs.accuracy.to_bits());
+    }
+
+    #[test]
+    fn max_pp_and_pp_at() {
+        let map = beatmap();
+        let attrs = Difficulty::new()
+            .mods(8 + 16) // HDHR
+            .calculate_for_mode::<Osu>(&map)
+            .unwrap();
+
+        let ss = OsuPerformance::new(&map).mods(8 + 16).calculate().unwrap();
+        assert!((attrs.max_pp(8 + 16) - ss.pp).abs() < f64::EPSILON);
+
+        let acc = OsuPerformance::new(&map)
+            .mods(8 + 16)
+            .accuracy(99.0)
+            .calculate()
+            .unwrap();
+        assert!((attrs.pp_at(99.0, 8 + 16) - acc.pp).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn create() {
         let mut map = beatmap();
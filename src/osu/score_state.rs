@@ -1,7 +1,10 @@
 use crate::util::{float_ext::FloatExt, hint::unlikely};
 
+use super::{performance::full_combo_threshold, OsuDifficultyAttributes};
+
 /// Aggregation for a score's current state.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OsuScoreState {
     /// Maximum combo that the score has had so far. **Not** the maximum
     /// possible combo of the map so far.
@@ -58,6 +61,19 @@ impl OsuScoreState {
         self.n300 + self.n100 + self.n50 + self.misses
     }
 
+    /// Whether the state is a full combo on a map with the given attributes.
+    ///
+    /// Dropped slider tails don't break combo so a score without misses
+    /// whose combo is only missing the combo of dropped tails is still
+    /// considered a full combo. This uses the slider end hits of the state
+    /// and is thus only suitable for osu!lazer scores with slider accuracy.
+    /// For other scores, use [`OsuPerformance::is_fc`].
+    ///
+    /// [`OsuPerformance::is_fc`]: crate::osu::OsuPerformance::is_fc
+    pub fn is_fc(&self, attrs: &OsuDifficultyAttributes) -> bool {
+        self.misses == 0 && f64::from(self.max_combo) >= full_combo_threshold(attrs, self, false)
+    }
+
     /// Calculate the accuracy between `0.0` and `1.0` for this state.
     pub fn accuracy(&self, origin: OsuScoreOrigin) -> f64 {
         let mut numerator = f64::from(6 * self.n300 + 2 * self.n100 + self.n50);
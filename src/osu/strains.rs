@@ -24,8 +24,25 @@ impl OsuStrains {
     pub const SECTION_LEN: f64 = 400.0;
 }
 
+/// The strains of an osu! hitobject.
+///
+/// Suitable to find difficulty spikes within a map.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OsuObjectStrain {
+    /// The start time of the hitobject in ms, unaffected by the clock rate.
+    pub start_time: f64,
+    /// Strain of the aim skill after processing the hitobject.
+    pub aim: f64,
+    /// Strain of the speed skill after processing the hitobject.
+    pub speed: f64,
+    /// Strain of the flashlight skill after processing the hitobject.
+    pub flashlight: f64,
+}
+
 pub fn strains(difficulty: &Difficulty, map: &Beatmap) -> Result<OsuStrains, ConvertError> {
     let map = map.convert_ref(GameMode::Osu, difficulty.get_mods())?;
+    let map = difficulty.apply_slider_overrides(map);
+    let map = difficulty.apply_time_range(map);
 
     let DifficultyValues {
         skills:
@@ -45,3 +62,41 @@ pub fn strains(difficulty: &Difficulty, map: &Beatmap) -> Result<OsuStrains, Con
         flashlight: flashlight.into_current_strain_peaks().into_vec(),
     })
 }
+
+pub fn object_strains(
+    difficulty: &Difficulty,
+    map: &Beatmap,
+) -> Result<Vec<OsuObjectStrain>, ConvertError> {
+    let map = map.convert_ref(GameMode::Osu, difficulty.get_mods())?;
+    let map = difficulty.apply_slider_overrides(map);
+    let map = difficulty.apply_time_range(map);
+
+    let DifficultyValues {
+        skills:
+            OsuSkills {
+                aim,
+                speed,
+                flashlight,
+                ..
+            },
+        attrs: _,
+    } = DifficultyValues::calculate(difficulty, &map);
+
+    // The first hit object has no difficulty object
+    let object_strains = map
+        .hit_objects
+        .iter()
+        .skip(1)
+        .zip(aim.object_strains())
+        .zip(speed.object_strains())
+        .zip(flashlight.object_strains())
+        .map(|(((h, &aim), &speed), &flashlight)| OsuObjectStrain {
+            start_time: h.start_time,
+            aim,
+            speed,
+            flashlight,
+        })
+        .collect();
+
+    Ok(object_strains)
+}
@@ -0,0 +1,107 @@
+use super::OsuDifficultyAttributes;
+
+/// The predominant style of an osu!standard map.
+///
+/// See [`classify_osu`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MapStyle {
+    /// Mostly aim-heavy jumps.
+    Jump,
+    /// Mostly streams.
+    Stream,
+    /// Streams that are dense enough to require alternating.
+    Alt,
+    /// Slider-heavy aim.
+    Tech,
+    /// Neither aim nor speed stand out.
+    Balanced,
+}
+
+/// Sliders account for more than this portion of the aim rating.
+const TECH_SLIDER_FACTOR: f64 = 0.85;
+/// Aim rating exceeds speed rating by at least this factor.
+const JUMP_RATIO: f64 = 1.1;
+/// Speed rating exceeds aim rating by at least this factor.
+const STREAM_RATIO: f64 = 1.1;
+/// Speed rating exceeds aim rating by at least this factor.
+const ALT_RATIO: f64 = 1.3;
+
+/// Classify an osu!standard map based on its difficulty attributes.
+///
+/// This is a heuristic that compares skill ratings:
+/// - [`MapStyle::Tech`] if sliders make up a considerable part of the aim
+///   rating, i.e. the slider factor is below `0.85`.
+/// - [`MapStyle::Jump`] if the aim rating is at least 10% above the speed
+///   rating.
+/// - [`MapStyle::Alt`] if the speed rating is at least 30% above the aim
+///   rating.
+/// - [`MapStyle::Stream`] if the speed rating is at least 10% above the aim
+///   rating.
+/// - [`MapStyle::Balanced`] otherwise.
+///
+/// Since only the attributes are considered, neither the angle distribution
+/// nor the rhythm complexity of the map play a role. Maps with a similar
+/// ratio of skill ratings are classified the same regardless of e.g. their
+/// jump angles; see [`aim_pattern_histogram`] to compare those instead.
+///
+/// # Example
+///
+/// ```
+/// use rosu_pp::{
+///     osu::{classify_osu, MapStyle, Osu},
+///     Beatmap, Difficulty,
+/// };
+///
+/// let map = Beatmap::from_path("./resources/2785319.osu").unwrap();
+/// let attrs = Difficulty::new().calculate_for_mode::<Osu>(&map).unwrap();
+///
+/// assert_eq!(classify_osu(&attrs), MapStyle::Jump);
+/// ```
+///
+/// [`aim_pattern_histogram`]: super::aim_pattern_histogram
+pub fn classify_osu(attrs: &OsuDifficultyAttributes) -> MapStyle {
+    if attrs.aim <= 0.0 || attrs.speed <= 0.0 {
+        return MapStyle::Balanced;
+    }
+
+    if attrs.slider_factor < TECH_SLIDER_FACTOR {
+        return MapStyle::Tech;
+    }
+
+    let aim_ratio = attrs.aim / attrs.speed;
+    let speed_ratio = attrs.speed / attrs.aim;
+
+    if aim_ratio >= JUMP_RATIO {
+        MapStyle::Jump
+    } else if speed_ratio >= ALT_RATIO {
+        MapStyle::Alt
+    } else if speed_ratio >= STREAM_RATIO {
+        MapStyle::Stream
+    } else {
+        MapStyle::Balanced
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attrs(aim: f64, speed: f64, slider_factor: f64) -> OsuDifficultyAttributes {
+        OsuDifficultyAttributes {
+            aim,
+            speed,
+            slider_factor,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn styles() {
+        assert_eq!(classify_osu(&attrs(3.0, 2.5, 0.98)), MapStyle::Jump);
+        assert_eq!(classify_osu(&attrs(2.5, 2.8, 0.98)), MapStyle::Stream);
+        assert_eq!(classify_osu(&attrs(2.0, 3.0, 0.98)), MapStyle::Alt);
+        assert_eq!(classify_osu(&attrs(3.0, 2.5, 0.7)), MapStyle::Tech);
+        assert_eq!(classify_osu(&attrs(2.5, 2.6, 0.98)), MapStyle::Balanced);
+        assert_eq!(classify_osu(&attrs(0.0, 0.0, 1.0)), MapStyle::Balanced);
+    }
+}
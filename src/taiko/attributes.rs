@@ -1,7 +1,10 @@
-use crate::taiko::performance::TaikoPerformance;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::{taiko::performance::TaikoPerformance, GameMods};
 
 /// The result of a difficulty calculation on an osu!taiko map.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TaikoDifficultyAttributes {
     /// The difficulty of the stamina skill.
     pub stamina: f64,
@@ -18,6 +21,11 @@ pub struct TaikoDifficultyAttributes {
     /// The ratio of stamina difficulty from mono-color (single color) streams to total
     /// stamina difficulty.
     pub mono_stamina_factor: f64,
+    /// The multiplier applied to rhythm strains based on the stamina and
+    /// color ratings.
+    pub pattern_multiplier: f64,
+    /// The bonus applied to stamina strains for long and stamina-heavy maps.
+    pub strain_length_bonus: f64,
     /// The final star rating.
     pub stars: f64,
     /// The maximum combo.
@@ -41,14 +49,76 @@ impl TaikoDifficultyAttributes {
         self.is_convert
     }
 
+    /// The skill ratings and multipliers that make up the star rating.
+    ///
+    /// Useful to explain which skills a map's difficulty stems from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{taiko::Taiko, Beatmap, Difficulty};
+    ///
+    /// let map = Beatmap::from_path("./resources/1028484.osu").unwrap();
+    /// let attrs = Difficulty::new().calculate_for_mode::<Taiko>(&map).unwrap();
+    ///
+    /// let breakdown = attrs.breakdown();
+    ///
+    /// assert!(breakdown.stamina > 0.0);
+    /// assert!((0.0..=1.0).contains(&breakdown.mono_stamina_factor));
+    /// ```
+    pub const fn breakdown(&self) -> TaikoDifficultyBreakdown {
+        TaikoDifficultyBreakdown {
+            stamina: self.stamina,
+            rhythm: self.rhythm,
+            color: self.color,
+            reading: self.reading,
+            mono_stamina_factor: self.mono_stamina_factor,
+            pattern_multiplier: self.pattern_multiplier,
+            strain_length_bonus: self.strain_length_bonus,
+        }
+    }
+
     /// Returns a builder for performance calculation.
     pub fn performance<'a>(self) -> TaikoPerformance<'a> {
         self.into()
     }
 }
 
+/// The components of an osu!taiko star rating.
+///
+/// The skill ratings already include their skill multiplier but not the
+/// pattern multiplier or strain length bonus, which are applied per
+/// section before the ratings are combined into the star rating.
+///
+/// See [`TaikoDifficultyAttributes::breakdown`].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct TaikoDifficultyBreakdown {
+    /// The rating of the stamina skill.
+    pub stamina: f64,
+    /// The rating of the rhythm skill.
+    pub rhythm: f64,
+    /// The rating of the color skill.
+    pub color: f64,
+    /// The rating of the reading skill.
+    pub reading: f64,
+    /// The ratio of stamina difficulty from mono-color streams to total
+    /// stamina difficulty, raised to the 5th power.
+    ///
+    /// Close to `1.0` for maps whose stamina difficulty mostly stems from
+    /// single color streams. The performance calculation scales accuracy
+    /// more harshly the higher this factor is.
+    pub mono_stamina_factor: f64,
+    /// The multiplier applied to rhythm strains based on the stamina and
+    /// color ratings.
+    pub pattern_multiplier: f64,
+    /// The multiplier applied to stamina strains for long and stamina-heavy
+    /// maps.
+    pub strain_length_bonus: f64,
+}
+
 /// The result of a performance calculation on an osu!taiko map.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TaikoPerformanceAttributes {
     /// The difficulty attributes that were used for the performance calculation
     pub difficulty: TaikoDifficultyAttributes,
@@ -62,6 +132,8 @@ pub struct TaikoPerformanceAttributes {
     pub effective_miss_count: f64,
     /// Upper bound on the player's tap deviation.
     pub estimated_unstable_rate: Option<f64>,
+    /// The accuracy of the score between `0.0` and `1.0`.
+    pub accuracy: f64,
 }
 
 impl TaikoPerformanceAttributes {
@@ -75,6 +147,11 @@ impl TaikoPerformanceAttributes {
         self.pp
     }
 
+    /// Return the accuracy of the score between `0.0` and `1.0`.
+    pub const fn accuracy(&self) -> f64 {
+        self.accuracy
+    }
+
     /// Return the maximum combo of the map.
     pub const fn max_combo(&self) -> u32 {
         self.difficulty.max_combo
@@ -91,6 +168,51 @@ impl TaikoPerformanceAttributes {
     pub fn performance<'a>(self) -> TaikoPerformance<'a> {
         self.difficulty.into()
     }
+
+    /// The accuracy portion of the pp, i.e. [`pp_acc`], for a score with
+    /// the given accuracy between `0.0` and `100.0` and no misses.
+    ///
+    /// Only the accuracy value is recalculated based on the difficulty
+    /// attributes so this is much cheaper than a full calculation, e.g. when
+    /// sweeping over many accuracies. Mods are required since HD and FL
+    /// affect the value.
+    ///
+    /// [`pp_acc`]: TaikoPerformanceAttributes::pp_acc
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rosu_pp::{taiko::TaikoPerformance, Beatmap};
+    ///
+    /// let map = Beatmap::from_path("./resources/1028484.osu").unwrap();
+    /// let attrs = TaikoPerformance::new(&map).calculate().unwrap();
+    ///
+    /// assert!(attrs.acc_pp_at(99.0, 0) > attrs.acc_pp_at(95.0, 0));
+    /// ```
+    pub fn acc_pp_at(&self, acc: f64, mods: impl Into<GameMods>) -> f64 {
+        TaikoPerformance::from(self.difficulty.clone())
+            .mods(mods)
+            .accuracy(acc)
+            .accuracy_value()
+            // Calculating with attributes cannot fail
+            .unwrap_or_default()
+    }
+}
+
+impl Display for TaikoPerformanceAttributes {
+    /// Format a one-line summary, e.g.
+    /// `312.47pp (difficulty 250.12, acc 58.61) 98.50% 5.41*`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{:.2}pp (difficulty {:.2}, acc {:.2}) {:.2}% {:.2}*",
+            self.pp,
+            self.pp_difficulty,
+            self.pp_acc,
+            self.accuracy * 100.0,
+            self.stars(),
+        )
+    }
 }
 
 impl From<TaikoPerformanceAttributes> for TaikoDifficultyAttributes {
@@ -1,3 +1,4 @@
 pub mod color_data;
 pub mod data;
 pub mod preprocessor;
+pub mod runs;
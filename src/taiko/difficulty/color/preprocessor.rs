@@ -64,7 +64,7 @@ impl ColorDifficultyPreprocessor {
         Self::encode_repeating_hit_patterns(alternating_mono_patterns)
     }
 
-    fn encode_mono_streaks(data: &TaikoDifficultyObjects) -> Vec<RefCount<MonoStreak>> {
+    pub fn encode_mono_streaks(data: &TaikoDifficultyObjects) -> Vec<RefCount<MonoStreak>> {
         let mut data_iter = data.objects.iter();
 
         let Some(taiko_object) = data_iter.next() else {
@@ -179,9 +179,9 @@ impl ColorDifficultyPreprocessor {
             hit_patterns.push(RefCount::clone(curr_hit_pattern));
         }
 
-        hit_patterns
-            .iter_mut()
-            .for_each(|pattern| pattern.get_mut().find_repetition_interval());
+        for pattern in hit_patterns.iter_mut() {
+            pattern.get_mut().find_repetition_interval();
+        }
 
         hit_patterns
     }
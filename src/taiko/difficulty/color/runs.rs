@@ -0,0 +1,93 @@
+use rosu_map::section::general::GameMode;
+
+use crate::{
+    model::mode::ConvertError,
+    taiko::{convert, difficulty::DifficultyValues, object::HitType},
+    util::sync::Weak,
+    Beatmap, Difficulty,
+};
+
+use super::preprocessor::ColorDifficultyPreprocessor;
+
+/// The color of an osu!taiko note.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TaikoColor {
+    /// A center hit, i.e. a red note.
+    Don,
+    /// A rim hit, i.e. a blue note.
+    Kat,
+}
+
+/// A run of consecutive notes of the same color as detected by the color
+/// evaluation of the osu!taiko difficulty calculation.
+///
+/// See [`Difficulty::taiko_color_patterns`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TaikoColorRun {
+    /// The start time of the run's first note in ms, unaffected by the clock
+    /// rate.
+    pub start_time: f64,
+    /// The color of the run's notes.
+    pub color: TaikoColor,
+    /// The amount of notes in the run.
+    pub len: usize,
+}
+
+pub fn color_runs(
+    difficulty: &Difficulty,
+    map: &Beatmap,
+) -> Result<Vec<TaikoColorRun>, ConvertError> {
+    let map = map.convert_ref(GameMode::Taiko, difficulty.get_mods())?;
+    let mut map = difficulty.apply_time_range(map);
+
+    if let Some(seed) = difficulty.get_mods().random_seed() {
+        convert::apply_random_to_beatmap(map.to_mut(), seed);
+    }
+
+    let mut n_diff_objects = 0;
+    let mut max_combo = 0;
+
+    let diff_objects = DifficultyValues::create_difficulty_objects(
+        &map,
+        difficulty.get_passed_objects() as u32,
+        difficulty.get_clock_rate(),
+        &mut max_combo,
+        &mut n_diff_objects,
+        difficulty.get_mods(),
+    );
+
+    // The first hit object has no difficulty object
+    n_diff_objects = n_diff_objects.saturating_sub(1);
+
+    let runs = ColorDifficultyPreprocessor::encode_mono_streaks(&diff_objects)
+        .iter()
+        .filter_map(|mono_streak| {
+            let mut notes = mono_streak
+                .get()
+                .hit_objects
+                .iter()
+                .filter_map(Weak::upgrade)
+                .filter(|h| h.get().base_hit_type.is_hit() && h.get().idx < n_diff_objects)
+                .collect::<Vec<_>>()
+                .into_iter();
+
+            let first = notes.next()?;
+            let first = first.get();
+
+            let color = match first.base_hit_type {
+                HitType::Center => TaikoColor::Don,
+                HitType::Rim => TaikoColor::Kat,
+                HitType::NonHit => unreachable!(),
+            };
+
+            Some(TaikoColorRun {
+                // Difficulty objects start at the third hit object
+                start_time: map.hit_objects[first.idx + 2].start_time,
+                color,
+                len: notes.len() + 1,
+            })
+        })
+        .collect();
+
+    Ok(runs)
+}
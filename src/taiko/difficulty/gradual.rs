@@ -6,11 +6,15 @@ use crate::{
     any::difficulty::skills::StrainSkill,
     model::{beatmap::HitWindows, hit_object::HitObject, mode::ConvertError},
     taiko::convert,
-    util::sync::RefCount,
+    util::{
+        snapshots::{Rewind, Snapshots},
+        sync::RefCount,
+    },
     Beatmap, Difficulty,
 };
 
 use super::{
+    combined_strain,
     object::{TaikoDifficultyObject, TaikoDifficultyObjects},
     skills::TaikoSkills,
     DifficultyValues, TaikoDifficultyAttributes,
@@ -57,6 +61,13 @@ pub struct TaikoGradualDifficulty {
     skills: TaikoSkills,
     total_hits: usize,
     first_combos: FirstTwoCombos,
+    snapshots: Snapshots<Snapshot>,
+}
+
+struct Snapshot {
+    attrs: TaikoDifficultyAttributes,
+    skills: <TaikoSkills as Rewind>::Snapshot,
+    diff_objects_iter: Iter<'static, RefCount<TaikoDifficultyObject>>,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -70,7 +81,8 @@ enum FirstTwoCombos {
 impl TaikoGradualDifficulty {
     /// Create a new difficulty attributes iterator for osu!taiko maps.
     pub fn new(difficulty: Difficulty, map: &Beatmap) -> Result<Self, ConvertError> {
-        let mut map = map.convert_ref(GameMode::Taiko, difficulty.get_mods())?;
+        let map = map.convert_ref(GameMode::Taiko, difficulty.get_mods())?;
+        let mut map = difficulty.apply_time_range(map);
 
         if let Some(seed) = difficulty.get_mods().random_seed() {
             convert::apply_random_to_beatmap(map.to_mut(), seed);
@@ -121,6 +133,12 @@ impl TaikoGradualDifficulty {
 
         let diff_objects_iter = extend_lifetime(diff_objects.iter());
 
+        let snapshots = Snapshots::new(Snapshot {
+            attrs: attrs.clone(),
+            skills: skills.snapshot(),
+            diff_objects_iter: diff_objects_iter.clone(),
+        });
+
         Ok(Self {
             idx: 0,
             difficulty,
@@ -130,21 +148,64 @@ impl TaikoGradualDifficulty {
             attrs,
             total_hits,
             first_combos,
+            snapshots,
         })
     }
-}
 
-fn extend_lifetime(
-    iter: Iter<'_, RefCount<TaikoDifficultyObject>>,
-) -> Iter<'static, RefCount<TaikoDifficultyObject>> {
-    // SAFETY: The underlying data will never be moved.
-    unsafe { mem::transmute(iter) }
-}
+    /// Restore the latest snapshot for at most `idx` processed objects if
+    /// `idx` objects were already processed.
+    ///
+    /// From then on, snapshots are stored periodically while processing.
+    pub(crate) fn rewind(&mut self, idx: usize) {
+        self.snapshots.enable();
 
-impl Iterator for TaikoGradualDifficulty {
-    type Item = TaikoDifficultyAttributes;
+        if idx >= self.idx {
+            return;
+        }
 
-    fn next(&mut self) -> Option<Self::Item> {
+        let Some((snapshot_idx, snapshot)) = self.snapshots.get(idx) else {
+            return;
+        };
+
+        self.idx = snapshot_idx;
+        self.attrs = snapshot.attrs.clone();
+        self.skills.rewind(&snapshot.skills);
+        self.diff_objects_iter = snapshot.diff_objects_iter.clone();
+        self.snapshots.truncate(snapshot_idx);
+    }
+
+    /// Process the next hit object and return its combined strain without
+    /// evaluating the difficulty attributes.
+    ///
+    /// Map-wide bonuses such as the strain length bonus are not applied.
+    pub(crate) fn next_strain(&mut self) -> Option<f64> {
+        self.process_next()?;
+
+        if self.idx < 3 {
+            return Some(0.0);
+        }
+
+        let last = |strains: &[f64]| strains.last().copied().unwrap_or(0.0);
+
+        let strains = [
+            last(self.skills.rhythm.object_strains()),
+            last(self.skills.reading.object_strains()),
+            last(self.skills.color.object_strains()),
+            last(self.skills.stamina.object_strains()),
+        ];
+
+        let is_relax = self.difficulty.get_mods().rx();
+
+        Some(combined_strain(
+            strains,
+            is_relax,
+            self.attrs.is_convert,
+            1.0,
+            1.0,
+        ))
+    }
+
+    fn process_next(&mut self) -> Option<()> {
         // The first difficulty object belongs to the third note since each
         // difficulty object requires the current, the last, and the second to
         // last note. Hence, if we're still on the first or second object, we
@@ -181,6 +242,32 @@ impl Iterator for TaikoGradualDifficulty {
         }
 
         self.idx += 1;
+        self.store_snapshot();
+
+        Some(())
+    }
+
+    fn store_snapshot(&mut self) {
+        self.snapshots.store(self.idx, || Snapshot {
+            attrs: self.attrs.clone(),
+            skills: self.skills.snapshot(),
+            diff_objects_iter: self.diff_objects_iter.clone(),
+        });
+    }
+}
+
+fn extend_lifetime(
+    iter: Iter<'_, RefCount<TaikoDifficultyObject>>,
+) -> Iter<'static, RefCount<TaikoDifficultyObject>> {
+    // SAFETY: The underlying data will never be moved.
+    unsafe { mem::transmute(iter) }
+}
+
+impl Iterator for TaikoGradualDifficulty {
+    type Item = TaikoDifficultyAttributes;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.process_next()?;
 
         let mut attrs = self.attrs.clone();
         let is_relax = self.difficulty.get_mods().rx();
@@ -256,6 +343,8 @@ impl Iterator for TaikoGradualDifficulty {
                     break;
                 }
             }
+
+            self.store_snapshot();
         }
 
         self.next()
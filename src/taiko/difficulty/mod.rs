@@ -20,6 +20,8 @@ use crate::{
 
 pub(crate) use self::skills::TaikoSkills;
 
+pub use self::color::runs::{color_runs, TaikoColor, TaikoColorRun};
+
 use super::{attributes::TaikoDifficultyAttributes, convert};
 
 mod color;
@@ -39,7 +41,8 @@ pub fn difficulty(
     difficulty: &Difficulty,
     map: &Beatmap,
 ) -> Result<TaikoDifficultyAttributes, ConvertError> {
-    let mut map = map.convert_ref(GameMode::Taiko, difficulty.get_mods())?;
+    let map = map.convert_ref(GameMode::Taiko, difficulty.get_mods())?;
+    let mut map = difficulty.apply_time_range(map);
 
     if let Some(seed) = difficulty.get_mods().random_seed() {
         convert::apply_random_to_beatmap(map.to_mut(), seed);
@@ -101,31 +104,13 @@ fn combined_difficulty_value(
         .zip(color_peaks.iter())
         .zip(stamina_peaks.iter());
 
-    for (((mut rhythm_peak, mut reading_peak), mut color_peak), mut stamina_peak) in iter {
-        rhythm_peak *= RHYTHM_SKILL_MULTIPLIER;
-        rhythm_peak *= pattern_multiplier;
-
-        reading_peak *= READING_SKILL_MULTIPLIER;
-
-        color_peak *= if is_relax {
-            0.0 // * There is no colour difficulty in relax.
-        } else {
-            COLOR_SKILL_MULTIPLIER
-        };
-
-        stamina_peak *= STAMINA_SKILL_MULTIPLIER;
-        stamina_peak *= strain_length_bonus;
-
-        // * Available finger count is increased by 150%, thus we adjust accordingly.
-        stamina_peak /= if is_convert || is_relax { 1.5 } else { 1.0 };
-
-        let peak = norm(
-            2.0,
-            [
-                norm(1.5, [color_peak, stamina_peak]),
-                rhythm_peak,
-                reading_peak,
-            ],
+    for (((rhythm_peak, reading_peak), color_peak), stamina_peak) in iter {
+        let peak = combined_strain(
+            [rhythm_peak, reading_peak, color_peak, stamina_peak],
+            is_relax,
+            is_convert,
+            pattern_multiplier,
+            strain_length_bonus,
         );
 
         // * Sections with 0 strain are excluded to avoid worst-case time complexity of the following sort (e.g. /b/2351871).
@@ -148,6 +133,35 @@ fn combined_difficulty_value(
     difficulty
 }
 
+/// Combine the rhythm, reading, color, and stamina strain of a section or
+/// object.
+fn combined_strain(
+    [mut rhythm, mut reading, mut color, mut stamina]: [f64; 4],
+    is_relax: bool,
+    is_convert: bool,
+    pattern_multiplier: f64,
+    strain_length_bonus: f64,
+) -> f64 {
+    rhythm *= RHYTHM_SKILL_MULTIPLIER;
+    rhythm *= pattern_multiplier;
+
+    reading *= READING_SKILL_MULTIPLIER;
+
+    color *= if is_relax {
+        0.0 // * There is no colour difficulty in relax.
+    } else {
+        COLOR_SKILL_MULTIPLIER
+    };
+
+    stamina *= STAMINA_SKILL_MULTIPLIER;
+    stamina *= strain_length_bonus;
+
+    // * Available finger count is increased by 150%, thus we adjust accordingly.
+    stamina /= if is_convert || is_relax { 1.5 } else { 1.0 };
+
+    norm(2.0, [norm(1.5, [color, stamina]), rhythm, reading])
+}
+
 fn rescale(stars: f64) -> f64 {
     if stars < 0.0 {
         stars
@@ -252,6 +266,8 @@ impl DifficultyValues {
         attrs.color = color_rating;
         attrs.stamina = stamina_rating;
         attrs.mono_stamina_factor = mono_stamina_factor;
+        attrs.pattern_multiplier = pattern_multiplier;
+        attrs.strain_length_bonus = strain_length_bonus;
         attrs.stars = star_rating;
     }
 
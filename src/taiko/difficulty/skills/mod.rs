@@ -1,5 +1,7 @@
 use reading::Reading;
 
+use crate::util::snapshots::Rewind;
+
 use self::{color::Color, rhythm::Rhythm, stamina::Stamina};
 
 pub mod color;
@@ -27,3 +29,31 @@ impl TaikoSkills {
         }
     }
 }
+
+impl Rewind for TaikoSkills {
+    type Snapshot = (
+        <Rhythm as Rewind>::Snapshot,
+        <Reading as Rewind>::Snapshot,
+        <Color as Rewind>::Snapshot,
+        <Stamina as Rewind>::Snapshot,
+        <Stamina as Rewind>::Snapshot,
+    );
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (
+            self.rhythm.snapshot(),
+            self.reading.snapshot(),
+            self.color.snapshot(),
+            self.stamina.snapshot(),
+            self.single_color_stamina.snapshot(),
+        )
+    }
+
+    fn rewind(&mut self, (rhythm, reading, color, stamina, single_color_stamina): &Self::Snapshot) {
+        self.rhythm.rewind(rhythm);
+        self.reading.rewind(reading);
+        self.color.rewind(color);
+        self.stamina.rewind(stamina);
+        self.single_color_stamina.rewind(single_color_stamina);
+    }
+}
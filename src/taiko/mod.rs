@@ -8,9 +8,11 @@ use crate::{
     Difficulty,
 };
 
+use self::object::TaikoObject;
+
 pub use self::{
-    attributes::{TaikoDifficultyAttributes, TaikoPerformanceAttributes},
-    difficulty::gradual::TaikoGradualDifficulty,
+    attributes::{TaikoDifficultyAttributes, TaikoDifficultyBreakdown, TaikoPerformanceAttributes},
+    difficulty::{gradual::TaikoGradualDifficulty, TaikoColor, TaikoColorRun},
     performance::{gradual::TaikoGradualPerformance, TaikoPerformance},
     score_state::TaikoScoreState,
     strains::TaikoStrains,
@@ -34,6 +36,23 @@ impl Taiko {
         debug_assert!(!map.is_convert && map.mode == GameMode::Osu);
         convert::convert(map);
     }
+
+    /// The runs of same-colored notes of an osu!taiko [`Beatmap`].
+    pub(crate) fn color_runs(
+        difficulty: &Difficulty,
+        map: &Beatmap,
+    ) -> Result<Vec<TaikoColorRun>, ConvertError> {
+        difficulty::color_runs(difficulty, map)
+    }
+
+    /// The combo of each hitobject of an osu!taiko [`Beatmap`].
+    pub(crate) fn object_combo_values(map: &Beatmap) -> Vec<u32> {
+        map.hit_objects
+            .iter()
+            .zip(map.hit_sounds.iter())
+            .map(|(h, sound)| u32::from(TaikoObject::new(h, *sound).is_hit()))
+            .collect()
+    }
 }
 
 impl IGameMode for Taiko {
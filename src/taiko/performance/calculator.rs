@@ -8,6 +8,7 @@ pub(super) struct TaikoPerformanceCalculator<'mods> {
     attrs: TaikoDifficultyAttributes,
     mods: &'mods GameMods,
     state: TaikoScoreState,
+    miss_penalty: f64,
 }
 
 impl<'a> TaikoPerformanceCalculator<'a> {
@@ -15,8 +16,14 @@ impl<'a> TaikoPerformanceCalculator<'a> {
         attrs: TaikoDifficultyAttributes,
         mods: &'a GameMods,
         state: TaikoScoreState,
+        miss_penalty: f64,
     ) -> Self {
-        Self { attrs, mods, state }
+        Self {
+            attrs,
+            mods,
+            state,
+            miss_penalty,
+        }
     }
 }
 
@@ -57,9 +64,17 @@ impl TaikoPerformanceCalculator<'_> {
             pp_difficulty: diff_value,
             effective_miss_count,
             estimated_unstable_rate,
+            accuracy: self.state.accuracy(),
         }
     }
 
+    /// Only the accuracy portion of [`TaikoPerformanceCalculator::calculate`].
+    pub fn accuracy_value(&self) -> f64 {
+        let estimated_unstable_rate = self.compute_deviation_upper_bound().map(|v| v * 10.0);
+
+        self.compute_accuracy_value(estimated_unstable_rate)
+    }
+
     fn compute_difficulty_value(
         &self,
         effective_miss_count: f64,
@@ -82,7 +97,7 @@ impl TaikoPerformanceCalculator<'_> {
         let length_bonus = 1.0 + 0.1 * f64::min(1.0, f64::from(attrs.max_combo) / 1500.0);
         difficulty_value *= length_bonus;
 
-        difficulty_value *= f64::powf(0.986, effective_miss_count);
+        difficulty_value *= f64::powf(self.miss_penalty, effective_miss_count);
 
         if self.mods.ez() {
             difficulty_value *= 0.9;
@@ -136,25 +151,11 @@ impl TaikoPerformanceCalculator<'_> {
     // * and the hit judgements, assuming the player's mean hit error is 0. The estimation is consistent in that
     // * two SS scores on the same map with the same settings will always return the same deviation.
     fn compute_deviation_upper_bound(&self) -> Option<f64> {
-        if self.state.n300 == 0 || self.attrs.great_hit_window <= 0.0 {
-            return None;
-        }
-
-        #[allow(clippy::items_after_statements, clippy::unreadable_literal)]
-        // * 99% critical value for the normal distribution (one-tailed).
-        const Z: f64 = 2.32634787404;
-
-        let n = self.total_hits();
-
-        // * Proportion of greats hit.
-        let p = f64::from(self.state.n300) / n;
-
-        // * We can be 99% confident that p is at least this value.
-        let p_lower_bound = (n * p + Z * Z / 2.0) / (n + Z * Z)
-            - Z / (n + Z * Z) * f64::sqrt(n * p * (1.0 - p) + Z * Z / 4.0);
-
-        // * We can be 99% confident that the deviation is not higher than:
-        Some(self.attrs.great_hit_window / (f64::sqrt(2.0) * erf_inv(p_lower_bound)))
+        deviation_upper_bound(
+            self.attrs.great_hit_window,
+            self.state.n300,
+            self.total_hits(),
+        )
     }
 
     const fn total_hits(&self) -> f64 {
@@ -165,3 +166,32 @@ impl TaikoPerformanceCalculator<'_> {
         self.state.n300 + self.state.n100
     }
 }
+
+/// Upper bound on the tap deviation for `n300` greats out of `total_hits`.
+///
+/// See [`TaikoPerformanceCalculator::compute_deviation_upper_bound`].
+pub(super) fn deviation_upper_bound(
+    great_hit_window: f64,
+    n300: u32,
+    total_hits: f64,
+) -> Option<f64> {
+    if n300 == 0 || great_hit_window <= 0.0 {
+        return None;
+    }
+
+    #[allow(clippy::items_after_statements, clippy::unreadable_literal)]
+    // * 99% critical value for the normal distribution (one-tailed).
+    const Z: f64 = 2.32634787404;
+
+    let n = total_hits;
+
+    // * Proportion of greats hit.
+    let p = f64::from(n300) / n;
+
+    // * We can be 99% confident that p is at least this value.
+    let p_lower_bound = (n * p + Z * Z / 2.0) / (n + Z * Z)
+        - Z / (n + Z * Z) * f64::sqrt(n * p * (1.0 - p) + Z * Z / 4.0);
+
+    // * We can be 99% confident that the deviation is not higher than:
+    Some(great_hit_window / (f64::sqrt(2.0) * erf_inv(p_lower_bound)))
+}
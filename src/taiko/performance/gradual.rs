@@ -1,4 +1,5 @@
 use crate::{
+    any::performance::judgement::JudgementTotals,
     model::mode::ConvertError,
     taiko::{difficulty::gradual::TaikoGradualDifficulty, TaikoScoreState},
     Beatmap, Difficulty,
@@ -79,6 +80,7 @@ use super::TaikoPerformanceAttributes;
 /// [`nth`]: TaikoGradualPerformance::nth
 pub struct TaikoGradualPerformance {
     difficulty: TaikoGradualDifficulty,
+    pub(crate) totals: JudgementTotals,
 }
 
 impl TaikoGradualPerformance {
@@ -86,7 +88,10 @@ impl TaikoGradualPerformance {
     pub fn new(difficulty: Difficulty, map: &Beatmap) -> Result<Self, ConvertError> {
         let difficulty = TaikoGradualDifficulty::new(difficulty, map)?;
 
-        Ok(Self { difficulty })
+        Ok(Self {
+            difficulty,
+            totals: JudgementTotals::default(),
+        })
     }
 
     /// Process the next hit object and calculate the performance attributes
@@ -121,6 +126,28 @@ impl TaikoGradualPerformance {
         Some(performance)
     }
 
+    /// Process everything up to the hit object at index `idx` and calculate
+    /// the performance attributes for the resulting score state.
+    ///
+    /// Unlike [`nth`](Self::nth), `idx` is not relative to the current
+    /// position so already processed objects can be revisited. The result is
+    /// the same as calling `next` `idx + 1` times on a new instance.
+    ///
+    /// Returns `None` if `idx` is out of bounds.
+    pub fn at_index(
+        &mut self,
+        state: TaikoScoreState,
+        idx: usize,
+    ) -> Option<TaikoPerformanceAttributes> {
+        if idx >= self.difficulty.idx + self.difficulty.len() {
+            return None;
+        }
+
+        self.difficulty.rewind(idx);
+
+        self.nth(state, idx - self.difficulty.idx)
+    }
+
     /// Returns the amount of remaining objects.
     #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
@@ -2,7 +2,7 @@ use std::cmp;
 
 use rosu_map::section::general::GameMode;
 
-use self::calculator::TaikoPerformanceCalculator;
+use self::calculator::{deviation_upper_bound, TaikoPerformanceCalculator};
 
 use crate::{
     any::{Difficulty, HitResultPriority, IntoModePerformance, IntoPerformance},
@@ -25,13 +25,20 @@ pub struct TaikoPerformance<'map> {
     difficulty: Difficulty,
     combo: Option<u32>,
     acc: Option<f64>,
+    unstable_rate: Option<f64>,
     hitresult_priority: HitResultPriority,
     n300: Option<u32>,
     n100: Option<u32>,
     misses: Option<u32>,
+    miss_penalty: Option<f64>,
 }
 
 impl<'map> TaikoPerformance<'map> {
+    /// The default base of the miss penalty.
+    ///
+    /// See [`TaikoPerformance::miss_penalty`].
+    pub const DEFAULT_MISS_PENALTY: f64 = 0.986;
+
     /// Create a new performance calculator for osu!taiko maps.
     ///
     /// The argument `map_or_attrs` must be either
@@ -124,6 +131,23 @@ impl<'map> TaikoPerformance<'map> {
         self
     }
 
+    /// Specify the base of the miss penalty that scales the difficulty value,
+    /// i.e. the difficulty value is multiplied by
+    /// `miss_penalty ^ effective_miss_count`.
+    ///
+    /// Lower values punish misses more harshly.
+    ///
+    /// Defaults to [`TaikoPerformance::DEFAULT_MISS_PENALTY`].
+    ///
+    /// | Minimum | Maximum |
+    /// | :-----: | :-----: |
+    /// | 0       | 1       |
+    pub const fn miss_penalty(mut self, miss_penalty: f64) -> Self {
+        self.miss_penalty = Some(miss_penalty.clamp(0.0, 1.0));
+
+        self
+    }
+
     /// Specify the accuracy of a play between `0.0` and `100.0`.
     /// This will be used to generate matching hitresults.
     pub fn accuracy(mut self, acc: f64) -> Self {
@@ -132,6 +156,21 @@ impl<'map> TaikoPerformance<'map> {
         self
     }
 
+    /// Specify the unstable rate of a play.
+    ///
+    /// This will be used to generate hitresults whose estimated unstable rate
+    /// matches the given one as closely as possible. It only has an effect if
+    /// neither `n300` nor `n100` are specified, in which case it takes
+    /// precedence over [`TaikoPerformance::accuracy`].
+    ///
+    /// Since the estimation depends on the amount of objects, unstable rates
+    /// that are lower than the one of an SS will result in an SS.
+    pub const fn unstable_rate(mut self, unstable_rate: f64) -> Self {
+        self.unstable_rate = Some(unstable_rate.max(0.0));
+
+        self
+    }
+
     /// Use the specified settings of the given [`Difficulty`].
     pub fn difficulty(mut self, difficulty: Difficulty) -> Self {
         self.difficulty = difficulty;
@@ -214,6 +253,12 @@ impl<'map> TaikoPerformance<'map> {
     }
 
     /// Create the [`TaikoScoreState`] that will be used for performance calculation.
+    ///
+    /// Hitresults that were not specified are generated based on the
+    /// accuracy, unstable rate, and [`HitResultPriority`]. If the ok hit
+    /// window of the attributes is zero, i.e. 100s are impossible, only 300s
+    /// and misses are generated. Specified 100s are kept in that case.
+    #[allow(clippy::too_many_lines)]
     pub fn generate_state(&mut self) -> Result<TaikoScoreState, ConvertError> {
         let attrs = match self.map_or_attrs {
             MapOrAttrs::Map(ref map) => {
@@ -225,6 +270,7 @@ impl<'map> TaikoPerformance<'map> {
         };
 
         let max_combo = attrs.max_combo();
+        let without_ok_window = attrs.ok_hit_window <= 0.0;
 
         let total_result_count = cmp::min(self.difficulty.get_passed_objects() as u32, max_combo);
 
@@ -236,7 +282,15 @@ impl<'map> TaikoPerformance<'map> {
         let mut n300 = self.n300.map_or(0, |n| cmp::min(n, n_remaining));
         let mut n100 = self.n100.map_or(0, |n| cmp::min(n, n_remaining));
 
-        if let Some(acc) = self.acc {
+        if let (Some(unstable_rate), None, None) = (self.unstable_rate, self.n300, self.n100) {
+            n300 = n300_for_unstable_rate(
+                unstable_rate,
+                attrs.great_hit_window,
+                n_remaining,
+                total_result_count,
+            );
+            n100 = n_remaining - n300;
+        } else if let Some(acc) = self.acc {
             match (self.n300, self.n100) {
                 (Some(_), Some(_)) => {
                     let remaining = total_result_count.saturating_sub(n300 + n100 + misses);
@@ -246,6 +300,16 @@ impl<'map> TaikoPerformance<'map> {
                             n300 += remaining;
                         }
                         HitResultPriority::WorstCase => n100 += remaining,
+                        HitResultPriority::Balanced => {
+                            let worst_acc = accuracy(n300, n100 + remaining, misses);
+                            let best_acc = accuracy(n300 + remaining, n100, misses);
+                            let n = HitResultPriority::balanced_split(
+                                remaining, acc, worst_acc, best_acc,
+                            );
+
+                            n300 += n;
+                            n100 += remaining - n;
+                        }
                     }
                 }
                 (Some(_), None) => n100 += total_result_count.saturating_sub(n300 + misses),
@@ -253,7 +317,13 @@ impl<'map> TaikoPerformance<'map> {
                 (None, None) => {
                     let target_total = acc * f64::from(2 * total_result_count);
 
-                    if let HitResultPriority::Fastest = priority {
+                    if without_ok_window {
+                        // Only 300s and misses are possible so each 300 is
+                        // worth twice as much
+                        let target_n300 = (target_total / 2.0).round_ties_even() as u32;
+                        n300 = cmp::min(n_remaining, target_n300);
+                        n100 = n_remaining - n300;
+                    } else if let HitResultPriority::Fastest = priority {
                         n300 = f64::round_ties_even(target_total) as u32 - n_remaining;
                         n100 = total_result_count.saturating_sub(n300 + misses);
                     } else {
@@ -280,13 +350,13 @@ impl<'map> TaikoPerformance<'map> {
             let remaining = total_result_count.saturating_sub(n300 + n100 + misses);
 
             match priority {
-                HitResultPriority::BestCase | HitResultPriority::Fastest => {
-                    match (self.n300, self.n100) {
-                        (None, _) => n300 = remaining,
-                        (_, None) => n100 = remaining,
-                        _ => n300 += remaining,
-                    }
-                }
+                HitResultPriority::BestCase
+                | HitResultPriority::Fastest
+                | HitResultPriority::Balanced => match (self.n300, self.n100) {
+                    (None, _) => n300 = remaining,
+                    (_, None) => n100 = remaining,
+                    _ => n300 += remaining,
+                },
                 HitResultPriority::WorstCase => match (self.n100, self.n300) {
                     (None, _) => n100 = remaining,
                     (_, None) => n300 = remaining,
@@ -295,6 +365,18 @@ impl<'map> TaikoPerformance<'map> {
             }
         }
 
+        let misses = if without_ok_window {
+            // Without an ok hit window, generated 100s are misses instead.
+            // Specified 100s are kept as is.
+            let specified = self.n100.map_or(0, |n| cmp::min(n, n_remaining));
+            let generated = n100.saturating_sub(specified);
+            n100 -= generated;
+
+            misses + generated
+        } else {
+            misses
+        };
+
         let max_possible_combo = max_combo.saturating_sub(misses);
 
         let max_combo = self.combo.map_or(max_possible_combo, |combo| {
@@ -323,7 +405,30 @@ impl<'map> TaikoPerformance<'map> {
             MapOrAttrs::Map(ref map) => self.difficulty.calculate_for_mode::<Taiko>(map)?,
         };
 
-        Ok(TaikoPerformanceCalculator::new(attrs, self.difficulty.get_mods(), state).calculate())
+        let miss_penalty = self.miss_penalty.unwrap_or(Self::DEFAULT_MISS_PENALTY);
+
+        let inner =
+            TaikoPerformanceCalculator::new(attrs, self.difficulty.get_mods(), state, miss_penalty);
+
+        Ok(inner.calculate())
+    }
+
+    /// Calculate only the accuracy portion of the performance, i.e.
+    /// [`TaikoPerformanceAttributes::pp_acc`].
+    pub(crate) fn accuracy_value(mut self) -> Result<f64, ConvertError> {
+        let state = self.generate_state()?;
+
+        let attrs = match self.map_or_attrs {
+            MapOrAttrs::Attrs(attrs) => attrs,
+            MapOrAttrs::Map(ref map) => self.difficulty.calculate_for_mode::<Taiko>(map)?,
+        };
+
+        let miss_penalty = self.miss_penalty.unwrap_or(Self::DEFAULT_MISS_PENALTY);
+
+        let inner =
+            TaikoPerformanceCalculator::new(attrs, self.difficulty.get_mods(), state, miss_penalty);
+
+        Ok(inner.accuracy_value())
     }
 
     pub(crate) const fn from_map_or_attrs(map_or_attrs: MapOrAttrs<'map, Taiko>) -> Self {
@@ -332,9 +437,11 @@ impl<'map> TaikoPerformance<'map> {
             difficulty: Difficulty::new(),
             combo: None,
             acc: None,
+            unstable_rate: None,
             misses: None,
             n300: None,
             n100: None,
+            miss_penalty: None,
             hitresult_priority: HitResultPriority::DEFAULT,
         }
     }
@@ -372,6 +479,7 @@ impl<'map> TryFrom<OsuPerformance<'map>> for TaikoPerformance<'map> {
             n100,
             n50: _,
             misses,
+            slider_breaks: _,
             hitresult_priority,
         } = osu;
 
@@ -380,10 +488,12 @@ impl<'map> TryFrom<OsuPerformance<'map>> for TaikoPerformance<'map> {
             difficulty,
             combo,
             acc,
+            unstable_rate: None,
             hitresult_priority,
             n300,
             n100,
             misses,
+            miss_penalty: None,
         })
     }
 }
@@ -405,6 +515,47 @@ fn accuracy(n300: u32, n100: u32, misses: u32) -> f64 {
     f64::from(numerator) / f64::from(denominator)
 }
 
+/// Binary search for the amount of n300 whose estimated unstable rate is
+/// closest to the given one.
+fn n300_for_unstable_rate(
+    unstable_rate: f64,
+    great_hit_window: f64,
+    n_remaining: u32,
+    total_hits: u32,
+) -> u32 {
+    // Without a hit window the unstable rate cannot be estimated
+    if great_hit_window <= 0.0 {
+        return n_remaining;
+    }
+
+    let estimate = |n300: u32| {
+        deviation_upper_bound(great_hit_window, n300, f64::from(total_hits))
+            .map_or(f64::INFINITY, |deviation| deviation * 10.0)
+    };
+
+    // The estimation decreases with more n300 so we look for the smallest
+    // amount whose estimation does not exceed the target
+    let mut low = 0;
+    let mut high = n_remaining;
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+
+        if estimate(mid) <= unstable_rate {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    if low > 0 && (estimate(low - 1) - unstable_rate).abs() < (estimate(low) - unstable_rate).abs()
+    {
+        low - 1
+    } else {
+        low
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::sync::OnceLock;
@@ -573,6 +724,40 @@ mod test {
         assert_eq!(state, expected);
     }
 
+    #[test]
+    fn hitresults_n300_n100_misses_acc_balanced() {
+        let state = TaikoPerformance::from(attrs())
+            .accuracy(95.0)
+            .n300(100)
+            .n100(10)
+            .misses(2)
+            .hitresult_priority(HitResultPriority::Balanced)
+            .generate_state()
+            .unwrap();
+
+        assert!(state.n300 > 100 && state.n100 > 10);
+        assert_eq!(state.total_hits(), MAX_COMBO);
+        assert!((state.accuracy() - 0.95).abs() < 0.002);
+    }
+
+    #[test]
+    fn hitresults_unstable_rate() {
+        let mut performance = TaikoPerformance::from(attrs())
+            .misses(2)
+            .accuracy(50.0)
+            .unstable_rate(300.0);
+
+        let state = performance.generate_state().unwrap();
+
+        assert_eq!(state.total_hits(), MAX_COMBO);
+        assert!(state.n100 > 0);
+
+        let attrs = performance.calculate().unwrap();
+        let ur = attrs.estimated_unstable_rate.unwrap();
+
+        assert!((ur - 300.0).abs() < 3.0, "{ur}");
+    }
+
     #[test]
     fn hitresults_misses_best() {
         let state = TaikoPerformance::from(attrs())
@@ -592,6 +777,47 @@ mod test {
         assert_eq!(state, expected);
     }
 
+    #[test]
+    fn hitresults_without_ok_window() {
+        let attrs = TaikoDifficultyAttributes {
+            ok_hit_window: 0.0,
+            ..attrs()
+        };
+
+        let state = TaikoPerformance::from(attrs.clone())
+            .accuracy(90.0)
+            .misses(2)
+            .generate_state()
+            .unwrap();
+
+        assert_eq!(state.n100, 0);
+        assert_eq!(state.total_hits(), MAX_COMBO);
+        assert!((state.accuracy() - 0.9).abs() < 0.002);
+
+        let state = TaikoPerformance::from(attrs.clone())
+            .n100(5)
+            .misses(2)
+            .generate_state()
+            .unwrap();
+
+        assert_eq!(state.n100, 5);
+        assert_eq!(state.misses, 2);
+        assert_eq!(state.total_hits(), MAX_COMBO);
+
+        let state = TaikoPerformance::from(attrs)
+            .n300(250)
+            .n100(5)
+            .misses(2)
+            .accuracy(90.0)
+            .hitresult_priority(HitResultPriority::WorstCase)
+            .generate_state()
+            .unwrap();
+
+        assert_eq!(state.n300, 250);
+        assert_eq!(state.n100, 5);
+        assert_eq!(state.misses, MAX_COMBO - 255);
+    }
+
     #[test]
     fn create() {
         let mut map = beatmap();
@@ -637,4 +863,68 @@ mod test {
         ))
         .is_none());
     }
+
+    #[test]
+    fn default_miss_penalty() {
+        let attrs = attrs();
+
+        let default = TaikoPerformance::from(attrs.clone())
+            .misses(5)
+            .calculate()
+            .unwrap();
+
+        let explicit = TaikoPerformance::from(attrs)
+            .misses(5)
+            .miss_penalty(0.986)
+            .calculate()
+            .unwrap();
+
+        assert_eq!(default, explicit);
+    }
+
+    #[test]
+    fn harsher_miss_penalty() {
+        let attrs = attrs();
+
+        let default = TaikoPerformance::from(attrs.clone())
+            .misses(5)
+            .calculate()
+            .unwrap();
+
+        let harsh = TaikoPerformance::from(attrs)
+            .misses(5)
+            .miss_penalty(0.9)
+            .calculate()
+            .unwrap();
+
+        assert!(harsh.pp_difficulty < default.pp_difficulty);
+    }
+
+    #[test]
+    fn acc_pp_at_matches_calculate() {
+        let attrs = attrs();
+
+        for mods in [0, 8 + 1024] {
+            let perf_attrs = TaikoPerformance::from(attrs.clone())
+                .mods(mods)
+                .calculate()
+                .unwrap();
+
+            for acc in [100.0, 98.5, 95.0, 90.0, 80.0] {
+                let full = TaikoPerformance::from(attrs.clone())
+                    .mods(mods)
+                    .accuracy(acc)
+                    .calculate()
+                    .unwrap();
+
+                let acc_pp = perf_attrs.acc_pp_at(acc, mods);
+
+                assert!(
+                    (acc_pp - full.pp_acc).abs() < f64::EPSILON,
+                    "{mods} {acc}: {acc_pp} != {}",
+                    full.pp_acc
+                );
+            }
+        }
+    }
 }
@@ -31,6 +31,7 @@ impl TaikoStrains {
 
 pub fn strains(difficulty: &Difficulty, map: &Beatmap) -> Result<TaikoStrains, ConvertError> {
     let map = map.convert_ref(GameMode::Taiko, difficulty.get_mods())?;
+    let map = difficulty.apply_time_range(map);
 
     let great_hit_window = map
         .attributes()
@@ -1,4 +1,6 @@
-use std::f64::consts::E;
+use core::f64::consts::E;
+
+use super::math;
 
 pub const fn bpm_to_milliseconds(bpm: f64, delimiter: Option<i32>) -> f64 {
     60_000.0 / i32_unwrap_or(delimiter, 4) as f64 / bpm
@@ -18,24 +20,22 @@ const fn i32_unwrap_or(option: Option<i32>, default: i32) -> i32 {
 
 // `f64::exp` is not const
 pub fn logistic(x: f64, midpoint_offset: f64, multiplier: f64, max_value: Option<f64>) -> f64 {
-    max_value.unwrap_or(1.0) / (1.0 + f64::exp(multiplier * (midpoint_offset - x)))
+    max_value.unwrap_or(1.0) / (1.0 + math::exp(multiplier * (midpoint_offset - x)))
 }
 
 // `f64::exp` is not const
 pub fn logistic_exp(exp: f64, max_value: Option<f64>) -> f64 {
-    max_value.unwrap_or(1.0) / (1.0 + f64::exp(exp))
+    max_value.unwrap_or(1.0) / (1.0 + math::exp(exp))
 }
 
 pub fn norm<const N: usize>(p: f64, values: [f64; N]) -> f64 {
-    values
-        .into_iter()
-        .map(|x| f64::powf(x, p))
-        .sum::<f64>()
-        .powf(p.recip())
+    let sum = values.into_iter().map(|x| math::powf(x, p)).sum::<f64>();
+
+    math::powf(sum, p.recip())
 }
 
 pub fn bell_curve(x: f64, mean: f64, width: f64, multiplier: Option<f64>) -> f64 {
-    multiplier.unwrap_or(1.0) * f64::exp(E * -(f64::powf(x - mean, 2.0) / f64::powf(width, 2.0)))
+    multiplier.unwrap_or(1.0) * math::exp(E * -(math::powf(x - mean, 2.0) / math::powf(width, 2.0)))
 }
 
 pub const fn smoothstep(x: f64, start: f64, end: f64) -> f64 {
@@ -197,10 +197,23 @@ macro_rules! define_skill {
                     object::{IDifficultyObject, IDifficultyObjects, HasStartTime},
                     skills::{StrainSkill, StrainDecaySkill},
                 },
-                util::strains_vec::StrainsVec,
+                util::{snapshots::Rewind, strains_vec::StrainsVec},
             };
 
             define_skill!( @impl $trait $name $objects[$object] );
+
+            impl Rewind for $name {
+                type Snapshot = ( $( <$field_type as Rewind>::Snapshot, )* );
+
+                fn snapshot(&self) -> Self::Snapshot {
+                    ( $( self.$field_name.snapshot(), )* )
+                }
+
+                fn rewind(&mut self, snapshot: &Self::Snapshot) {
+                    let ( $( $field_name, )* ) = snapshot;
+                    $( self.$field_name.rewind($field_name); )*
+                }
+            }
         };
     };
 
@@ -293,6 +306,10 @@ macro_rules! define_skill {
                     )
                 )
             }
+
+            fn object_strains(&self) -> &[f64] {
+                &self.strain_skill_object_strains
+            }
         }
     };
 
@@ -0,0 +1,45 @@
+//! Float functions that are not available in `core`.
+//!
+//! They're provided by `std` unless the module is compiled without it, i.e.
+//! with `--cfg rosu_pp_no_std` and the `libm` feature, in which case `libm`
+//! is used instead. Regular builds are therefore unaffected by `libm`.
+
+#[cfg(all(feature = "libm", rosu_pp_no_std))]
+mod imp {
+    pub fn exp(x: f64) -> f64 {
+        libm::exp(x)
+    }
+
+    pub fn ln(x: f64) -> f64 {
+        libm::log(x)
+    }
+
+    pub fn powf(x: f64, n: f64) -> f64 {
+        libm::pow(x, n)
+    }
+
+    pub fn sqrt(x: f64) -> f64 {
+        libm::sqrt(x)
+    }
+}
+
+#[cfg(not(all(feature = "libm", rosu_pp_no_std)))]
+mod imp {
+    pub fn exp(x: f64) -> f64 {
+        f64::exp(x)
+    }
+
+    pub fn ln(x: f64) -> f64 {
+        f64::ln(x)
+    }
+
+    pub fn powf(x: f64, n: f64) -> f64 {
+        f64::powf(x, n)
+    }
+
+    pub fn sqrt(x: f64) -> f64 {
+        f64::sqrt(x)
+    }
+}
+
+pub use self::imp::{exp, ln, powf, sqrt};
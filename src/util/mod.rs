@@ -4,7 +4,9 @@ pub mod hint;
 pub mod interval_grouping;
 pub mod limited_queue;
 pub mod map_or_attrs;
+pub mod math;
 pub mod random;
+pub mod snapshots;
 pub mod sort;
 pub mod special_functions;
 pub mod strains_vec;
@@ -0,0 +1,201 @@
+/// Amount of processed objects between two snapshots.
+const INTERVAL: usize = 256;
+
+/// State of a gradual calculation that can be restored to an earlier point.
+///
+/// Buffers that only grow while processing are not cloned. Instead, their
+/// snapshot is just their length and rewinding truncates them so that the
+/// size of a snapshot does not depend on the amount of processed objects.
+pub trait Rewind {
+    type Snapshot;
+
+    /// Capture the current state.
+    fn snapshot(&self) -> Self::Snapshot;
+
+    /// Restore a state that was captured at an earlier point.
+    fn rewind(&mut self, snapshot: &Self::Snapshot);
+}
+
+macro_rules! rewind_by_copy {
+    ( $( $ty:ty ),* ) => {
+        $(
+            impl Rewind for $ty {
+                type Snapshot = Self;
+
+                fn snapshot(&self) -> Self::Snapshot {
+                    *self
+                }
+
+                fn rewind(&mut self, snapshot: &Self::Snapshot) {
+                    *self = *snapshot;
+                }
+            }
+        )*
+    };
+}
+
+rewind_by_copy!(bool, f32, f64, Option<f32>);
+
+impl<T> Rewind for Vec<T> {
+    type Snapshot = usize;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.len()
+    }
+
+    fn rewind(&mut self, snapshot: &Self::Snapshot) {
+        debug_assert!(*snapshot <= self.len());
+        self.truncate(*snapshot);
+    }
+}
+
+/// Boxed slices don't grow so they're cloned as a whole.
+impl<T: Clone> Rewind for Box<[T]> {
+    type Snapshot = Self;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.clone()
+    }
+
+    fn rewind(&mut self, snapshot: &Self::Snapshot) {
+        self.clone_from_slice(snapshot);
+    }
+}
+
+/// States of a gradual calculation that are stored periodically so that the
+/// calculation can be rewound without starting from scratch.
+///
+/// Only the initial state is stored until [`Snapshots::enable`] is called so
+/// that gradual calculations which are never rewound don't pay for cloning
+/// their state.
+pub struct Snapshots<T> {
+    /// Stored states alongside their amount of processed objects, sorted by
+    /// the latter.
+    states: Vec<(usize, T)>,
+    enabled: bool,
+}
+
+impl<T> Snapshots<T> {
+    /// Create new snapshots with the state before any processed object.
+    pub fn new(initial: T) -> Self {
+        Self {
+            states: vec![(0, initial)],
+            enabled: false,
+        }
+    }
+
+    /// Start storing periodic snapshots on subsequent calls to
+    /// [`Snapshots::store`].
+    pub const fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    /// Store the state if enabled and `idx` processed objects mark a
+    /// snapshot that has not been stored yet.
+    ///
+    /// This should be called after each processed object.
+    pub fn store(&mut self, idx: usize, state: impl FnOnce() -> T) {
+        if !self.enabled || idx % INTERVAL != 0 {
+            return;
+        }
+
+        if let Err(i) = self.states.binary_search_by_key(&idx, |(idx, _)| *idx) {
+            self.states.insert(i, (idx, state()));
+        }
+    }
+
+    /// Remove all states after `idx` processed objects.
+    ///
+    /// This must be called when rewinding to `idx` since the truncated
+    /// buffers of the restored state can't restore later states anymore.
+    pub fn truncate(&mut self, idx: usize) {
+        let len = self
+            .states
+            .partition_point(|(stored_idx, _)| *stored_idx <= idx);
+
+        self.states.truncate(len);
+    }
+
+    /// Returns the latest stored state for at most `idx` processed objects
+    /// alongside its amount of processed objects.
+    pub fn get(&self, idx: usize) -> Option<(usize, &T)> {
+        let i = self
+            .states
+            .partition_point(|(stored_idx, _)| *stored_idx <= idx)
+            .checked_sub(1)?;
+
+        let (stored_idx, state) = &self.states[i];
+
+        Some((*stored_idx, state))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opt_in() {
+        let mut snapshots = Snapshots::new(0);
+
+        for idx in 1..=2 * INTERVAL {
+            snapshots.store(idx, || idx);
+        }
+
+        // Only the initial state until enabled
+        assert_eq!(snapshots.get(2 * INTERVAL), Some((0, &0)));
+
+        snapshots.enable();
+
+        for idx in 2 * INTERVAL + 1..=4 * INTERVAL {
+            snapshots.store(idx, || idx);
+        }
+
+        assert_eq!(snapshots.get(INTERVAL), Some((0, &0)));
+        assert_eq!(snapshots.get(3 * INTERVAL - 1), Some((0, &0)));
+        assert_eq!(
+            snapshots.get(3 * INTERVAL),
+            Some((3 * INTERVAL, &(3 * INTERVAL)))
+        );
+        assert_eq!(
+            snapshots.get(5 * INTERVAL),
+            Some((4 * INTERVAL, &(4 * INTERVAL)))
+        );
+
+        // Replaying after a rewind fills the gaps
+        snapshots.store(INTERVAL, || INTERVAL);
+        assert_eq!(snapshots.get(INTERVAL + 1), Some((INTERVAL, &INTERVAL)));
+
+        // Already stored snapshots are kept
+        snapshots.store(3 * INTERVAL, || 0);
+        assert_eq!(
+            snapshots.get(3 * INTERVAL),
+            Some((3 * INTERVAL, &(3 * INTERVAL)))
+        );
+    }
+
+    #[test]
+    fn truncate() {
+        let mut snapshots = Snapshots::new(0);
+        snapshots.enable();
+
+        for idx in 1..=3 * INTERVAL {
+            snapshots.store(idx, || idx);
+        }
+
+        snapshots.truncate(2 * INTERVAL - 1);
+
+        assert_eq!(snapshots.get(3 * INTERVAL), Some((INTERVAL, &INTERVAL)));
+    }
+
+    #[test]
+    fn rewind_vec() {
+        let mut strains = vec![1.0, 2.0];
+        let snapshot = strains.snapshot();
+
+        strains.extend([3.0, 4.0]);
+        strains.rewind(&snapshot);
+
+        assert_eq!(strains, [1.0, 2.0]);
+    }
+}
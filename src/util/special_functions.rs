@@ -55,6 +55,8 @@ mod consts {
 #[allow(clippy::wildcard_imports)]
 use consts::*;
 
+use super::math;
+
 pub fn erf(x: f64) -> f64 {
     if x == 0.0 {
         return 0.0;
@@ -195,7 +197,7 @@ fn erf_imp(z: f64, mut invert: bool) -> f64 {
             )
         };
 
-        let g = f64::exp(-z * z) / z;
+        let g = math::exp(-z * z) / z;
 
         (g * b) + (g * r)
     } else {
@@ -222,13 +224,13 @@ fn erf_inv_impl(p: f64, q: f64, s: f64) -> f64 {
     } else if q >= 0.25 {
         const Y: f32 = 2.249481201171875;
 
-        let g = (-2.0 * q.ln()).sqrt();
+        let g = math::sqrt(-2.0 * math::ln(q));
         let xs = q - 0.25;
         let r = evaluate_polynomial(xs, ERV_INV_IMP_BN) / evaluate_polynomial(xs, ERV_INV_IMP_BD);
 
         g / (f64::from(Y) + r)
     } else {
-        let x = (-q.ln()).sqrt();
+        let x = math::sqrt(-math::ln(q));
 
         if x < 3.0 {
             const Y: f32 = 0.807220458984375;
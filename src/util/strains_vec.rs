@@ -8,7 +8,10 @@ mod inner {
         slice::{self, Iter},
     };
 
-    use crate::util::hint::{likely, unlikely};
+    use crate::util::{
+        hint::{likely, unlikely},
+        snapshots::Rewind,
+    };
 
     use self::entry::StrainsEntry;
 
@@ -181,6 +184,49 @@ mod inner {
         }
     }
 
+    /// Snapshot of a [`StrainsVec`] to truncate it back to.
+    #[derive(Copy, Clone)]
+    pub struct StrainsLen {
+        entries: usize,
+        len: usize,
+        // Consecutive zeros are counted in the last entry which might still
+        // be incremented after the snapshot
+        last: Option<StrainsEntry>,
+        #[cfg(debug_assertions)]
+        has_zero: bool,
+    }
+
+    impl Rewind for StrainsVec {
+        type Snapshot = StrainsLen;
+
+        fn snapshot(&self) -> Self::Snapshot {
+            StrainsLen {
+                entries: self.inner.len(),
+                len: self.len,
+                last: self.inner.last().copied(),
+                #[cfg(debug_assertions)]
+                has_zero: self.has_zero,
+            }
+        }
+
+        fn rewind(&mut self, snapshot: &Self::Snapshot) {
+            debug_assert!(snapshot.len <= self.len);
+
+            self.inner.truncate(snapshot.entries);
+
+            if let (Some(last), Some(entry)) = (self.inner.last_mut(), snapshot.last) {
+                *last = entry;
+            }
+
+            self.len = snapshot.len;
+
+            #[cfg(debug_assertions)]
+            {
+                self.has_zero = snapshot.has_zero;
+            }
+        }
+    }
+
     pub struct StrainsIter<'a> {
         inner: Copied<Iter<'a, StrainsEntry>>,
         curr: Option<StrainsEntry>,
@@ -370,6 +416,31 @@ mod inner {
 
                 assert_eq!(unsafe { vec.transmute_into_vec() }, raw);
             }
+
+            #[test]
+            fn rewind(
+                values in prop::collection::vec(prop::option::of(0.0..1_000.0), 0..1_000),
+                split in 0.0..1.0,
+            ) {
+                let split = (values.len() as f64 * split) as usize;
+                let mut vec = StrainsVec::with_capacity(values.len());
+
+                for opt in values[..split].iter().copied() {
+                    vec.push(opt.map_or(0.0, f64::abs));
+                }
+
+                let expected = vec.clone().into_vec();
+                let snapshot = vec.snapshot();
+
+                for opt in values[split..].iter().copied() {
+                    vec.push(opt.map_or(0.0, f64::abs));
+                }
+
+                vec.rewind(&snapshot);
+
+                assert_eq!(vec.len(), expected.len());
+                assert_eq!(vec.into_vec(), expected);
+            }
         }
     }
 }
@@ -381,6 +452,8 @@ mod inner {
         slice::{Iter, IterMut},
     };
 
+    use crate::util::snapshots::Rewind;
+
     /// Plain wrapper around `Vec<f64>` because the `raw_strains` feature
     /// is disabled.
     #[derive(Clone)]
@@ -438,4 +511,16 @@ mod inner {
             self.inner
         }
     }
+
+    impl Rewind for StrainsVec {
+        type Snapshot = usize;
+
+        fn snapshot(&self) -> Self::Snapshot {
+            self.inner.snapshot()
+        }
+
+        fn rewind(&mut self, snapshot: &Self::Snapshot) {
+            self.inner.rewind(snapshot);
+        }
+    }
 }
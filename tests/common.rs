@@ -41,6 +41,34 @@ pub fn assert_eq_option<F: Float>(a: Option<F>, b: Option<F>) {
     }
 }
 
+/// A map of the given mode that only contains a single circle.
+#[allow(unused, reason = "false positive")]
+pub fn single_circle_map(mode: u8) -> rosu_pp::Beatmap {
+    let content = format!(
+        "osu file format v14
+
+[General]
+Mode: {mode}
+
+[Difficulty]
+HPDrainRate:5
+CircleSize:4
+OverallDifficulty:5
+ApproachRate:5
+SliderMultiplier:1.4
+SliderTickRate:1
+
+[TimingPoints]
+0,500,4,2,0,100,1,0
+
+[HitObjects]
+256,192,1000,1,0,0:0:0:0:
+"
+    );
+
+    content.parse().unwrap()
+}
+
 /// Trait to provide flexibility in the `assert_eq_float` function.
 pub trait Float:
     Copy + std::fmt::Display + std::ops::Sub<Output = Self> + PartialOrd + Sized
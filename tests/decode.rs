@@ -1,3 +1,8 @@
+use std::{
+    fs::File,
+    io::{self, BufReader, Read},
+};
+
 use rosu_pp::{model::mode::GameMode, Beatmap, GameMods};
 
 use crate::common::assert_eq_float;
@@ -23,6 +28,7 @@ fn osu() {
     assert_eq!(map.effect_points.len(), 0);
     assert_eq_float(map.stack_leniency, 0.5);
     assert_eq!(map.breaks.len(), 1);
+    assert_eq!(map.mania_key_count(), Some(7));
 }
 
 #[test]
@@ -86,6 +92,7 @@ fn mania() {
     assert_eq!(map.effect_points.len(), 0);
     assert_eq_float(map.stack_leniency, 0.7);
     assert_eq!(map.breaks.len(), 0);
+    assert_eq!(map.mania_key_count(), Some(4));
 }
 
 #[test]
@@ -111,3 +118,63 @@ fn empty_mania() {
     let map = Beatmap::from_bytes(&[]).unwrap();
     let _ = map.convert(GameMode::Mania, &GameMods::default());
 }
+
+#[test]
+fn from_reader() {
+    struct Trickle<R>(R);
+
+    impl<R: Read> Read for Trickle<R> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = buf.len().min(7);
+
+            self.0.read(&mut buf[..len])
+        }
+    }
+
+    struct Failing;
+
+    impl Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::ConnectionReset, "reset"))
+        }
+    }
+
+    for path in [common::OSU, common::TAIKO, common::CATCH, common::MANIA] {
+        let expected = Beatmap::from_path(path).unwrap();
+
+        let file = File::open(path).unwrap();
+        assert_eq!(
+            Beatmap::from_reader(BufReader::new(file)).unwrap(),
+            expected
+        );
+
+        let file = File::open(path).unwrap();
+        assert_eq!(Beatmap::from_reader(Trickle(file)).unwrap(), expected);
+    }
+
+    let err = Beatmap::from_reader(Failing).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::ConnectionReset);
+}
+
+#[test]
+fn limited_object_count() {
+    for path in [common::OSU, common::TAIKO, common::CATCH, common::MANIA] {
+        let bytes = std::fs::read(path).unwrap();
+        let map = Beatmap::from_path(path).unwrap();
+        let count = map.hit_objects.len();
+
+        assert_eq!(Beatmap::from_bytes_limited(&bytes, count).unwrap(), map);
+        assert!(Beatmap::from_bytes_limited(&bytes, count - 1).is_err());
+    }
+}
+
+#[test]
+fn parse_warnings() {
+    for path in [common::OSU, common::TAIKO, common::CATCH, common::MANIA] {
+        let bytes = std::fs::read(path).unwrap();
+        let (map, warnings) = Beatmap::from_bytes_with_warnings(&bytes).unwrap();
+
+        assert_eq!(map, Beatmap::from_path(path).unwrap());
+        assert!(warnings.is_empty(), "{path}: {warnings:?}");
+    }
+}
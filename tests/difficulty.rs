@@ -1,11 +1,23 @@
+#![cfg_attr(not(target_os = "windows"), allow(unused))]
+
 use std::panic::{self, UnwindSafe};
 
 use rosu_pp::{
+    any::DifficultyAttributes,
     catch::{Catch, CatchDifficultyAttributes},
     mania::{Mania, ManiaDifficultyAttributes},
-    osu::{Osu, OsuDifficultyAttributes},
+    model::{
+        beatmap::BeatmapBuilder,
+        hit_object::{PathControlPoint, PathType, Pos},
+        mode::GameMode,
+        mods::rosu_mods::{
+            generated_mods::{RandomMania, RandomTaiko},
+            GameMod, GameModIntermode, GameMods as GameModsLazer,
+        },
+    },
+    osu::{self, Osu, OsuDifficultyAttributes},
     taiko::{Taiko, TaikoDifficultyAttributes},
-    Beatmap, Difficulty,
+    Beatmap, Difficulty, GameMods,
 };
 
 use self::common::*;
@@ -49,9 +61,12 @@ macro_rules! test_cases {
         n_circles: $n_circles:literal,
         n_sliders: $n_sliders:literal,
         n_large_ticks: $n_large_ticks:literal,
+        n_slider_ends: $n_slider_ends:literal,
         n_spinners: $n_spinners:literal,
         stars: $stars:literal,
         max_combo: $max_combo:literal,
+        legacy_score_base_multiplier: $legacy_score_base_multiplier:literal,
+        maximum_legacy_combo_score: $maximum_legacy_combo_score:literal,
     }) => {
         OsuDifficultyAttributes {
             aim: $aim,
@@ -70,9 +85,12 @@ macro_rules! test_cases {
             n_circles: $n_circles,
             n_sliders: $n_sliders,
             n_large_ticks: $n_large_ticks,
+            n_slider_ends: $n_slider_ends,
             n_spinners: $n_spinners,
             stars: $stars,
             max_combo: $max_combo,
+            legacy_score_base_multiplier: $legacy_score_base_multiplier,
+            maximum_legacy_combo_score: $maximum_legacy_combo_score,
         }
     };
     ( @Taiko {
@@ -83,6 +101,8 @@ macro_rules! test_cases {
         great_hit_window: $great_hit_window:literal,
         ok_hit_window: $ok_hit_window:literal,
         mono_stamina_factor: $mono_stamina_factor:literal,
+        pattern_multiplier: $pattern_multiplier:literal,
+        strain_length_bonus: $strain_length_bonus:literal,
         stars: $stars:literal,
         max_combo: $max_combo:literal,
         is_convert: $is_convert:literal,
@@ -95,6 +115,8 @@ macro_rules! test_cases {
             great_hit_window: $great_hit_window,
             ok_hit_window: $ok_hit_window,
             mono_stamina_factor: $mono_stamina_factor,
+            pattern_multiplier: $pattern_multiplier,
+            strain_length_bonus: $strain_length_bonus,
             stars: $stars,
             max_combo: $max_combo,
             is_convert: $is_convert,
@@ -156,9 +178,12 @@ fn basic_osu() {
                 n_circles: 307,
                 n_sliders: 293,
                 n_large_ticks: 15,
+                n_slider_ends: 293,
                 n_spinners: 1,
                 stars: 5.653394946111255,
                 max_combo: 909,
+                legacy_score_base_multiplier: 5.0,
+                maximum_legacy_combo_score: 15729840.0,
             };
             HD => {
                 aim: 2.8768763397837276,
@@ -177,9 +202,12 @@ fn basic_osu() {
                 n_circles: 307,
                 n_sliders: 293,
                 n_large_ticks: 15,
+                n_slider_ends: 293,
                 n_spinners: 1,
                 stars: 5.653394946111255,
                 max_combo: 909,
+                legacy_score_base_multiplier: 5.0,
+                maximum_legacy_combo_score: 15729840.0,
             };
             HR => {
                 aim: 3.2524329530597154,
@@ -198,9 +226,12 @@ fn basic_osu() {
                 n_circles: 307,
                 n_sliders: 293,
                 n_large_ticks: 15,
+                n_slider_ends: 293,
                 n_spinners: 1,
                 stars: 6.252509796432301,
                 max_combo: 909,
+                legacy_score_base_multiplier: 5.0,
+                maximum_legacy_combo_score: 15729840.0,
             };
             DT => {
                 aim: 4.048049265438377,
@@ -219,9 +250,12 @@ fn basic_osu() {
                 n_circles: 307,
                 n_sliders: 293,
                 n_large_ticks: 15,
+                n_slider_ends: 293,
                 n_spinners: 1,
                 stars: 8.041658803681496,
                 max_combo: 909,
+                legacy_score_base_multiplier: 5.0,
+                maximum_legacy_combo_score: 15729840.0,
             };
             FL => {
                 aim: 2.8768763397837276,
@@ -240,9 +274,12 @@ fn basic_osu() {
                 n_circles: 307,
                 n_sliders: 293,
                 n_large_ticks: 15,
+                n_slider_ends: 293,
                 n_spinners: 1,
                 stars: 6.864894125872836,
                 max_combo: 909,
+                legacy_score_base_multiplier: 5.0,
+                maximum_legacy_combo_score: 15729840.0,
             };
             HD FL => {
                 aim: 2.8768763397837276,
@@ -261,9 +298,12 @@ fn basic_osu() {
                 n_circles: 307,
                 n_sliders: 293,
                 n_large_ticks: 15,
+                n_slider_ends: 293,
                 n_spinners: 1,
                 stars: 7.173433597920448,
                 max_combo: 909,
+                legacy_score_base_multiplier: 5.0,
+                maximum_legacy_combo_score: 15729840.0,
             };
         }
     };
@@ -282,6 +322,8 @@ fn basic_taiko() {
                 great_hit_window: 35.0,
                 ok_hit_window: 80.0,
                 mono_stamina_factor: 2.5849839992846003E-07,
+                pattern_multiplier: 0.9869063921396976,
+                strain_length_bonus: 1.0,
                 stars: 2.9052867123859096,
                 max_combo: 289,
                 is_convert: false,
@@ -294,6 +336,8 @@ fn basic_taiko() {
                 great_hit_window: 29.0,
                 ok_hit_window: 68.0,
                 mono_stamina_factor: 2.5849839992846003E-07,
+                pattern_multiplier: 0.9869063921396976,
+                strain_length_bonus: 1.0,
                 stars: 2.987035945441977,
                 max_combo: 289,
                 is_convert: false,
@@ -306,6 +350,8 @@ fn basic_taiko() {
                 great_hit_window: 23.333333333333332,
                 ok_hit_window: 53.333333333333336,
                 mono_stamina_factor: 2.464552111442399E-07,
+                pattern_multiplier: 1.0497952270822248,
+                strain_length_bonus: 1.0,
                 stars: 4.032774431778195,
                 max_combo: 289,
                 is_convert: false,
@@ -326,6 +372,8 @@ fn convert_taiko() {
                 great_hit_window: 23.59999942779541,
                 ok_hit_window: 57.19999885559082,
                 mono_stamina_factor: 0.0014311041774359666,
+                pattern_multiplier: 1.1753799591209622,
+                strain_length_bonus: 1.0,
                 stars: 4.856701972823887,
                 max_combo: 908,
                 is_convert: true,
@@ -338,6 +386,8 @@ fn convert_taiko() {
                 great_hit_window: 20.0,
                 ok_hit_window: 50.0,
                 mono_stamina_factor: 0.0014311041774359666,
+                pattern_multiplier: 1.1753799591209622,
+                strain_length_bonus: 1.0,
                 stars: 5.335018644413355,
                 max_combo: 908,
                 is_convert: true,
@@ -350,6 +400,8 @@ fn convert_taiko() {
                 great_hit_window: 15.733332951863607,
                 ok_hit_window: 38.13333257039388,
                 mono_stamina_factor: 0.0014418086037955797,
+                pattern_multiplier: 1.2641433620912044,
+                strain_length_bonus: 1.0,
                 stars: 7.1789968695409225,
                 max_combo: 908,
                 is_convert: true,
@@ -482,6 +534,406 @@ fn convert_mania() {
     };
 }
 
+#[test]
+fn random_seed_is_deterministic() {
+    fn random_mods(mode: GameMode, seed: f64) -> GameModsLazer {
+        let gamemod = match mode {
+            GameMode::Taiko => GameMod::RandomTaiko(RandomTaiko { seed: Some(seed) }),
+            GameMode::Mania => GameMod::RandomMania(RandomMania { seed: Some(seed) }),
+            GameMode::Osu | GameMode::Catch => unreachable!(),
+        };
+
+        [gamemod].into_iter().collect()
+    }
+
+    for (path, mode) in [
+        (common::TAIKO, GameMode::Taiko),
+        (common::MANIA, GameMode::Mania),
+    ] {
+        let map = Beatmap::from_path(path).unwrap();
+
+        let calculate = |seed| {
+            Difficulty::new()
+                .mods(random_mods(mode, seed))
+                .calculate(&map)
+        };
+
+        let attrs = calculate(1337.0);
+
+        assert_eq!(attrs, calculate(1337.0), "{mode:?}");
+        assert_eq!(
+            attrs.stars().to_bits(),
+            calculate(1337.0).stars().to_bits(),
+            "{mode:?}"
+        );
+
+        // Shuffling osu!mania columns does not affect difficulty
+        if mode == GameMode::Taiko {
+            assert_ne!(attrs, Difficulty::new().calculate(&map));
+        }
+    }
+}
+
+#[test]
+fn time_range() {
+    for path in [common::OSU, common::TAIKO, common::CATCH, common::MANIA] {
+        let map = Beatmap::from_path(path).unwrap();
+
+        let start = map.hit_objects[map.hit_objects.len() / 4].start_time;
+        let end = map.hit_objects[map.hit_objects.len() / 2].start_time;
+
+        let section = trim_hit_objects(path, start, end);
+        assert!(!section.hit_objects.is_empty());
+
+        let difficulty = Difficulty::new().mods(HD | DT);
+
+        let expected = difficulty.calculate(&section);
+        let actual = difficulty.clone().time_range(start, end).calculate(&map);
+
+        assert_eq!(actual, expected, "{path}");
+
+        let gradual = difficulty
+            .time_range(start, end)
+            .gradual_difficulty(&map)
+            .last();
+
+        assert_eq!(gradual, Some(expected), "{path}");
+    }
+
+    let map = Beatmap::from_path(common::OSU).unwrap();
+    let start = map.hit_objects[map.hit_objects.len() / 4].start_time;
+    let end = map.hit_objects[map.hit_objects.len() / 2].start_time;
+    let section = trim_hit_objects(common::OSU, start, end);
+
+    let difficulty = Difficulty::new().mods(HD | DT);
+    let ranged = difficulty.clone().time_range(start, end);
+
+    assert_eq!(
+        osu::doubletappable_objects(&ranged, &map).unwrap(),
+        osu::doubletappable_objects(&difficulty, &section).unwrap(),
+    );
+    assert_eq!(
+        osu::aim_pattern_histogram(&ranged, &map).unwrap(),
+        osu::aim_pattern_histogram(&difficulty, &section).unwrap(),
+    );
+
+    // Indices refer to the full map so they're offset by the amount of
+    // objects before the range
+    let offset = map.hit_objects.len() / 4;
+    let expected: Vec<_> = difficulty
+        .strains(&section)
+        .section_object_indices(&difficulty, &section)
+        .unwrap()
+        .into_iter()
+        .map(|(start, end)| (start + offset, end + offset))
+        .collect();
+
+    let actual = ranged
+        .strains(&map)
+        .section_object_indices(&ranged, &map)
+        .unwrap();
+
+    assert_eq!(actual, expected);
+}
+
+/// Parse the map at `path` while only keeping the hitobject lines whose
+/// start time lies in `start..end`.
+fn trim_hit_objects(path: &str, start: f64, end: f64) -> Beatmap {
+    let content = std::fs::read_to_string(path).unwrap();
+    let mut in_hit_objects = false;
+
+    let trimmed: Vec<_> = content
+        .lines()
+        .filter(|line| {
+            if line.starts_with('[') {
+                in_hit_objects = *line == "[HitObjects]";
+
+                return true;
+            }
+
+            if !in_hit_objects || line.trim().is_empty() {
+                return true;
+            }
+
+            let time: f64 = line.split(',').nth(2).unwrap().trim().parse().unwrap();
+
+            (start..end).contains(&time)
+        })
+        .collect();
+
+    Beatmap::from_bytes(trimmed.join("\n").as_bytes()).unwrap()
+}
+
+#[test]
+fn holds_as_notes() {
+    let map = Beatmap::from_path(common::MANIA).unwrap();
+
+    let holds = Difficulty::new().calculate(&map);
+    let rice = Difficulty::new().holds_as_notes(true).calculate(&map);
+
+    let DifficultyAttributes::Mania(holds) = holds else {
+        panic!("expected mania attributes");
+    };
+    let DifficultyAttributes::Mania(rice) = rice else {
+        panic!("expected mania attributes");
+    };
+
+    assert!(holds.n_hold_notes > 0);
+    assert!(
+        rice.stars < holds.stars,
+        "{} >= {}",
+        rice.stars,
+        holds.stars
+    );
+    assert_eq!(rice.max_combo, holds.max_combo);
+    assert_eq!(rice.n_hold_notes, holds.n_hold_notes);
+
+    let gradual = Difficulty::new()
+        .holds_as_notes(true)
+        .gradual_difficulty(&map)
+        .last();
+
+    assert_eq!(gradual, Some(DifficultyAttributes::Mania(rice)));
+}
+
+#[test]
+fn exceeds_stars() {
+    for path in [common::OSU, common::TAIKO, common::CATCH, common::MANIA] {
+        let map = Beatmap::from_path(path).unwrap();
+        let difficulty = Difficulty::new().mods(HR);
+        let stars = difficulty.calculate(&map).stars();
+
+        for threshold in [1.0, stars - 0.01, stars + 0.01] {
+            assert_eq!(
+                map.exceeds_stars(&difficulty, threshold),
+                Some(stars > threshold),
+                "{path}: {threshold}"
+            );
+        }
+    }
+
+    assert_eq!(
+        Beatmap::from_bytes(&[])
+            .unwrap()
+            .exceeds_stars(&Difficulty::new(), 1.0),
+        None
+    );
+}
+
+#[test]
+fn partial_stars_are_lower_bound() {
+    let map = Beatmap::from_path(common::OSU).unwrap();
+
+    let maps = [
+        GameMode::Osu,
+        GameMode::Taiko,
+        GameMode::Catch,
+        GameMode::Mania,
+    ]
+    .map(|mode| {
+        map.convert_ref(mode, &GameMods::default())
+            .unwrap()
+            .into_owned()
+    })
+    .into_iter()
+    .chain(
+        [common::TAIKO, common::CATCH, common::MANIA].map(|path| Beatmap::from_path(path).unwrap()),
+    );
+
+    for map in maps {
+        for mods in [NM, HD | DT, HR, EZ | HT] {
+            let difficulty = Difficulty::new().mods(mods);
+            let stars = difficulty.calculate(&map).stars();
+
+            for (i, attrs) in difficulty.gradual_difficulty(&map).enumerate() {
+                assert!(
+                    attrs.stars() <= stars + 1e-9,
+                    "{:?} {mods}: {i}: {} > {stars}",
+                    map.mode,
+                    attrs.stars()
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn empty_map() {
+    let map = Beatmap::from_bytes(&[]).unwrap();
+
+    for mode in [
+        GameMode::Osu,
+        GameMode::Taiko,
+        GameMode::Catch,
+        GameMode::Mania,
+    ] {
+        let map = map.convert_ref(mode, &GameMods::default()).unwrap();
+
+        for mods in [NM, HD | HR | DT | FL, EZ | HT] {
+            let attrs = Difficulty::new().mods(mods).calculate(&map);
+
+            assert_eq_float(attrs.stars(), 0.0);
+            assert_eq!(attrs.max_combo(), 0, "{mode:?}: {mods}");
+            assert_eq!(
+                Difficulty::new()
+                    .mods(mods)
+                    .gradual_difficulty(&map)
+                    .count(),
+                0,
+                "{mode:?}: {mods}"
+            );
+        }
+    }
+
+    let osu = Beatmap::from_path(common::OSU).unwrap();
+    let attrs = Difficulty::new().passed_objects(0).calculate(&osu);
+    assert_eq_float(attrs.stars(), 0.0);
+}
+
+#[test]
+fn single_object_map() {
+    for mode in 0..4 {
+        let map = single_circle_map(mode);
+
+        for mods in [NM, HD | HR | DT | FL, EZ | HT] {
+            let attrs = Difficulty::new().mods(mods).calculate(&map);
+
+            assert!(attrs.stars().is_finite(), "{mode}: {mods}");
+            assert!(attrs.stars() >= 0.0, "{mode}: {mods}");
+            assert_eq!(attrs.max_combo(), 1, "{mode}: {mods}");
+        }
+    }
+}
+
+#[test]
+fn builder_matches_decode() {
+    let content = "osu file format v14
+
+[General]
+Mode: 0
+
+[Difficulty]
+HPDrainRate:6
+CircleSize:4
+OverallDifficulty:8
+ApproachRate:9
+SliderMultiplier:1.4
+SliderTickRate:1
+
+[TimingPoints]
+0,500,4,2,0,100,1,0
+
+[HitObjects]
+100,100,1000,1,0,0:0:0:0:
+300,100,1250,1,0,0:0:0:0:
+300,300,1500,1,0,0:0:0:0:
+100,300,1750,1,0,0:0:0:0:
+200,200,2000,2,0,L|300:200,2,100
+256,192,3500,12,0,5000,0:0:0:0:
+";
+
+    let parsed: Beatmap = content.parse().unwrap();
+
+    let control_points = [
+        PathControlPoint {
+            pos: Pos::new(0.0, 0.0),
+            path_type: Some(PathType::LINEAR),
+        },
+        PathControlPoint::new(Pos::new(100.0, 0.0)),
+    ];
+
+    let built = BeatmapBuilder::new()
+        .hp(6.0)
+        .cs(4.0)
+        .od(8.0)
+        .ar(9.0)
+        .slider_multiplier(1.4)
+        .slider_tick_rate(1.0)
+        .timing_point(0.0, 500.0)
+        .circle(Pos::new(100.0, 100.0), 1000.0)
+        .circle(Pos::new(300.0, 100.0), 1250.0)
+        .circle(Pos::new(300.0, 300.0), 1500.0)
+        .circle(Pos::new(100.0, 300.0), 1750.0)
+        .slider(
+            Pos::new(200.0, 200.0),
+            2000.0,
+            control_points,
+            1,
+            Some(100.0),
+        )
+        .spinner(Pos::new(256.0, 192.0), 3500.0, 1500.0)
+        .build();
+
+    for mods in [NM, HD | HR | DT | FL, EZ | HT] {
+        assert_eq!(
+            Difficulty::new().mods(mods).calculate(&built),
+            Difficulty::new().mods(mods).calculate(&parsed),
+            "{mods}"
+        );
+    }
+}
+
+#[test]
+fn ignore_mods() {
+    let map = Beatmap::from_path(common::OSU).unwrap();
+
+    let with_hd = Difficulty::new().mods(HD | DT | FL).calculate(&map);
+    let without_hd = Difficulty::new().mods(DT | FL).calculate(&map);
+    let ignored_hd = Difficulty::new()
+        .mods(HD | DT | FL)
+        .ignore_mods(HD)
+        .calculate(&map);
+
+    assert_ne!(with_hd, without_hd);
+    assert_eq!(ignored_hd, without_hd);
+
+    let ignored_dt = Difficulty::new().mods(DT).ignore_mods(DT).calculate(&map);
+    let clock_rate = Difficulty::new().clock_rate(1.5).calculate(&map);
+    assert_eq!(ignored_dt, clock_rate);
+
+    let lazer = GameModsLazer::from_iter([
+        GameMod::HiddenOsu(Default::default()),
+        GameMod::DoubleTimeOsu(Default::default()),
+        GameMod::FlashlightOsu(Default::default()),
+    ]);
+    let ignored_lazer = Difficulty::new()
+        .mods(lazer)
+        .ignore_mods(HD)
+        .calculate(&map);
+    assert_eq!(ignored_lazer, without_hd);
+
+    // Order of calls does not matter
+    let ignored_first = Difficulty::new().ignore_mods(HD).mods(HD | DT | FL);
+    assert_eq!(ignored_first.calculate(&map), without_hd);
+
+    // Score multipliers still consider ignored mods
+    assert_eq!(
+        ignored_first.max_legacy_score(&map),
+        Difficulty::new().mods(HD | DT | FL).max_legacy_score(&map)
+    );
+}
+
+#[test]
+fn clock_rate_with_mod_semantics() {
+    let map = Beatmap::from_path(common::OSU).unwrap();
+
+    let like_dt = Difficulty::new()
+        .mods(HD)
+        .clock_rate_with_mod_semantics(1.3, GameModIntermode::DoubleTime);
+    let mods_after = Difficulty::new()
+        .clock_rate_with_mod_semantics(1.3, GameModIntermode::DoubleTime)
+        .mods(HD);
+    let plain = Difficulty::new().mods(HD).clock_rate(1.3);
+
+    assert_eq!(like_dt.calculate(&map), plain.calculate(&map));
+    assert_eq!(mods_after.calculate(&map), plain.calculate(&map));
+    assert_eq!(
+        like_dt.max_legacy_score(&map),
+        mods_after.max_legacy_score(&map)
+    );
+    assert!(like_dt.max_legacy_score(&map) > plain.max_legacy_score(&map));
+}
+
 fn run<A>(actual: &A, expected: &A, mods: u32)
 where
     A: AssertEq,
@@ -511,9 +963,12 @@ impl AssertEq for OsuDifficultyAttributes {
             n_circles,
             n_sliders,
             n_large_ticks,
+            n_slider_ends,
             n_spinners,
             stars,
             max_combo,
+            legacy_score_base_multiplier,
+            maximum_legacy_combo_score,
         } = self;
 
         assert_eq_float(*aim, expected.aim);
@@ -541,9 +996,18 @@ impl AssertEq for OsuDifficultyAttributes {
         assert_eq!(*n_circles, expected.n_circles);
         assert_eq!(*n_sliders, expected.n_sliders);
         assert_eq!(*n_large_ticks, expected.n_large_ticks);
+        assert_eq!(*n_slider_ends, expected.n_slider_ends);
         assert_eq!(*n_spinners, expected.n_spinners);
         assert_eq_float(*stars, expected.stars);
         assert_eq!(*max_combo, expected.max_combo);
+        assert_eq_float(
+            *legacy_score_base_multiplier,
+            expected.legacy_score_base_multiplier,
+        );
+        assert_eq_float(
+            *maximum_legacy_combo_score,
+            expected.maximum_legacy_combo_score,
+        );
     }
 }
 
@@ -557,6 +1021,8 @@ impl AssertEq for TaikoDifficultyAttributes {
             great_hit_window,
             ok_hit_window,
             mono_stamina_factor,
+            pattern_multiplier,
+            strain_length_bonus,
             stars,
             max_combo,
             is_convert,
@@ -569,6 +1035,8 @@ impl AssertEq for TaikoDifficultyAttributes {
         assert_eq_float(*great_hit_window, expected.great_hit_window);
         assert_eq_float(*ok_hit_window, expected.ok_hit_window);
         assert_eq_float(*mono_stamina_factor, expected.mono_stamina_factor);
+        assert_eq_float(*pattern_multiplier, expected.pattern_multiplier);
+        assert_eq_float(*strain_length_bonus, expected.strain_length_bonus);
         assert_eq_float(*stars, expected.stars);
         assert_eq!(*max_combo, expected.max_combo);
         assert_eq!(*is_convert, expected.is_convert);
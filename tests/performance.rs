@@ -1,11 +1,14 @@
+#![cfg_attr(not(target_os = "windows"), allow(unused))]
+
 use std::panic::{self, UnwindSafe};
 
 use rosu_pp::{
     catch::{CatchPerformance, CatchPerformanceAttributes},
     mania::{ManiaPerformance, ManiaPerformanceAttributes},
+    model::mode::GameMode,
     osu::{OsuPerformance, OsuPerformanceAttributes},
     taiko::{TaikoPerformance, TaikoPerformanceAttributes},
-    Beatmap,
+    Beatmap, GameMods, Performance,
 };
 
 use self::common::*;
@@ -290,6 +293,76 @@ fn convert_mania() {
     };
 }
 
+#[test]
+fn display_summary() {
+    for (path, component) in [
+        (OSU, "(aim "),
+        (TAIKO, "(difficulty "),
+        (CATCH, "pp "),
+        (MANIA, "(difficulty "),
+    ] {
+        let map = Beatmap::from_path(path).unwrap();
+        let attrs = Performance::new(&map).accuracy(97.0).calculate();
+        let summary = attrs.to_string();
+
+        assert!(
+            summary.starts_with(&format!("{:.2}pp", attrs.pp())),
+            "{summary}"
+        );
+        assert!(summary.contains(component), "{summary}");
+        assert!(
+            summary.contains(&format!(" {:.2}% ", attrs.accuracy() * 100.0)),
+            "{summary}"
+        );
+    }
+}
+
+#[test]
+fn empty_map() {
+    let map = Beatmap::from_bytes(&[]).unwrap();
+
+    for mode in [
+        GameMode::Osu,
+        GameMode::Taiko,
+        GameMode::Catch,
+        GameMode::Mania,
+    ] {
+        let map = map.convert_ref(mode, &GameMods::default()).unwrap();
+
+        for mods in [NM, HD | HR | DT | FL, EZ | HT] {
+            let attrs = Performance::new(map.as_ref()).mods(mods).calculate();
+            assert_eq_float(attrs.pp(), 0.0);
+
+            let attrs = Performance::new(map.as_ref())
+                .mods(mods)
+                .accuracy(95.0)
+                .combo(10)
+                .misses(2)
+                .calculate();
+            assert_eq_float(attrs.pp(), 0.0);
+
+            let attrs = attrs.performance().mods(mods).calculate();
+            assert_eq_float(attrs.pp(), 0.0);
+        }
+    }
+}
+
+#[test]
+fn single_object_map() {
+    for mode in 0..4 {
+        let map = single_circle_map(mode);
+
+        for mods in [NM, HD | HR | DT | FL, EZ | HT] {
+            for misses in [0, 1] {
+                let attrs = Performance::new(&map).mods(mods).misses(misses).calculate();
+
+                assert!(attrs.pp().is_finite(), "{mode}: {mods}");
+                assert!(attrs.pp() >= 0.0, "{mode}: {mods}");
+            }
+        }
+    }
+}
+
 fn run<A>(actual: &A, expected: &A, mods: u32)
 where
     A: AssertEq,
@@ -311,6 +384,7 @@ impl AssertEq for OsuPerformanceAttributes {
             pp_speed,
             effective_miss_count,
             speed_deviation,
+            accuracy: _,
         } = self;
 
         assert_eq_float(*pp, expected.pp);
@@ -332,6 +406,7 @@ impl AssertEq for TaikoPerformanceAttributes {
             pp_difficulty,
             effective_miss_count,
             estimated_unstable_rate,
+            accuracy: _,
         } = self;
 
         assert_eq_float(*pp, expected.pp);
@@ -344,7 +419,14 @@ impl AssertEq for TaikoPerformanceAttributes {
 
 impl AssertEq for CatchPerformanceAttributes {
     fn assert_eq(&self, expected: &Self) {
-        let Self { difficulty: _, pp } = self;
+        let Self {
+            difficulty: _,
+            pp,
+            accuracy: _,
+            effective_miss_count: _,
+            combo_scaling: _,
+            acc_scaling: _,
+        } = self;
 
         assert_eq_float(*pp, expected.pp);
     }
@@ -356,6 +438,7 @@ impl AssertEq for ManiaPerformanceAttributes {
             difficulty: _,
             pp,
             pp_difficulty,
+            accuracy: _,
         } = self;
 
         assert_eq_float(*pp_difficulty, expected.pp_difficulty);
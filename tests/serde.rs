@@ -0,0 +1,69 @@
+#![cfg(feature = "serde")]
+
+use rosu_pp::{
+    any::{DifficultyAttributes, PerformanceAttributes},
+    model::{
+        mode::GameMode,
+        mods::rosu_mods::{GameMod, GameMods as GameModsLazer},
+    },
+    osu::OsuPerformance,
+    Beatmap, Difficulty, Performance,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use self::common::*;
+
+#[allow(dead_code)]
+mod common;
+
+#[track_caller]
+fn roundtrip<T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug>(value: &T) {
+    let json = serde_json::to_string(value).unwrap();
+    let deserialized = serde_json::from_str::<T>(&json).unwrap();
+
+    assert_eq!(&deserialized, value);
+}
+
+#[test]
+fn attributes_roundtrip() {
+    for path in [OSU, TAIKO, CATCH, MANIA] {
+        let map = Beatmap::from_path(path).unwrap();
+
+        let attrs = Performance::new(&map)
+            .mods(HD | DT)
+            .accuracy(97.5)
+            .misses(3)
+            .calculate();
+
+        roundtrip(&attrs);
+        roundtrip(&attrs.difficulty_attributes());
+    }
+}
+
+#[test]
+fn mode_is_preserved() {
+    let map = Beatmap::from_path(OSU).unwrap();
+    let attrs = Difficulty::new().calculate(&map);
+
+    let json = serde_json::to_string(&attrs).unwrap();
+    assert!(json.starts_with(r#"{"Osu":"#), "{json}");
+
+    let converted = map.convert(GameMode::Taiko, &Default::default()).unwrap();
+    let attrs = Difficulty::new().calculate(&converted);
+
+    let json = serde_json::to_string(&attrs).unwrap();
+    let deserialized = serde_json::from_str::<DifficultyAttributes>(&json).unwrap();
+    assert!(matches!(deserialized, DifficultyAttributes::Taiko(_)));
+}
+
+#[test]
+fn lazer_mods_roundtrip() {
+    let map = Beatmap::from_path(OSU).unwrap();
+
+    let mods: GameModsLazer = [GameMod::HiddenOsu(Default::default())]
+        .into_iter()
+        .collect();
+
+    let attrs = OsuPerformance::new(&map).mods(mods).calculate().unwrap();
+    roundtrip(&PerformanceAttributes::Osu(attrs));
+}